@@ -2,7 +2,8 @@
 //!
 //! This library is meant to provide an easy to use game engine for simple games
 //! like Tic-Tac-Toe, Connect-4, Chess, and Checkers. As of right now, the only games
-//! current implemented are Tic-Tac-Tie and Connect-4.
+//! current implemented are Tic-Tac-Tie, Connect-4, Ultimate Tic-Tac-Toe, Qubic,
+//! Gomoku, Reversi, Order and Chaos and Hex, along with rule variants for some of them.
 //! 
 
 /// A simple implementation of a struct that can create any grid based game
@@ -21,5 +22,47 @@ pub mod player_editor;
 /// There is a very basic dumb `Ai` implemented until actual algorithms can be
 /// implemented.
 pub mod ai_engine;
+/// This module holds the colours players can pick and the themes used to draw
+/// them on the board.
+pub mod theme;
+/// This module runs a match turn by turn so that any frontend (the terminal, a
+/// server, etc.) can play a game without writing its own game loop.
+pub mod session;
+/// This module runs external engines (bots) as subprocesses and asks them for
+/// moves over their stdin and stdout.
+pub mod bot;
+/// This module runs tournaments (round-robin, Swiss or knockout) between any
+/// number of players, and saves them so they can be finished later.
+pub mod tournament;
+/// This module keeps Elo ratings for players in each mode and uses them to pick
+/// a fair `Ai` opponent.
+pub mod rating;
+/// This module keeps score in best-of-N series and picks who moves first in
+/// each game.
+pub mod series;
+/// This module holds the rules for Ultimate Tic-Tac-Toe, where the board is a
+/// 3 by 3 grid of Tic-Tac-Toe boards.
+pub mod ultimate;
+/// This module holds the rules for Qubic, which is Tic-Tac-Toe played on a
+/// cube of stacked boards.
+pub mod qubic;
+/// This module holds the rules for Gomoku, five in a row on a big board, along
+/// with Renju's forbidden moves for black.
+pub mod gomoku;
+/// This module holds the rules for Reversi, where discs that are flanked are
+/// flipped to the other side and the most discs wins.
+pub mod reversi;
+/// This module holds the `PopOut` rules for Connect-4, where a player can pop
+/// one of their own discs out of the bottom of a column.
+pub mod popout;
+/// This module holds the rules Tic-Tac-Toe can be played by besides the usual
+/// ones: misère, wild, Notakto and sliding marks.
+pub mod ttt_variants;
+/// This module holds the rules for Order and Chaos, where either player can
+/// place either mark and the two players have different goals.
+pub mod order_chaos;
+/// This module holds the rules for Hex, where the cells are hexagons and each
+/// player tries to connect their two sides of the board.
+pub mod hex;
 
 use super::ui_lib::cmdln_interface;
\ No newline at end of file
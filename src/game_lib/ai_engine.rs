@@ -1,63 +1,109 @@
-use rand::seq::IteratorRandom;
-use super::player::Sprite;
-use super::game::Mode;
+use std::cmp::Reverse;
+use std::fmt;
+use std::iter;
+use rand::Rng;
+use rand::seq::{IteratorRandom, SliceRandom};
+use super::game::{Cell, Game, Mode, MoveStatus, Rules, State};
+use super::game_engine::{check_move, get_valid_moves, play_move, score, valid_moves};
+use super::gomoku;
+use super::rating::EASY_RATING;
 
-/// A funtion that, when called, is used by the ai engine to get valid moves
-///
-/// # Arguments
-/// 
-/// * `game` - \
-///    A game struct used as a way to anaylze the current state of the board
-///    to make a valid moves list
-///
-/// # Returns
-///
-/// * `Vec<usize>` - \
-///     A vector of unsigned integers that represent the moves an Ai can do 
-///                  
-/// # Examples
-/// 
-/// Basic Usage:
-///
-/// ```
-/// use::super::Game;
-/// use::super::Sprite;
-///
-/// let game = Game::tic_tac_toe();
-/// let valid_moves = create_valid_moves(game);
-///
-/// for (_, move) in valid_moves.iter().enumerte() {
-///     eprintln!("These are the valid moves: {move}");   
-/// }
-/// ```
-fn get_valid_moves(board: &[Sprite], size: usize, mode: Mode) -> Vec<usize> {
-    let mut valid_moves = Vec::new();
-
-    match mode {
-        Mode::TicTacToe => {
-            for (index, cell) in board.iter().enumerate() {
-                if cell == &Sprite::default() {
-                    valid_moves.push(index + 1);
-                }
-            }
-        } 
-        Mode::ConnectFour => {
-            for (index, _) in board.iter().enumerate().take(size) {
-                if board[index] == Sprite::default() {
-                    valid_moves.push(index + 1);
-                }
-            }
-        }
-        Mode::Chess => {
-            ();
-        }
-        Mode::Checkers => {
-            ();
-        }
+/// The most positions the `Hard` Ai will look at before it has to guess
+const SEARCH_BUDGET: usize = 20_000;
+/// How far ahead the `Hard` Ai will look on small boards
+const MAX_SEARCH_DEPTH: usize = 9;
+/// The score given to a won game, anything above the heuristic scores
+const WIN_SCORE: i32 = 1_000_000;
+/// How many fours in a row the `Hard` Ai plays through looking for a forced
+/// win in Gomoku
+const MAX_FOURS: usize = 8;
+/// The most positions the `Hard` Ai will look at in one search for a forced
+/// win in Gomoku
+const FOURS_BUDGET: usize = 300;
+/// How many of its best looking Gomoku moves the `Hard` Ai checks against the
+/// opponent's forced wins
+const THREAT_CANDIDATES: usize = 8;
+
+/// An enumerator used to set how hard an `Ai` player is to beat
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Difficulty {
+    /// `Easy` - \
+    ///  Picks a random valid move
+    #[default]
+    Easy,
+    /// `Medium` - \
+    ///  Takes a winning move or blocks an opponent's winning move, 
+    ///  otherwise it picks a random move that doesn't lose straight away
+    Medium,
+    /// `Hard` - \
+    ///  Searches ahead as far as the board size allows and picks the best move.
+    ///  In Gomoku it searches the threats on the board instead
+    Hard,
+}
 
+impl Difficulty {
+    /// Every difficulty a user can pick from, from easiest to hardest
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    /// This function gives the Elo rating the difficulty plays at in a mode, so
+    /// players can be rated against it and matched with a fair opponent. The
+    /// ratings were measured with `rating::calibrate`, 200 games for each pair
+    /// of difficulties on the mode's default board, with `Easy` held at
+    /// `EASY_RATING`. Where one difficulty took every point from the other the
+    /// gap is the least the games could show, so it may well be wider.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode being played
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - \
+    ///    The difficulty's rating in that mode
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Difficulty, Mode};
+    ///
+    /// assert!(Difficulty::Hard.rating(Mode::ConnectFour) > Difficulty::Medium.rating(Mode::ConnectFour));
+    /// assert!(Difficulty::Hard.rating(Mode::Gomoku) > Difficulty::Hard.rating(Mode::TicTacToe));
+    /// ```
+    #[must_use]
+    pub fn rating(self, mode: Mode) -> f64 {
+        let [easy, medium, hard] = match mode {
+            Mode::TicTacToe => [EASY_RATING, 1233.0, 1435.0],
+            Mode::ConnectFour => [EASY_RATING, 1147.0, 1411.0],
+            Mode::UltimateTicTacToe => [EASY_RATING, 1173.0, 1900.0],
+            Mode::Qubic => [EASY_RATING, 2040.0, 2799.0],
+            Mode::Gomoku => [EASY_RATING, 2040.0, 3081.0],
+            Mode::Reversi => [EASY_RATING, 955.0, 1654.0],
+            Mode::OrderAndChaos => [EASY_RATING, 1338.0, 2378.0],
+            Mode::Hex => [EASY_RATING, 1920.0, 2839.0],
+            // nothing can be measured in the modes that can't be played yet
+            Mode::Chess | Mode::Checkers => [EASY_RATING; 3],
+        };
+
+        match self {
+            Difficulty::Easy => easy,
+            Difficulty::Medium => medium,
+            Difficulty::Hard => hard,
+        }
     }
+}
 
-    valid_moves
+// Just a println formatter for the difficulties
+impl fmt::Display for Difficulty {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Difficulty::Easy => write!(format_buffer, "Easy"),
+            Difficulty::Medium => write!(format_buffer, "Medium"),
+            Difficulty::Hard => write!(format_buffer, "Hard"),
+        }
+    }
 }
 
 /// A very simple function that adds very, very basic `Ai` that can pick a random space based on
@@ -67,54 +113,377 @@ fn get_valid_moves(board: &[Sprite], size: usize, mode: Mode) -> Vec<usize> {
 ///
 /// * `board` - \
 ///     A reference to the board
-/// * `size` - \
-///     An unsigned integer used to give the rng a range
+/// * `width` - \
+///     An unsigned integer used to find the columns for Connect-4
 /// * `mode` - \
 ///     Adjust the algorithm according to the mode
+/// * `rng` - \
+///     The random number generator used to pick the move
 ///
 /// # Returns
 ///
 ///  * `usize` - \
 ///      An arch sized unsigned integer meant as the move
 ///
+/// # Panics
+///
+/// Will panic if there are no valid moves left on the board
+///
 /// # Examples
 ///
 /// Basic Usage:
 ///
 /// ```
-/// use super::Mode;
-///
-/// let mut game = Game::tictactoe();
-/// let player_1 = Player::human(String::from("P1"), Sprite::new("X"));
-/// let player_2 = Player::ai(Sprite::new("H"));
-///
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     1,
-/// );
-/// let ai_selection = simple_think(&game.board, board.size. game.mode);
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     ai_selection,
-/// );
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::ai_engine::simple_think;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::edit_board;
+///
+/// let mut game = Game::tic_tac_toe();
+/// let mut rng = rand::thread_rng();
+///
+/// game.board = edit_board(game.board, game.width, game.current_mode, 1, 1);
+/// let ai_selection = simple_think(&game.board, game.width, game.current_mode, &mut rng);
+/// game.board = edit_board(game.board, game.width, game.current_mode, 2, ai_selection);
 ///
 /// println!("{game}");
 /// ```
-pub fn simple_think(board: &[Sprite], size: usize, mode: Mode) -> usize {
-    let mut rng = rand::thread_rng();
-
-    let valid_moves = get_valid_moves(board, size, mode);
-    let ai_pick = *valid_moves.iter().choose(&mut rng).unwrap_or_else(| | {
+pub fn simple_think(board: &[Cell], width: usize, mode: Mode, rng: &mut impl Rng) -> usize {
+    let valid_moves = get_valid_moves(board, width, mode);
+    let ai_pick = *valid_moves.iter().choose(rng).unwrap_or_else(| | {
         panic!("There was an issue in the simple_think function")
     });
             
     ai_pick
-}
\ No newline at end of file
+}
+
+/// This function picks a move for an `Ai` player based on how difficult it is
+/// meant to be. With more than one opponent, the `Hard` `Ai` plays as if they
+/// were all working together against it.
+///
+/// # Arguments
+///
+/// * `game` - \
+///     A reference to the game being played
+/// * `ai_player` - \
+///     The seat of the `Ai` making the move, counted from 1
+/// * `opponents` - \
+///     The seats of the players it is playing against, in the order they
+///     move after it
+/// * `difficulty` - \
+///     How well the `Ai` should play
+/// * `rng` - \
+///     The random number generator used to pick between equally good moves
+///
+/// # Returns
+///
+///  * `usize` - \
+///      An arch sized unsigned integer meant as the move
+///
+/// # Panics
+///
+/// Will panic if there are no valid moves left on the board
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Difficulty, Game, think};
+///
+/// let game = Game::tic_tac_toe();
+/// let mut rng = rand::thread_rng();
+///
+/// // player 2 is the `Ai` and player 1 moves after it
+/// let ai_selection = think(&game, 2, &[1], Difficulty::Hard, &mut rng);
+///
+/// println!("The Ai picked {ai_selection}");
+/// ```
+pub fn think(
+    game: &Game,
+    ai_player: usize,
+    opponents: &[usize],
+    difficulty: Difficulty,
+    rng: &mut impl Rng,
+) -> usize {
+    // every player in the order they move, starting with the `Ai`
+    let turn_order: Vec<usize> = [ai_player].into_iter().chain(opponents.iter().copied()).collect();
+
+    match difficulty {
+        Difficulty::Easy => random_move(game, rng),
+        Difficulty::Medium => {
+            let valid_moves = valid_moves(game);
+
+            // win if we can, then stop the next player that could win from winning
+            let winning_move = valid_moves.iter().copied().find(|&selection| {
+                play(game, &turn_order, 0, selection).current_state == State::Won
+            });
+            let threat = (1..turn_order.len()).find_map(|opponent| {
+                valid_moves
+                    .iter()
+                    .copied()
+                    .find(|&selection| play(game, &turn_order, opponent, selection).current_state == State::Won)
+                    .map(|selection| (opponent, selection))
+            });
+            // playing the threat ourselves usually stops it, but when the
+            // pieces aren't ours (like in Order and Chaos) that could finish
+            // it for them, so look for another move that does
+            let blocking_move = threat.and_then(|(opponent, threat)| {
+                let stops_threat = |selection: usize| {
+                    let next_game = play(game, &turn_order, 0, selection);
+
+                    next_game.current_state != State::Lost
+                        && (check_move(&next_game, threat) != MoveStatus::Valid
+                            || play(&next_game, &turn_order, opponent, threat).current_state != State::Won)
+                };
+
+                iter::once(threat)
+                    .chain(valid_moves.iter().copied())
+                    .find(|&selection| stops_threat(selection))
+                    .or_else(| | (play(game, &turn_order, 0, threat).current_state != State::Lost).then_some(threat))
+            });
+
+            // and under rules where a move can lose, like misère, keep away
+            // from those
+            let safe_moves: Vec<usize> = valid_moves
+                .iter()
+                .copied()
+                .filter(|&selection| play(game, &turn_order, 0, selection).current_state != State::Lost)
+                .collect();
+
+            winning_move
+                .or(blocking_move)
+                .or_else(| | safe_moves.iter().copied().choose(rng))
+                .unwrap_or_else(| | random_move(game, rng))
+        }
+        Difficulty::Hard if game.current_mode == Mode::Gomoku => threat_move(game, &turn_order, rng),
+        Difficulty::Hard => {
+            let mut valid_moves = valid_moves(game);
+
+            // shuffling means equally good moves aren't always picked in the
+            // same order
+            valid_moves.shuffle(rng);
+
+            let depth = search_depth(valid_moves.len());
+            let mut best_score = -WIN_SCORE * 2;
+            let mut best_move = valid_moves[0];
+
+            for selection in valid_moves {
+                let next_game = play(game, &turn_order, 0, selection);
+                let next_mover = next_mover(game, &next_game, turn_order.len(), 0);
+                let score = minimax(&next_game, &turn_order, next_mover, depth - 1, best_score, WIN_SCORE * 2);
+
+                if score > best_score {
+                    best_score = score;
+                    best_move = selection;
+                }
+            }
+
+            best_move
+        }
+    }
+}
+
+// applies the move of the player at `mover` in the turn order to a copy of the
+// game and updates its state
+fn play(game: &Game, turn_order: &[usize], mover: usize, selection: usize) -> Game {
+    let mut next_game = game.clone();
+
+    play_move(&mut next_game, turn_order[mover], selection);
+
+    next_game
+}
+
+// finds who moves after the player at `mover` in the turn order, which skips
+// anyone who had to pass
+fn next_mover(game: &Game, next_game: &Game, num_of_players: usize, mover: usize) -> usize {
+    (mover + next_game.num_of_turns - game.num_of_turns) % num_of_players
+}
+
+// picks any move the rules allow
+fn random_move(game: &Game, rng: &mut impl Rng) -> usize {
+    *valid_moves(game).iter().choose(rng).unwrap_or_else(| | {
+        panic!("There was an issue in the random_move function")
+    })
+}
+
+// finds how many moves ahead can be searched without going over the budget
+fn search_depth(num_of_moves: usize) -> usize {
+    let mut depth = 1;
+    let mut positions = num_of_moves.max(2);
+
+    while depth < MAX_SEARCH_DEPTH && positions * num_of_moves <= SEARCH_BUDGET {
+        positions *= num_of_moves;
+        depth += 1;
+    }
+
+    depth
+}
+
+// scores the game for the `Ai` (the first player in the turn order), higher 
+// is better for it. The `Ai` picks its best move and every opponent picks the
+// move that is worst for the `Ai`.
+fn minimax(
+    game: &Game,
+    turn_order: &[usize],
+    mover: usize,
+    depth: usize,
+    mut alpha: i32,
+    mut beta: i32,
+) -> i32 {
+    let depth_left = i32::try_from(depth).unwrap_or(0);
+
+    match game.current_state {
+        // the last player to move won (or lost, so the player after them
+        // won), and a win sooner (or a loss further away) is better for the `Ai`
+        State::Won if mover == 1 % turn_order.len() => return WIN_SCORE + depth_left,
+        State::Lost if mover == 0 => return WIN_SCORE + depth_left,
+        State::Won | State::Lost => return -WIN_SCORE - depth_left,
+        State::Tie => return 0,
+        State::NotOver => (),
+    }
+
+    if depth == 0 {
+        return score(game, turn_order[0]);
+    }
+
+    for selection in valid_moves(game) {
+        let next_game = play(game, turn_order, mover, selection);
+        let next_mover = next_mover(game, &next_game, turn_order.len(), mover);
+        let score = minimax(&next_game, turn_order, next_mover, depth - 1, alpha, beta);
+
+        if mover == 0 {
+            alpha = alpha.max(score);
+        } else {
+            beta = beta.min(score);
+        }
+
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    if mover == 0 {
+        alpha
+    } else {
+        beta
+    }
+}
+
+// picks a Gomoku move from the threats on the board: winning, stopping the
+// next win, playing fours that can't all be blocked, stopping the opponent's,
+// then building its own lines where they also break up the opponents'
+fn threat_move(game: &Game, turn_order: &[usize], rng: &mut impl Rng) -> usize {
+    let ai_player = turn_order[0];
+    let opponents = &turn_order[1..];
+    let valid_moves = valid_moves(game);
+    let is_valid = |selection: &usize| valid_moves.contains(selection);
+
+    if let Some(selection) = gomoku::winning_moves(game, ai_player).into_iter().find(is_valid) {
+        return selection;
+    }
+
+    let block = opponents
+        .iter()
+        .find_map(|opponent| gomoku::winning_moves(game, *opponent).into_iter().find(is_valid));
+
+    if let Some(selection) = block {
+        return selection;
+    }
+
+    // runs of fours only force the one player who has to answer them
+    let opponent = match opponents {
+        [opponent] => Some(*opponent),
+        _ => None,
+    };
+
+    if let Some(selection) = opponent.and_then(|opponent| forced_win(game, ai_player, opponent)) {
+        return selection;
+    }
+
+    let mut candidates: Vec<usize> = gomoku::candidate_moves(game).into_iter().filter(is_valid).collect();
+
+    if candidates.is_empty() {
+        return random_move(game, rng);
+    }
+
+    // shuffling means equally good moves aren't always picked in the same order
+    candidates.shuffle(rng);
+    candidates.sort_by_cached_key(|selection| {
+        let attack = gomoku::threat_score(game, *selection, ai_player);
+        let defence = opponents
+            .iter()
+            .map(|opponent| gomoku::threat_score(game, *selection, *opponent))
+            .max()
+            .unwrap_or(0);
+
+        Reverse(attack + defence * 9 / 10)
+    });
+
+    // a run of fours the opponent could play next has to be broken up first
+    if let Some(opponent) = opponent.filter(|opponent| forced_win(game, *opponent, ai_player).is_some()) {
+        let defence = candidates.iter().take(THREAT_CANDIDATES).copied().find(|selection| {
+            forced_win(&play(game, turn_order, 0, *selection), opponent, ai_player).is_none()
+        });
+
+        if let Some(selection) = defence {
+            return selection;
+        }
+    }
+
+    candidates[0]
+}
+
+// looks for a run of fours `attacker` can play in Gomoku, where each one has
+// to be blocked, that ends in a win, and gives its first move
+fn forced_win(game: &Game, attacker: usize, defender: usize) -> Option<usize> {
+    let mut budget = FOURS_BUDGET;
+
+    fours_to_win(game, attacker, defender, MAX_FOURS, &mut budget)
+}
+
+// plays through the attacker's fours and the defender's blocks until one of
+// them wins, the fours run out, or the search does
+fn fours_to_win(
+    game: &Game,
+    attacker: usize,
+    defender: usize,
+    fours_left: usize,
+    budget: &mut usize,
+) -> Option<usize> {
+    if fours_left == 0 {
+        return None;
+    }
+
+    let turn_order = [attacker, defender];
+
+    gomoku::four_moves(game, attacker).into_iter().find(|selection| {
+        if *budget == 0 {
+            return false;
+        }
+
+        *budget -= 1;
+
+        let after_four = play(game, &turn_order, 0, *selection);
+
+        // the defender would rather win than block
+        if !gomoku::winning_moves(&after_four, defender).is_empty() {
+            return false;
+        }
+
+        match gomoku::winning_moves(&after_four, attacker).as_slice() {
+            [block] => {
+                // a block black isn't allowed to play in Renju stops nothing
+                if game.rules == Rules::Renju && defender == gomoku::black(game) && gomoku::foul(&after_four, *block).is_some() {
+                    return true;
+                }
+
+                let after_block = play(&after_four, &turn_order, 1, *block);
+
+                after_block.current_state == State::NotOver
+                    && fours_to_win(&after_block, attacker, defender, fours_left - 1, budget).is_some()
+            }
+            // two ways to make five can't both be blocked
+            _ => true,
+        }
+    })
+}
@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use super::game::{Game, Mode, PieceKind, Rules};
+
+/// How long a bot is given to pick each move
+pub const BOT_MOVE_TIME: Duration = Duration::from_secs(1);
+/// How much longer than its move time a bot can take before it is given up on
+pub const BOT_GRACE_TIME: Duration = Duration::from_secs(2);
+
+/// A struct used to run an external engine (a bot) as a subprocess and ask it for
+/// moves. The two talk over the bot's stdin and stdout with the Grid Engine
+/// Protocol, one command per line, in the spirit of UCI for chess.
+///
+/// # Protocol
+///
+/// Sent to the bot:
+///
+/// * `gep` - \
+///    The first command, the bot answers with any `id name <NAME>`,
+///    `id author <NAME>` and `option ...` lines and then `gepok`
+/// * `setoption name <NAME> value <VALUE>` - \
+///    Changes one of the options the bot listed
+/// * `isready` - \
+///    The bot answers `readyok` once it is done with everything before it
+/// * `newgame <MODE> <WIDTH> <HEIGHT> <WIN_LENGTH> [PLAYERS]` - \
+///    A new round is starting, `MODE` is `tic-tac-toe`, `connect-4`,
+///    `ultimate`, `qubic`, `gomoku`, `reversi`, `order-and-chaos` or `hex`.
+///    The number of players is only sent when there are more than two
+/// * `rules <RULES>` - \
+///    Sent straight after `newgame` when the round isn't played by the
+///    standard rules, `RULES` is `freestyle` or `renju` in `gomoku`,
+///    `popout` in `connect-4`, `misere`, `wild`, `notakto` or `sliding` in
+///    `tic-tac-toe` and `swap` in `hex`. Notakto's boards are `HEIGHT` wide,
+///    side by side
+/// * `position <CELLS> <PLAYER> [LAST_MOVE]` - \
+///    The board, one character per cell row by row from the top left (`.` for
+///    empty, `1`, `2`, `3`... for the players' pieces, going on to `a`, `b`...
+///    after `9`), and which player is moving. The last move played is only
+///    sent in `ultimate`, where it decides which small board is played next.
+///    In `qubic` the cells go layer by layer, each one row by row, and in
+///    `wild`, `notakto` and `order-and-chaos`, where either player can place
+///    them, the marks are `x` and `o`. In `order-and-chaos` the player who
+///    moved first this round is Order, and in `hex` player 1 connects the top
+///    and bottom rows and player 2 the left and right columns
+/// * `go movetime <MS>` - \
+///    Pick a move for the player in the last `position` within `MS` milliseconds
+/// * `quit` - \
+///    The bot should exit
+///
+/// Sent by the bot:
+///
+/// * `bestmove <N>` - \
+///    The answer to `go`, the cell (or column for Connect-4) counted from 1,
+///    or `bestmove none` if there is nothing to play. In `popout` a disc is
+///    popped out of a column by answering the width plus the column, in
+///    `wild` and `order-and-chaos` an O is placed by answering the number of
+///    cells plus the cell,
+///    in `sliding` a mark is slid by answering `(FROM + 1) * CELLS + TO`, and
+///    in `swap` the first stone is taken by answering the number of cells
+///    plus 1
+/// * `info <TEXT>` - \
+///    Anything the bot wants to say, which is ignored
+pub struct Bot {
+    command: String,
+    child: Child,
+    stdin: ChildStdin,
+    lines: Receiver<String>,
+    last_game: Option<(Mode, Rules, usize, usize, usize, usize)>,
+    last_turns: usize,
+}
+
+impl Bot {
+    /// This constructor is used to start a bot and wait for it to finish the
+    /// `gep` handshake.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - \
+    ///    The bot's executable followed by any arguments, separated by spaces
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Bot)` - \
+    ///    A Bot that is ready for a game
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the executable could not be started or it did not
+    /// answer the handshake in time.
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use std::fs;
+    /// use tic_tac_toe_and_connect_4::Game;
+    /// use tic_tac_toe_and_connect_4::game_lib::bot::Bot;
+    ///
+    /// // a bot that always wants the middle
+    /// let script = std::env::temp_dir().join("middle_bot.sh");
+    /// fs::write(&script, "while read cmd rest; do case $cmd in \
+    ///     gep) echo gepok;; isready) echo readyok;; go) echo bestmove 5;; quit) exit;; esac; done").unwrap();
+    ///
+    /// let mut bot = Bot::launch(&format!("sh {path}", path = script.display())).unwrap();
+    ///
+    /// assert_eq!(bot.best_move(&Game::tic_tac_toe(), 1, 2).unwrap(), 5);
+    /// ```
+    pub fn launch(command: &str) -> io::Result<Bot> {
+        let mut parts = command.split_whitespace();
+        let program = parts
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "The bot's command is empty"))?;
+        let mut child = Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let stdin = child.stdin.take().ok_or_else(|| io::Error::other("The bot has no stdin"))?;
+        let stdout = child.stdout.take().ok_or_else(|| io::Error::other("The bot has no stdout"))?;
+        let (sender, lines) = mpsc::channel();
+
+        // the bot's output is read on its own thread so a silent bot can be timed out
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut bot = Bot {
+            command: command.to_string(),
+            child,
+            stdin,
+            lines,
+            last_game: None,
+            last_turns: 0,
+        };
+
+        bot.send("gep")?;
+        bot.wait_for("gepok", BOT_GRACE_TIME)?;
+
+        Ok(bot)
+    }
+
+    /// This function gives the command the bot was started with.
+    ///
+    /// # Returns
+    ///
+    /// * `&str` - \
+    ///    The executable and its arguments
+    #[must_use]
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// This function asks the bot for its move. A `newgame` is sent first whenever
+    /// the game is a different size or a new round has started.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - \
+    ///    The Game as it stands
+    /// * `player_number` - \
+    ///    The seat of the player the bot is moving for, counted from 1
+    /// * `num_of_players` - \
+    ///    How many players are in the game
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - \
+    ///    The move the bot picked, which still needs to be checked
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the bot stopped running, took too long or did not
+    /// answer with a move.
+    pub fn best_move(
+        &mut self,
+        game: &Game,
+        player_number: usize,
+        num_of_players: usize,
+    ) -> io::Result<usize> {
+        let this_game = (game.current_mode, game.rules, game.width, game.height, game.win_length, num_of_players);
+        let is_new_game = self.last_game != Some(this_game) || self.last_turns > game.num_of_turns;
+
+        if is_new_game {
+            let mut new_game = format!("newgame {mode} {width} {height} {win_length}",
+                mode = mode_name(game.current_mode), width = game.width,
+                height = game.height, win_length = game.win_length);
+
+            // two player bots don't have to know about the extra argument
+            if num_of_players > 2 {
+                new_game = format!("{new_game} {num_of_players}");
+            }
+
+            self.send(&new_game)?;
+
+            // and only bots for modes with more than one set of rules have to
+            // know about them
+            if game.rules != Rules::Standard {
+                self.send(&format!("rules {rules}", rules = game.rules.to_string().to_lowercase()))?;
+            }
+
+            self.send("isready")?;
+            self.wait_for("readyok", BOT_GRACE_TIME)?;
+        }
+
+        self.last_game = Some(this_game);
+        self.last_turns = game.num_of_turns;
+
+        let cells: String = game
+            .board
+            .iter()
+            .map(|cell| match cell.map(|piece| piece.kind) {
+                Some(PieceKind::Cross) => 'x',
+                Some(PieceKind::Nought) => 'o',
+                _ => cell
+                    .and_then(|piece| u32::try_from(piece.owner).ok())
+                    .and_then(|number| char::from_digit(number, 36))
+                    .unwrap_or('.'),
+            })
+            .collect();
+
+        let mut position = format!("position {cells} {player_number}");
+
+        if let (Mode::UltimateTicTacToe, Some(last_move)) = (game.current_mode, game.moves.last()) {
+            position = format!("{position} {last_move}");
+        }
+
+        self.send(&position)?;
+        self.send(&format!("go movetime {time}", time = BOT_MOVE_TIME.as_millis()))?;
+
+        let answer = self.wait_for("bestmove", BOT_MOVE_TIME + BOT_GRACE_TIME)?;
+
+        answer
+            .trim_start_matches("bestmove")
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("The bot answered `{answer}`")))
+    }
+
+    // writes one command to the bot
+    fn send(&mut self, command: &str) -> io::Result<()> {
+        writeln!(self.stdin, "{command}")?;
+        self.stdin.flush()
+    }
+
+    // waits for a line from the bot starting with the given word, skipping the rest
+    fn wait_for(&mut self, word: &str, time: Duration) -> io::Result<String> {
+        let deadline = Instant::now() + time;
+
+        loop {
+            let left = deadline.saturating_duration_since(Instant::now());
+
+            match self.lines.recv_timeout(left) {
+                Ok(line) if line.split_whitespace().next() == Some(word) => return Ok(line),
+                Ok(_) => (),
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(io::Error::new(io::ErrorKind::TimedOut, format!("The bot did not send `{word}` in time")));
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "The bot stopped running"));
+                }
+            }
+        }
+    }
+}
+
+// asks the bot to quit and makes sure it is gone
+impl Drop for Bot {
+    fn drop(&mut self) {
+        let _ = self.send("quit");
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+// the bot's process can't be printed, so just its command is
+impl fmt::Debug for Bot {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        write!(format_buffer, "Bot({command:?})", command = self.command)
+    }
+}
+
+/// A struct used by a Session to keep the bots it has started, one for each
+/// player. A bot is started the first time it is needed, and again if its
+/// player's command changes.
+///
+/// Copies of a Session can't share a running process, so a clone starts with no
+/// bots and starts its own when they are needed. For the same reason, the bots
+/// are not compared when Sessions are.
+#[derive(Debug, Default)]
+pub struct Bots {
+    running: HashMap<usize, Bot>,
+}
+
+impl Bots {
+    /// This function asks a player's bot for its move, starting the bot first if
+    /// it isn't running yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_number` - \
+    ///    The seat of the player the bot is moving for, counted from 1
+    /// * `command` - \
+    ///    The command that starts the player's bot
+    /// * `game` - \
+    ///    The Game as it stands
+    /// * `num_of_players` - \
+    ///    How many players are in the game
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - \
+    ///    The move the bot picked, which still needs to be checked
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the bot could not be started or did not answer
+    /// with a move. The bot is stopped, so it is started again next time.
+    pub fn best_move(
+        &mut self,
+        player_number: usize,
+        command: &str,
+        game: &Game,
+        num_of_players: usize,
+    ) -> io::Result<usize> {
+        if self.running.get(&player_number).is_none_or(|bot| bot.command() != command) {
+            self.running.remove(&player_number);
+            self.running.insert(player_number, Bot::launch(command)?);
+        }
+
+        let answer = self
+            .running
+            .get_mut(&player_number)
+            .map_or_else(|| Err(io::Error::other("The bot is not running")),
+                |bot| bot.best_move(game, player_number, num_of_players));
+
+        if answer.is_err() {
+            self.running.remove(&player_number);
+        }
+
+        answer
+    }
+}
+
+impl Clone for Bots {
+    fn clone(&self) -> Self {
+        Bots::default()
+    }
+}
+
+impl PartialEq for Bots {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// This function gives the name a Mode is sent to a bot as.
+///
+/// # Arguments
+///
+/// * `mode` - \
+///    The Mode to name
+///
+/// # Returns
+///
+/// * `&str` - \
+///    The mode's name in the protocol, the same as `--mode` takes
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Mode;
+/// use tic_tac_toe_and_connect_4::game_lib::bot::mode_name;
+///
+/// assert_eq!(mode_name(Mode::ConnectFour), "connect-4");
+/// ```
+#[must_use]
+pub fn mode_name(mode: Mode) -> &'static str {
+    match mode {
+        Mode::TicTacToe => "tic-tac-toe",
+        Mode::ConnectFour => "connect-4",
+        Mode::Chess => "chess",
+        Mode::Checkers => "checkers",
+        Mode::UltimateTicTacToe => "ultimate",
+        Mode::Qubic => "qubic",
+        Mode::Gomoku => "gomoku",
+        Mode::Reversi => "reversi",
+        Mode::OrderAndChaos => "order-and-chaos",
+        Mode::Hex => "hex",
+    }
+}
@@ -1,5 +1,13 @@
 use std::fmt;
-use super::player::Sprite;
+use unicode_width::UnicodeWidthStr;
+use super::player::{List, MIN_CELL_WIDTH};
+use super::theme::Theme;
+use super::game_engine::valid_moves;
+use super::{gomoku, order_chaos, reversi, ultimate};
+use super::hex::MAX_SIZE;
+
+/// What a free cell that can be played is drawn as, in games that mark them
+const MOVE_MARK: &str = "·";
 
 /// An enumerator used to keep track the state of the Game
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -10,6 +18,10 @@ pub enum State {
     /// `Won` - \
     ///  Used to end the game
     Won,
+    /// `Lost` - \
+    ///  Ends the game with the player who just moved losing it, so the next \
+    ///  player wins, e.g. on the disc count in Reversi
+    Lost,
     /// `Tie` - \
     ///  Used to report a tie in the game
     Tie,
@@ -31,6 +43,44 @@ pub enum Mode {
     ///  Changes that game to follow the rules of Checkers and \
     ///  the board size
     Checkers,
+    /// `UltimateTicTacToe` - \
+    ///  A 3 by 3 grid of Tic-Tac-Toe boards, where the cell played sends the \
+    ///  next player to a small board and the small boards won decide the game
+    UltimateTicTacToe,
+    /// `Qubic` - \
+    ///  Tic-Tac-Toe in three dimensions on a cube of layers, where a line can \
+    ///  go through the layers as well as across them
+    Qubic,
+    /// `Gomoku` - \
+    ///  Five in a row on a 15 by 15 board, played by the game's `Rules`
+    Gomoku,
+    /// `Reversi` - \
+    ///  Also known as Othello, discs flanked by the player's are flipped to \
+    ///  their side and whoever has the most discs once nobody can move wins
+    Reversi,
+    /// `OrderAndChaos` - \
+    ///  Either player places an X or an O on a 6 by 6 board, where Order wins \
+    ///  with five of the same mark in a row and Chaos wins by filling the board \
+    ///  without one
+    OrderAndChaos,
+    /// `Hex` - \
+    ///  A rhombus of hexagonal cells where each player tries to connect their \
+    ///  two opposite sides with a chain of their stones
+    Hex,
+}
+
+impl Mode {
+    /// Every mode that can be played, in the order they are offered
+    pub const PLAYABLE: [Mode; 8] = [
+        Mode::TicTacToe,
+        Mode::ConnectFour,
+        Mode::UltimateTicTacToe,
+        Mode::Qubic,
+        Mode::Gomoku,
+        Mode::Reversi,
+        Mode::OrderAndChaos,
+        Mode::Hex,
+    ];
 }
 
 // Just a println formatter for the modes
@@ -49,6 +99,150 @@ impl fmt::Display for Mode {
             Mode::Checkers => {
                 write!(format_buffer, "Checkers")
             }
+            Mode::UltimateTicTacToe => {
+                write!(format_buffer, "Ultimate Tic-Tac-Toe")
+            }
+            Mode::Qubic => {
+                write!(format_buffer, "Qubic")
+            }
+            Mode::Gomoku => {
+                write!(format_buffer, "Gomoku")
+            }
+            Mode::Reversi => {
+                write!(format_buffer, "Reversi")
+            }
+            Mode::OrderAndChaos => {
+                write!(format_buffer, "Order and Chaos")
+            }
+            Mode::Hex => {
+                write!(format_buffer, "Hex")
+            }
+        }
+    }
+}
+
+/// An enumerator used to pick which rules a mode is played by, for modes that
+/// can be played more than one way
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Rules {
+    /// `Standard` - \
+    ///  The mode's usual rules. In Gomoku, exactly five in a row wins
+    #[default]
+    Standard,
+    /// `Freestyle` - \
+    ///  Gomoku where five or more in a row wins
+    Freestyle,
+    /// `Renju` - \
+    ///  Gomoku where black, whoever moves first, wins with exactly five but \
+    ///  can't make a double-three, a double-four or an overline, and white \
+    ///  wins with five or more
+    Renju,
+    /// `PopOut` - \
+    ///  Connect-4 where a player can also pop one of their own discs out of \
+    ///  the bottom row, a move that connects four for both players is a draw, \
+    ///  and so is the same position coming up three times
+    PopOut,
+    /// `Misere` - \
+    ///  Tic-Tac-Toe where the player who completes a line loses
+    Misere,
+    /// `Wild` - \
+    ///  Tic-Tac-Toe where either player can place an X or an O, and whoever \
+    ///  completes a line of either wins
+    Wild,
+    /// `Notakto` - \
+    ///  Tic-Tac-Toe on one or more boards side by side where both players \
+    ///  place Xs, a board with a line on it is out of play, and whoever \
+    ///  completes a line on the last board loses
+    Notakto,
+    /// `Sliding` - \
+    ///  Tic-Tac-Toe where each player only has as many marks as it takes to \
+    ///  win, and once they are all placed a mark is slid to a free cell next \
+    ///  to it instead
+    Sliding,
+    /// `Swap` - \
+    ///  Hex where the second player can take the first stone as their own \
+    ///  instead of placing one, so the first player has no reason to open \
+    ///  with too strong a move
+    Swap,
+}
+
+impl Rules {
+    /// This function gives the rules a mode can be played by, starting with
+    /// the one it is played by when none are picked.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode being played
+    ///
+    /// # Returns
+    ///
+    /// * `&[Rules]` - \
+    ///    Every set of rules the mode can be played by
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Mode, Rules};
+    ///
+    /// assert_eq!(Rules::for_mode(Mode::Qubic), &[Rules::Standard]);
+    /// assert!(Rules::for_mode(Mode::TicTacToe).contains(&Rules::Notakto));
+    /// assert!(Rules::for_mode(Mode::Gomoku).contains(&Rules::Renju));
+    /// assert!(Rules::for_mode(Mode::ConnectFour).contains(&Rules::PopOut));
+    /// ```
+    #[must_use]
+    pub fn for_mode(mode: Mode) -> &'static [Rules] {
+        match mode {
+            Mode::Gomoku => &[Rules::Standard, Rules::Freestyle, Rules::Renju],
+            Mode::ConnectFour => &[Rules::Standard, Rules::PopOut],
+            Mode::TicTacToe => &[Rules::Standard, Rules::Misere, Rules::Wild, Rules::Notakto, Rules::Sliding],
+            Mode::Hex => &[Rules::Standard, Rules::Swap],
+            _ => &[Rules::Standard],
+        }
+    }
+
+    /// This function tells whether the rules can only be played by two
+    /// players.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - \
+    ///    `true` when the rules are worked out for two players
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::Rules;
+    ///
+    /// assert!(Rules::Renju.is_for_two());
+    /// assert!(!Rules::Standard.is_for_two());
+    /// ```
+    #[must_use]
+    pub fn is_for_two(self) -> bool {
+        // black and white are the only sides in Renju, PopOut's draws are
+        // worked out for two, a loss in the Tic-Tac-Toe variants is a win for
+        // the one other player, and Hex's swap is between the first two moves
+        !matches!(self, Rules::Standard | Rules::Freestyle)
+    }
+}
+
+// Just a println formatter for the rules
+impl fmt::Display for Rules {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rules::Standard => write!(format_buffer, "Standard"),
+            Rules::Freestyle => write!(format_buffer, "Freestyle"),
+            Rules::Renju => write!(format_buffer, "Renju"),
+            Rules::PopOut => write!(format_buffer, "PopOut"),
+            Rules::Misere => write!(format_buffer, "Misere"),
+            Rules::Wild => write!(format_buffer, "Wild"),
+            Rules::Notakto => write!(format_buffer, "Notakto"),
+            Rules::Sliding => write!(format_buffer, "Sliding"),
+            Rules::Swap => write!(format_buffer, "Swap"),
         }
     }
 }
@@ -65,6 +259,260 @@ pub enum MoveStatus<'a> {
     Invalid(&'a str),
 }
 
+/// An enumerator used to say what a move does to the board. Moves are passed
+/// around the engine (and to bots and clients) as one number, the selection,
+/// and this is what that number stands for in the game's mode.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Move {
+    /// `Place(cell)` - \
+    ///  A piece is put in the cell, counted from 1
+    Place(usize),
+    /// `Drop(column)` - \
+    ///  A disc is dropped down the column, counted from 1, and lands on the \
+    ///  lowest free cell
+    Drop(usize),
+    /// `Pop(column)` - \
+    ///  The disc at the bottom of the column, counted from 1, is taken out and \
+    ///  the discs above it fall down a row
+    Pop(usize),
+    /// `Mark(cell, kind)` - \
+    ///  A mark of the kind (an X or an O) is put in the cell, counted from 1, \
+    ///  whichever player is moving
+    Mark(usize, PieceKind),
+    /// `Slide(from, to)` - \
+    ///  The player's piece in the first cell is moved to the second, both \
+    ///  counted from 1
+    Slide(usize, usize),
+    /// `Swap` - \
+    ///  The first stone on the board is taken over by the player moving \
+    ///  (see `Rules::Swap`)
+    Swap,
+}
+
+impl Move {
+    /// This function reads what a selection stands for. In Connect-4 the
+    /// columns are numbered from 1 to `width` for dropping a disc, and popping
+    /// one out of a column is numbered on from there, so popping the first
+    /// column is `width + 1`. In every other mode the selection is a cell. The
+    /// modes and rules that move pieces differently read it with
+    /// `game_engine::read_selection`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode being played
+    /// * `width` - \
+    ///    How many columns are on the board
+    /// * `selection` - \
+    ///    The move's number
+    ///
+    /// # Returns
+    ///
+    /// * `Move` - \
+    ///    What the move does
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Mode, Move};
+    ///
+    /// assert_eq!(Move::from_selection(Mode::ConnectFour, 7, 3), Move::Drop(3));
+    /// assert_eq!(Move::from_selection(Mode::ConnectFour, 7, 10), Move::Pop(3));
+    /// assert_eq!(Move::from_selection(Mode::TicTacToe, 3, 5), Move::Place(5));
+    /// ```
+    #[must_use]
+    pub fn from_selection(mode: Mode, width: usize, selection: usize) -> Move {
+        match mode {
+            Mode::ConnectFour if selection > width => Move::Pop(selection - width),
+            Mode::ConnectFour => Move::Drop(selection),
+            _ => Move::Place(selection),
+        }
+    }
+
+    /// This function gives the number a move is passed around as, the other
+    /// way around from `from_selection`. An O is numbered on from the last
+    /// cell, so an O in the first cell is `cells + 1`, and a slide is numbered
+    /// `(from + 1) * cells + to`, which comes after every O. A swap in Hex
+    /// is the one after the last cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - \
+    ///    How many columns are on the board
+    /// * `cells` - \
+    ///    How many cells are on the board
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The move's selection
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Move, PieceKind};
+    ///
+    /// assert_eq!(Move::Pop(3).selection(7, 42), 10);
+    /// assert_eq!(Move::Drop(3).selection(7, 42), 3);
+    /// assert_eq!(Move::Mark(5, PieceKind::Nought).selection(3, 9), 14);
+    /// assert_eq!(Move::Slide(1, 2).selection(3, 9), 20);
+    /// assert_eq!(Move::Swap.selection(11, 121), 122);
+    /// ```
+    #[must_use]
+    pub fn selection(self, width: usize, cells: usize) -> usize {
+        match self {
+            Move::Place(cell) | Move::Mark(cell, PieceKind::Cross) => cell,
+            Move::Drop(column) => column,
+            Move::Pop(column) => width + column,
+            Move::Mark(cell, _) => cells + cell,
+            Move::Slide(from, to) => (from + 1) * cells + to,
+            Move::Swap => cells + 1,
+        }
+    }
+}
+
+/// An enumerator used to tell what kind of piece is in a cell, so games with
+/// more than one kind of piece (Chess and Checkers) can be drawn and played
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum PieceKind {
+    /// `Stone` - \
+    ///  A Tic-Tac-Toe mark or a Connect-4 disc, drawn with the owner's sprite
+    #[default]
+    Stone,
+    /// `Man` - \
+    ///  An uncrowned Checkers piece, drawn with the owner's sprite
+    Man,
+    /// `King` - \
+    ///  A Chess king or a crowned Checkers piece
+    King,
+    /// `Queen` - \
+    ///  A Chess queen
+    Queen,
+    /// `Rook` - \
+    ///  A Chess rook
+    Rook,
+    /// `Bishop` - \
+    ///  A Chess bishop
+    Bishop,
+    /// `Knight` - \
+    ///  A Chess knight
+    Knight,
+    /// `Pawn` - \
+    ///  A Chess pawn
+    Pawn,
+    /// `Cross` - \
+    ///  An X that either player can place, in games where the marks aren't \
+    ///  the players' own
+    Cross,
+    /// `Nought` - \
+    ///  An O that either player can place
+    Nought,
+}
+
+impl PieceKind {
+    /// This function gives the letter a kind of piece is drawn with, if it
+    /// isn't drawn with its owner's sprite.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(char)` - \
+    ///    The piece's letter, like `N` for a knight
+    /// * `None` - \
+    ///    The piece is drawn with its owner's sprite
+    #[must_use]
+    pub fn letter(self) -> Option<char> {
+        match self {
+            PieceKind::Stone | PieceKind::Man => None,
+            PieceKind::King => Some('K'),
+            PieceKind::Queen => Some('Q'),
+            PieceKind::Rook => Some('R'),
+            PieceKind::Bishop => Some('B'),
+            PieceKind::Knight => Some('N'),
+            PieceKind::Pawn => Some('P'),
+            PieceKind::Cross => Some('X'),
+            PieceKind::Nought => Some('O'),
+        }
+    }
+
+    /// This function tells whether the kind of piece is a mark either player
+    /// can place, which looks the same whoever placed it.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - \
+    ///    `true` for an X or an O
+    #[must_use]
+    pub fn is_mark(self) -> bool {
+        matches!(self, PieceKind::Cross | PieceKind::Nought)
+    }
+}
+
+/// A struct used to represent a piece on the board. It only knows which player
+/// owns it, by their seat, so how it looks is up to the players (see
+/// `List::glyph`) and changing a sprite never changes the game.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Piece {
+    /// `owner` - \
+    ///  The seat of the player the piece belongs to, counted from 1
+    pub owner: usize,
+    /// `kind` - \
+    ///  What kind of piece it is
+    pub kind: PieceKind,
+}
+
+impl Piece {
+    /// This constructor is used to make the only kind of piece Tic-Tac-Toe and
+    /// Connect-4 have.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - \
+    ///    The seat of the player the piece belongs to, counted from 1
+    ///
+    /// # Returns
+    ///
+    /// * `Piece` - \
+    ///    A `Stone` owned by the player
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, Piece};
+    ///
+    /// let mut game = Game::tic_tac_toe();
+    ///
+    /// game.board[4] = Some(Piece::stone(1));
+    ///
+    /// println!("{game}");
+    /// ```
+    #[must_use]
+    pub fn stone(owner: usize) -> Piece {
+        Piece {
+            owner,
+            kind: PieceKind::Stone,
+        }
+    }
+}
+
+// Draws the piece without its owner's sprite: its letter, or the owner's seat
+impl fmt::Display for Piece {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind.letter() {
+            Some(letter) => write!(format_buffer, "{letter}"),
+            None => write!(format_buffer, "{owner}", owner = self.owner),
+        }
+    }
+}
+
+/// A cell on the board, which is either empty or holds a piece
+pub type Cell = Option<Piece>;
+
 /// A struct used to represent a Game and its related data
 #[derive(Debug, PartialEq, Clone)]
 pub struct Game {
@@ -78,16 +526,48 @@ pub struct Game {
     ///  An enum used to track the state of the game
     pub current_state: State,
     /// `board` - \
-    ///  A Vector of Sprites being used as the board
-    pub board: Vec<Sprite>,
-    /// `size` - \
-    ///  Used to track one axis of the board
-    pub size: usize,
+    ///  The cells of the board, row by row from the top left
+    pub board: Vec<Cell>,
+    /// `width` - \
+    ///  Used to track the number of columns on the board
+    pub width: usize,
+    /// `height` - \
+    ///  Used to track the number of rows on the board
+    pub height: usize,
+    /// `depth` - \
+    ///  Used to track the number of layers on the board, which is `1` for
+    ///  every game but Qubic
+    pub depth: usize,
+    /// `win_length` - \
+    ///  How many pieces a player needs in a line to win
+    pub win_length: usize,
+    /// `rules` - \
+    ///  Which rules the mode is played by, see `Rules::for_mode`
+    pub rules: Rules,
     /// `num_of_turns` - \
     ///  Keeps track of the number of turns 
     pub num_of_turns: usize,
+    /// `first_player` - \
+    ///  The seat of the player who moves first this round, counted from 1, for
+    ///  games where whose turn it is can't be told from the board alone
+    pub first_player: usize,
+    /// `moves` - \
+    ///  Every move played this round, in order, for rules that depend on what
+    ///  was played before (see `game_engine::play_move`)
+    pub moves: Vec<usize>,
+    /// `theme` - \
+    ///  The palette used when drawing the players' colours on the board
+    pub theme: Theme,
 }
 
+/// A struct that borrows a Game and the players in it so the board can be drawn
+/// with each player's sprite, in their colour when colour is turned on.
+#[derive(Debug)]
+pub struct ColouredGame<'a> {
+    game: &'a Game,
+    players: &'a List,
+    colour: bool,
+}
 
 impl Game {
     /// This is a constuctor for Game struct that is here for idiomatic sake
@@ -100,8 +580,12 @@ impl Game {
     ///    A String where the name can be set
     /// * `new_mode` - \
     ///    A enum that allows easy setting of the game mode
-    /// * `size` - \
-    ///    An unsigned integer used to set the size of the board
+    /// * `width` - \
+    ///    An unsigned integer used to set the number of columns
+    /// * `height` - \
+    ///    An unsigned integer used to set the number of rows
+    /// * `win_length` - \
+    ///    An unsigned integer used to set how many in a line wins the game
     /// 
     /// # Returns
     ///
@@ -113,18 +597,36 @@ impl Game {
     /// Basic Usage:
     ///
     /// ```
-    /// let game = Game::new(String::from("Test Game"), Mode::ConnectFour, 5);
+    /// use tic_tac_toe_and_connect_4::{Game, Mode};
+    ///
+    /// let game = Game::new(String::from("Test Game"), Mode::ConnectFour, 7, 6, 4);
     ///
     /// println!("{game}");
     /// ```
-    pub fn new(name: String, new_mode: Mode, size: usize) -> Game {
+    #[must_use]
+    pub fn new(name: String, new_mode: Mode, width: usize, height: usize, win_length: usize) -> Game {
+        // a Qubic board is always a cube, and Reversi starts with discs in the
+        // middle
+        let depth = if new_mode == Mode::Qubic { width } else { 1 };
+        let board = match new_mode {
+            Mode::Reversi => reversi::starting_board(width, height),
+            _ => vec![None; width * height * depth],
+        };
+
         Game {
             name,
             current_mode: new_mode,
             current_state: State::NotOver,
-            board: vec![Sprite::default(); size * size],
-            size,
+            board,
+            width,
+            height,
+            depth,
+            win_length,
+            rules: Rules::default(),
             num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
         }
     }
 
@@ -142,6 +644,8 @@ impl Game {
     /// Basic Usage:
     ///
     /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
     /// let game = Game::tic_tac_toe();
     ///
     /// println!("{game}");
@@ -152,9 +656,16 @@ impl Game {
             name: String::from("Tic-Tac-Toe"),
             current_mode: Mode::TicTacToe,
             current_state: State::NotOver,
-            board: vec![Sprite::default(); 9],
-            size: 3,
+            board: vec![None; 9],
+            width: 3,
+            height: 3,
+            depth: 1,
+            win_length: 3,
+            rules: Rules::default(),
             num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
         }
     }
     
@@ -172,6 +683,8 @@ impl Game {
     /// Basic Usage:
     ///
     /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
     /// let game = Game::connect_four();
     ///
     /// println!("{game}");
@@ -182,9 +695,16 @@ impl Game {
             name: String::from("Connect-4"),
             current_mode: Mode::ConnectFour,
             current_state: State::NotOver,
-            board: vec![Sprite::default(); 16],
-            size: 4,
+            board: vec![None; 16],
+            width: 4,
+            height: 4,
+            depth: 1,
+            win_length: 4,
+            rules: Rules::default(),
             num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
         }
     }    
 
@@ -202,6 +722,8 @@ impl Game {
     /// Basic Usage:
     ///
     /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
     /// let game = Game::chess();
     ///
     /// println!("{game}");
@@ -212,9 +734,16 @@ impl Game {
             name: String::from("Chess"),
             current_mode: Mode::Chess,
             current_state: State::NotOver,
-            board: vec![Sprite::default(); 64],
-            size: 8,
+            board: vec![None; 64],
+            width: 8,
+            height: 8,
+            depth: 1,
+            win_length: 8,
+            rules: Rules::default(),
             num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
         }
     }  
 
@@ -232,6 +761,8 @@ impl Game {
     /// Basic Usage:
     ///
     /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
     /// let game = Game::checkers();
     ///
     /// println!("{game}");
@@ -242,9 +773,284 @@ impl Game {
             name: String::from("Checkers"),
             current_mode: Mode::Checkers,
             current_state: State::NotOver,
-            board: vec![Sprite::default(); 64],
-            size: 8,
+            board: vec![None; 64],
+            width: 8,
+            height: 8,
+            depth: 1,
+            win_length: 8,
+            rules: Rules::default(),
+            num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// This constructor is essentially a `default` state that allows easy 
+    /// creation of an Ultimate Tic-Tac-Toe board, a 3 by 3 grid of 3 by 3
+    /// boards that are drawn apart from each other. The cells are counted row
+    /// by row across the whole 9 by 9 board.
+    /// 
+    /// # Returns
+    ///
+    /// * `Game` - \
+    ///    A Game struct with Ultimate Tic-Tac-Toe specs
+    ///
+    /// # Examples
+    /// 
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
+    /// let game = Game::ultimate_tic_tac_toe();
+    ///
+    /// println!("{game}");
+    /// ```
+    #[must_use] 
+    pub fn ultimate_tic_tac_toe() -> Game {
+        Game {
+            name: String::from("Ultimate Tic-Tac-Toe"),
+            current_mode: Mode::UltimateTicTacToe,
+            current_state: State::NotOver,
+            board: vec![None; 81],
+            width: 9,
+            height: 9,
+            depth: 1,
+            win_length: 3,
+            rules: Rules::default(),
+            num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// This constructor is used to create a Qubic board, a cube of layers
+    /// that are drawn side by side. It needs a full line as long as the cube is
+    /// wide to win. The cells are counted layer by layer, then row by row from
+    /// the top left.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - \
+    ///    How many cells are on each side of the cube, see `qubic::DEFAULT_SIZE`
+    /// 
+    /// # Returns
+    ///
+    /// * `Game` - \
+    ///    A Game struct with Qubic specs
+    ///
+    /// # Examples
+    /// 
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
+    /// let game = Game::qubic(4);
+    ///
+    /// assert_eq!(game.board.len(), 64);
+    ///
+    /// println!("{game}");
+    /// ```
+    #[must_use] 
+    pub fn qubic(size: usize) -> Game {
+        Game {
+            name: String::from("Qubic"),
+            current_mode: Mode::Qubic,
+            current_state: State::NotOver,
+            board: vec![None; size * size * size],
+            width: size,
+            height: size,
+            depth: size,
+            win_length: size,
+            rules: Rules::default(),
             num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// This constructor is used to create a Gomoku board, 15 by 15 with five in
+    /// a row to win, played by the given rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - \
+    ///    `Rules::Standard`, `Rules::Freestyle` or `Rules::Renju`
+    /// 
+    /// # Returns
+    ///
+    /// * `Game` - \
+    ///    A Game struct with Gomoku specs
+    ///
+    /// # Examples
+    /// 
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, Rules};
+    ///
+    /// let game = Game::gomoku(Rules::Renju);
+    ///
+    /// assert_eq!(game.board.len(), 225);
+    ///
+    /// println!("{game}");
+    /// ```
+    #[must_use] 
+    pub fn gomoku(rules: Rules) -> Game {
+        Game {
+            name: String::from("Gomoku"),
+            current_mode: Mode::Gomoku,
+            current_state: State::NotOver,
+            board: vec![None; gomoku::SIZE * gomoku::SIZE],
+            width: gomoku::SIZE,
+            height: gomoku::SIZE,
+            depth: 1,
+            win_length: gomoku::WIN_LENGTH,
+            rules,
+            num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// This constructor is used to create a Reversi board with the four discs
+    /// it starts with in the middle. Player 1 plays black and moves first.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - \
+    ///    How many cells are on each side of the board, which should be even
+    ///    (see `reversi::DEFAULT_SIZE`)
+    /// 
+    /// # Returns
+    ///
+    /// * `Game` - \
+    ///    A Game struct with Reversi specs
+    ///
+    /// # Examples
+    /// 
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
+    /// let game = Game::reversi(8);
+    ///
+    /// assert_eq!(game.board.iter().filter(|cell| cell.is_some()).count(), 4);
+    ///
+    /// println!("{game}");
+    /// ```
+    #[must_use] 
+    pub fn reversi(size: usize) -> Game {
+        Game {
+            name: String::from("Reversi"),
+            current_mode: Mode::Reversi,
+            current_state: State::NotOver,
+            board: reversi::starting_board(size, size),
+            width: size,
+            height: size,
+            depth: 1,
+            win_length: size,
+            rules: Rules::default(),
+            num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// This constructor is used to create an Order and Chaos board, 6 by 6 with
+    /// five of the same mark in a row to win. The player who moves first plays
+    /// Order (see `order_chaos::order`).
+    ///
+    /// # Returns
+    ///
+    /// * `Game` - \
+    ///    A Game struct with Order and Chaos specs
+    ///
+    /// # Examples
+    /// 
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
+    /// let game = Game::order_and_chaos();
+    ///
+    /// assert_eq!(game.board.len(), 36);
+    ///
+    /// println!("{game}");
+    /// ```
+    #[must_use] 
+    pub fn order_and_chaos() -> Game {
+        Game {
+            name: String::from("Order and Chaos"),
+            current_mode: Mode::OrderAndChaos,
+            current_state: State::NotOver,
+            board: vec![None; order_chaos::SIZE * order_chaos::SIZE],
+            width: order_chaos::SIZE,
+            height: order_chaos::SIZE,
+            depth: 1,
+            win_length: order_chaos::WIN_LENGTH,
+            rules: Rules::default(),
+            num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// This constructor is used to create a Hex board, a rhombus of hexagonal
+    /// cells. Player 1 connects the top and bottom sides and player 2 the left
+    /// and right (see `hex::TOP_TO_BOTTOM`).
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - \
+    ///    How many cells are on each side of the board, up to `hex::MAX_SIZE`
+    ///    (see `hex::DEFAULT_SIZE`)
+    /// 
+    /// # Returns
+    ///
+    /// * `Game` - \
+    ///    A Game struct with Hex specs
+    ///
+    /// # Examples
+    /// 
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::Game;
+    ///
+    /// let game = Game::hex(11);
+    ///
+    /// assert_eq!(game.board.len(), 121);
+    ///
+    /// println!("{game}");
+    /// ```
+    #[must_use] 
+    pub fn hex(size: usize) -> Game {
+        let size = size.min(MAX_SIZE);
+
+        Game {
+            name: String::from("Hex"),
+            current_mode: Mode::Hex,
+            current_state: State::NotOver,
+            board: vec![None; size * size],
+            width: size,
+            height: size,
+            depth: 1,
+            win_length: size,
+            rules: Rules::default(),
+            num_of_turns: 0,
+            first_player: 1,
+            moves: Vec::new(),
+            theme: Theme::default(),
         }
     }
 
@@ -261,49 +1067,30 @@ impl Game {
     /// Basic Usage:
     ///
     /// ```
-    /// use super::game::{Game, Mode, MoveStatus};
-    /// use super::player::{Player, Sprite};
-    /// use game_lib::game_engine::edit_board;
+    /// use tic_tac_toe_and_connect_4::Game;
+    /// use tic_tac_toe_and_connect_4::game_lib::game_engine::edit_board;
     ///
     /// let mut game = Game::tic_tac_toe();
-    /// let player_1 = Player::human(String::from("P1"), Sprite::new("X"));
-    ///
-    /// game.board = edit_board(
-    ///     game.board, 
-    ///     game.size, 
-    ///     game.mode, 
-    ///     &player_1.sprite, 
-    ///     &player_2.sprite, 
-    ///     1,
-    /// );
-    /// game.board = edit_board(
-    ///     game.board, 
-    ///     game.size, 
-    ///     game.mode, 
-    ///     &player_1.sprite, 
-    ///     &player_2.sprite, 
-    ///     2,
-    /// );
-    /// game.board = edit_board(
-    ///     game.board, 
-    ///     game.size, 
-    ///     game.mode, 
-    ///     &player_1.sprite, 
-    ///     &player_2.sprite, 
-    ///     3,
-    /// );
+    ///
+    /// game.board = edit_board(game.board, game.width, game.current_mode, 1, 1);
+    /// game.board = edit_board(game.board, game.width, game.current_mode, 1, 2);
+    /// game.board = edit_board(game.board, game.width, game.current_mode, 1, 3);
     /// game.reset(); // by commenting this line out, it SHOULD edit the board
     /// 
-    /// println!("{game}");
+    /// assert_eq!(game.board, Game::tic_tac_toe().board);
     /// ```
     pub fn reset(&mut self) {
-        self.board = vec![Sprite::default(); self.size * self.size];
+        self.board = match self.current_mode {
+            Mode::Reversi => reversi::starting_board(self.width, self.height),
+            _ => vec![None; self.width * self.height * self.depth],
+        };
         self.num_of_turns = 0;
+        self.moves.clear();
         self.current_state = State::NotOver;
     }
 
-    /// This function takes the game struct and completely resets the board according to the mode it
-    /// is in
+    /// This function adds one to the number of turns that have been played in
+    /// the game
     ///
     /// # Arguments
     ///
@@ -315,83 +1102,300 @@ impl Game {
     /// Basic Usage:
     ///
     /// ```
-    /// use super::game::{Game, Mode, MoveStatus};
-    /// use super::player::{Player, Sprite};
-    /// use game_lib::game_engine::edit_board;
+    /// use tic_tac_toe_and_connect_4::Game;
+    /// use tic_tac_toe_and_connect_4::game_lib::game_engine::edit_board;
     ///
     /// let mut game = Game::tic_tac_toe();
-    /// let player_1 = Player::human(String::from("P1"), Sprite::new("X"));
-    ///
-    /// game.board = edit_board(
-    ///     game.board, 
-    ///     game.size, 
-    ///     game.mode, 
-    ///     &player_1.sprite, 
-    ///     &player_2.sprite, 
-    ///     1,
-    /// );
+    ///
+    /// game.board = edit_board(game.board, game.width, game.current_mode, 1, 1);
     /// game.update_turns();
-    /// game.board = edit_board(
-    ///     game.board, 
-    ///     game.size, 
-    ///     game.mode, 
-    ///     &player_1.sprite, 
-    ///     &player_2.sprite, 
-    ///     2,
-    /// );
+    /// game.board = edit_board(game.board, game.width, game.current_mode, 1, 2);
     /// game.update_turns();
-    /// game.board = edit_board(
-    ///     game.board, 
-    ///     game.size, 
-    ///     game.mode, 
-    ///     &player_1.sprite, 
-    ///     &player_2.sprite, 
-    ///     3,
-    /// );
+    /// game.board = edit_board(game.board, game.width, game.current_mode, 1, 3);
     /// game.update_turns();
     /// 
-    /// println!("{game}");
+    /// assert_eq!(game.num_of_turns, 3);
     /// ```
     pub fn update_turns(&mut self) {
         self.num_of_turns += 1;
     }
-}
 
-// the formatter trait for the game struct
-impl fmt::Display for Game {
-    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+    /// This function tells whether the game is played with marks either
+    /// player can place (see `PieceKind::is_mark`) rather than pieces of
+    /// their own.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - \
+    ///    `true` when who placed a piece doesn't decide the game
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, Rules};
+    ///
+    /// let mut game = Game::tic_tac_toe();
+    ///
+    /// assert!(!game.plays_marks());
+    ///
+    /// game.rules = Rules::Wild;
+    ///
+    /// assert!(game.plays_marks());
+    /// assert!(Game::order_and_chaos().plays_marks());
+    /// ```
+    #[must_use]
+    pub fn plays_marks(&self) -> bool {
+        self.current_mode == Mode::OrderAndChaos || matches!(self.rules, Rules::Wild | Rules::Notakto)
+    }
+
+    /// This function tells whether the game can only be played by two
+    /// players, either because of its rules (see `Rules::is_for_two`) or
+    /// because the mode only has two sides.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - \
+    ///    `true` when the game needs exactly two players
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, Rules};
+    ///
+    /// let mut game = Game::tic_tac_toe();
+    ///
+    /// assert!(!game.is_for_two());
+    ///
+    /// game.rules = Rules::Misere;
+    ///
+    /// assert!(game.is_for_two());
+    /// assert!(Game::reversi(8).is_for_two());
+    /// ```
+    #[must_use]
+    pub fn is_for_two(&self) -> bool {
+        // Reversi's discs, Order and Chaos's roles and Hex's edges only come
+        // in pairs
+        self.rules.is_for_two() || matches!(self.current_mode, Mode::Reversi | Mode::OrderAndChaos | Mode::Hex)
+    }
+
+    /// This function pairs the game with its players so the board can be printed
+    /// in colour with the game's theme. 
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - \
+    ///    A reference to the game that should be drawn
+    /// * `players` - \
+    ///    A reference to the List of players whose colours are used
+    /// * `colour` - \
+    ///    Whether to use colour at all, see `theme::colour_enabled`
+    ///
+    /// # Returns
+    ///
+    /// * `ColouredGame` - \
+    ///    A struct that can be printed with `{}`
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Player, Sprite};
+    /// use tic_tac_toe_and_connect_4::game_lib::theme::colour_enabled;
+    ///
+    /// let game = Game::tic_tac_toe();
+    /// let player_list = List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("H")),
+    /// ]);
+    ///
+    /// println!("{}", game.coloured(&player_list, colour_enabled()));
+    /// ```
+    #[must_use]
+    pub fn coloured<'a>(&'a self, players: &'a List, colour: bool) -> ColouredGame<'a> {
+        ColouredGame {
+            game: self,
+            players,
+            colour,
+        }
+    }
+
+    // draws the grid and lets the caller decide how each cell looks. Games
+    // made of small boards leave a gap between them, the layers of a cube and
+    // Notakto's boards are drawn side by side, and free cells that can be played are dotted in
+    // games that mark their moves
+    fn draw(
+        &self, 
+        format_buffer: &mut fmt::Formatter, 
+        cell_width: usize,
+        cell: impl Fn(&Cell) -> String,
+    ) -> fmt::Result {
+        if self.current_mode == Mode::Hex {
+            return self.draw_hex(format_buffer, cell_width, cell);
+        }
+
+        let columns = self.width * self.depth;
+        let (column_block, row_block) = match self.current_mode {
+            Mode::UltimateTicTacToe => (Some(ultimate::BLOCK), Some(ultimate::BLOCK)),
+            Mode::Qubic => (Some(self.width), None),
+            Mode::TicTacToe if self.rules == Rules::Notakto => (Some(self.height), None),
+            _ => (None, None),
+        };
+        let marked = match self.current_mode {
+            Mode::UltimateTicTacToe | Mode::Reversi if self.current_state == State::NotOver => valid_moves(self),
+            _ => Vec::new(),
+        };
+        let is_edge = |line: usize, block: Option<usize>| line > 0 && block.is_some_and(|block| line.is_multiple_of(block));
+        // the cell drawn at a row and column, going to the next layer every
+        // `width` columns
+        let index = |row: usize, column: usize| (column / self.width * self.height + row) * self.width + column % self.width;
+        let separator = |format_buffer: &mut fmt::Formatter| -> fmt::Result {
+            for column in 0..columns {
+                if is_edge(column, column_block) {
+                    write!(format_buffer, "  ")?;
+                }
+
+                write!(format_buffer, " {:-^cell_width$} ", "-")?;
+            }
+
+            Ok(())
+        };
+
         writeln!(format_buffer)?;
-        write!(format_buffer, " ")?;
 
-        let sprites = &self.board;
+        if self.depth > 1 {
+            let layer_width = self.width * (cell_width + 4);
+
+            write!(format_buffer, " ")?;
+
+            for layer in 1..=self.depth {
+                if layer > 1 {
+                    write!(format_buffer, "  ")?;
+                }
+
+                write!(format_buffer, "{label:^layer_width$}", label = format!("Layer {layer}"))?;
+            }
 
-        for _ in 0..self.size {
-            write!(format_buffer, " {:-^3} ", "-")?;
+            writeln!(format_buffer)?;
         }
 
+        write!(format_buffer, " ")?;
+        separator(format_buffer)?;
         write!(format_buffer, "\n ")?;
 
-        for (index, sprite) in sprites.iter().enumerate() {
-            write!(format_buffer, "|")?;
-            write!(format_buffer, " {sprite:^3} ")?;    
-            write!(format_buffer, "|")?;
-            write!(format_buffer, "")?;
+        for row in 0..self.height {
+            for column in 0..columns {
+                if is_edge(column, column_block) {
+                    write!(format_buffer, "  ")?;
+                }
 
-            // check to see if we are at the
-            // end of the row
-            if  (index + 1) % self.size == 0 {
-                write!(format_buffer, "\n ")?;
+                let cell_index = index(row, column);
+                let contents = if marked.contains(&(cell_index + 1)) {
+                    centre(MOVE_MARK, cell_width)
+                } else {
+                    cell(&self.board[cell_index])
+                };
 
-                // format the line breaks between
-                // rows
-                for _ in 0..self.size {
-                    write!(format_buffer, " {:-^3} ", "-")?;
-                }
-                
+                write!(format_buffer, "|")?;
+                write!(format_buffer, " {contents} ")?;    
+                write!(format_buffer, "|")?;
+            }
+
+            // format the line breaks between
+            // rows
+            write!(format_buffer, "\n ")?;
+            separator(format_buffer)?;
+            write!(format_buffer, "\n ")?;
+
+            // and a blank line between rows of small boards
+            if is_edge(row + 1, row_block) && row + 1 < self.height {
                 write!(format_buffer, "\n ")?;
             }
         }
 
         write!(format_buffer, "")
     }
-}
\ No newline at end of file
+
+    // draws Hex's rhombus, each row set half a cell further right than the
+    // one above so a cell sits between the two it touches in the rows next to
+    // it. The columns are lettered and the rows numbered, the way Hex moves
+    // are written
+    fn draw_hex(
+        &self,
+        format_buffer: &mut fmt::Formatter,
+        cell_width: usize,
+        cell: impl Fn(&Cell) -> String,
+    ) -> fmt::Result {
+        // a cell and the gap after it take an even number of columns, so half
+        // of one is a whole number
+        let step = (cell_width + 3).next_multiple_of(2);
+        let gap = " ".repeat(step - cell_width - 2);
+        let label_width = self.height.to_string().len();
+        let letters = |format_buffer: &mut fmt::Formatter, indent: usize| -> fmt::Result {
+            write!(format_buffer, " {:indent$}", "", indent = label_width + 1 + indent)?;
+
+            for letter in ('a'..='z').take(self.width) {
+                write!(format_buffer, "{}{gap}", centre(&letter.to_string(), cell_width + 2))?;
+            }
+
+            writeln!(format_buffer)
+        };
+
+        writeln!(format_buffer)?;
+        letters(format_buffer, 0)?;
+
+        for row in 0..self.height {
+            write!(format_buffer, " {number:>label_width$} {:indent$}", "", number = row + 1, indent = row * step / 2)?;
+
+            for column in 0..self.width {
+                write!(format_buffer, "<{contents}>{gap}", contents = cell(&self.board[row * self.width + column]))?;
+            }
+
+            writeln!(format_buffer, "{number}", number = row + 1)?;
+        }
+
+        letters(format_buffer, self.height.saturating_sub(1) * step / 2)
+    }
+}
+
+// the formatter trait for the game struct
+impl fmt::Display for Game {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        self.draw(format_buffer, MIN_CELL_WIDTH, |cell| match cell {
+            Some(piece) => centre(&piece.to_string(), MIN_CELL_WIDTH),
+            None => centre("", MIN_CELL_WIDTH),
+        })
+    }
+}
+
+// the same grid as the game but with the players' sprites and colours
+impl fmt::Display for ColouredGame<'_> {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        let cell_width = self.players.cell_width();
+
+        self.game.draw(format_buffer, cell_width, |cell| {
+            let Some(piece) = cell else {
+                return centre("", cell_width);
+            };
+            let glyph = centre(&self.players.glyph(piece), cell_width);
+
+            match self.players.players.get(piece.owner.wrapping_sub(1)) {
+                Some(player) if self.colour => self.game.theme.paint(&glyph, player.colour),
+                _ => glyph,
+            }
+        })
+    }
+}
+
+// pads text on both sides to fill a number of terminal columns, measuring wide
+// characters like emoji by how much room they take rather than by `chars`
+fn centre(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.width());
+    let left = padding / 2;
+
+    format!("{empty:left$}{text}{empty:right$}", empty = "", right = padding - left)
+}
@@ -1,14 +1,18 @@
-use super::cmdln_interface::{TO_MAIN, GAME_MODE_SEL, BOARD_SIZE_SEL};
-use super::cmdln_interface::get_int_input;
-use super::player::Sprite;
-use super::game::{Game, Mode};
+use std::io::{BufRead, Write};
+use super::cmdln_interface::{TO_MAIN, BOARD_SIZE_SEL, NOTAKTO_BOARDS_SEL, THEME_SEL};
+use super::cmdln_interface::{get_int_input, Console};
+use super::game::{Game, Mode, Rules};
+use super::{gomoku, hex, qubic, reversi, ttt_variants};
+use super::theme::Theme;
 
 /// This function is used to take a Game struct and edit the fields with user
-/// selected input so they can edit the game mode and size of the board. After editing
+/// selected input so they can edit the game mode, size and theme of the board. After editing
 /// the funciton will then return the edited game.
 ///
 /// # Arguments
 ///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
 /// * `message` - \
 ///      A string literal used for the user prompt
 /// * `game` - \
@@ -19,137 +23,272 @@ use super::game::{Game, Mode};
 /// Basic Usage:
 /// 
 /// ```
-/// use super::game::{Game, Mode};
+/// use std::io::Cursor;
+/// use tic_tac_toe_and_connect_4::{Game, Mode};
+/// use tic_tac_toe_and_connect_4::game_lib::game_editor::game_editor;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
 ///
+/// // pick the gamemode menu, then Connect-4, then leave the editor
+/// let mut console = Console::new(Cursor::new("1\n2\nq\n"), Vec::new());
 /// let mut game = Game::tic_tac_toe();
 ///
-/// println!("{game}");
+/// game = game_editor(&mut console, "Editing the game: ", game.clone());
 ///
-/// game = game_editor("Editing the game: ", game.clone());
+/// assert_eq!(game.current_mode, Mode::ConnectFour);
 ///
-/// println!("{game}");
+/// // Notakto's boards are put away when moving on to Connect-4
+/// let mut console = Console::new(Cursor::new("4\n4\n1\n2\nq\n"), Vec::new());
+///
+/// game = game_editor(&mut console, "Editing the game: ", Game::tic_tac_toe());
+///
+/// assert_eq!(game.current_mode, Mode::ConnectFour);
+/// assert_eq!(game.width, game.height);
 /// ```
-pub fn game_editor(message: &str, mut game: Game) -> Game {
+pub fn game_editor<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    message: &str, 
+    mut game: Game,
+) -> Game {
     loop {
-        match get_int_input(message) {
+        match get_int_input(console, message) {
             None => {
-                eprintln!("{TO_MAIN}");
+                console.println(TO_MAIN);
 
                 break;
             }
             Some(1) => {
-                if let Some(new_mode) = get_new_mode(GAME_MODE_SEL) {
+                if let Some(new_mode) = get_new_mode(console) {
                     match new_mode {
+                        // the size is kept when switching between these two,
+                        // but Notakto's extra boards go with its rules
+                        Mode::TicTacToe | Mode::ConnectFour if matches!(game.current_mode, Mode::TicTacToe | Mode::ConnectFour) => {
+                            if game.rules == Rules::Notakto {
+                                game.width = game.height;
+                                game.board = vec![None; game.width * game.height];
+                            }
+
+                            game.current_mode = new_mode;
+                            game.rules = Rules::default();
+                        }
                         Mode::TicTacToe => {
-                            game.current_mode = Mode::TicTacToe;
+                            game = Game { theme: game.theme, ..Game::tic_tac_toe() };
                         }
                         Mode::ConnectFour => {
-                            game.current_mode = Mode::ConnectFour;
+                            game = Game { theme: game.theme, ..Game::connect_four() };
+                        }
+                        Mode::UltimateTicTacToe => {
+                            game = Game { theme: game.theme, ..Game::ultimate_tic_tac_toe() };
+                        }
+                        Mode::Qubic => {
+                            game = Game { theme: game.theme, ..Game::qubic(qubic::DEFAULT_SIZE) };
+                        }
+                        Mode::Gomoku => {
+                            game = Game { theme: game.theme, ..Game::gomoku(Rules::default()) };
                         }
-                        Mode::Chess => {
-                            game = Game::chess();
+                        Mode::Reversi => {
+                            game = Game { theme: game.theme, ..Game::reversi(reversi::DEFAULT_SIZE) };
                         }
-                        Mode::Checkers => {
-                            game = Game::checkers();
+                        Mode::OrderAndChaos => {
+                            game = Game { theme: game.theme, ..Game::order_and_chaos() };
                         }
+                        Mode::Hex => {
+                            game = Game { theme: game.theme, ..Game::hex(hex::DEFAULT_SIZE) };
+                        }
+                        // not offered until they can be played, see `Mode::PLAYABLE`
+                        Mode::Chess | Mode::Checkers => (),
                     }
                 }
             }
-            Some(2) => {
-                match game.current_mode {
-                    Mode::ConnectFour | Mode::TicTacToe => {      
-                        if let Some(new_size) = get_new_size(BOARD_SIZE_SEL) {
-                            game.size = new_size;
-                            game.board = vec![Sprite::default(); game.size * game.size];
-                        }
-                    }
-                    _ => {
-                        println!("Error: You can only edit board size for `tic-tac-toe` or `connect-4`");
+            Some(2) => game = edit_size(console, game),
+            Some(3) => {
+                if let Some(new_theme) = get_new_theme(console, THEME_SEL) {
+                    game.theme = new_theme;
+                }
+            }
+            Some(4) => {
+                if Rules::for_mode(game.current_mode).len() == 1 {
+                    console.println(format!("Error: {mode} is only played by one set of rules", mode = game.current_mode));
+                } else if let Some(new_rules) = get_new_rules(console, game.current_mode) {
+                    // Notakto is played on boards side by side, and every
+                    // other Tic-Tac-Toe game on one
+                    if game.current_mode == Mode::TicTacToe {
+                        let boards = if new_rules == Rules::Notakto { ttt_variants::NOTAKTO_BOARDS } else { 1 };
+
+                        game.width = game.height * boards;
+                        game.board = vec![None; game.width * game.height];
                     }
+
+                    game.rules = new_rules;
                 }
             }
-            _ => eprintln!("Invalid selection. Try again."),
+            _ => console.println("Invalid selection. Try again."),
         }
     }
 
     game
 }
 
-/// This function is used to display a prompt and get a game mode for the new 
-/// game. It automatically filters out incorrect output.
+/// This function is used to change the size of the board in the modes that
+/// can be resized, asking for it the way the mode needs it. Notakto is resized
+/// by how many boards it is played on.
 ///
 /// # Arguments
 ///
-/// * `message` - \
-///      A string literal used for the user prompt
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `game` - \
+///     The Game struct being edited
 ///
 /// # Returns
 ///
-/// * `Some(Mode::TicTacToe)` - \
-///      Represents Tic-Tac-Toe and sets up the game with those rules
-/// * `Some(Mode::ConnectFour)` - \
-///      Represents Connect-4 and sets up the game with those rules
-/// * `Some(Mode::Chess)` - \
-///      Represents Chess and sets up the game with those rules
-/// * `Some(Mode::Checkers)` - \
-///      Represents Checkers and sets up the game with those rules
+/// * `Game` - \
+///     The game with its new size, or as it was if the user kept it
+/// 
+/// # Examples
+/// 
+/// Basic Usage:
+/// 
+/// ```ignore
+/// use super::game::Game;
+///
+/// let mut console = Console::stdio();
+/// let mut game = Game::tic_tac_toe();
+///
+/// game = edit_size(&mut console, game);
+///
+/// println!("{game}");
+/// ```
+fn edit_size<R: BufRead, W: Write>(console: &mut Console<R, W>, mut game: Game) -> Game {
+    match game.current_mode {
+        // Notakto's boards stay the same size and more are added
+        Mode::TicTacToe if game.rules == Rules::Notakto => {
+            match get_int_input(console, NOTAKTO_BOARDS_SEL) {
+                Some(0) => console.println("Error: Notakto needs at least one board"),
+                Some(boards) if boards > ttt_variants::MAX_NOTAKTO_BOARDS => {
+                    console.println(format!("Error: Notakto can be played on at most {max} boards", max = ttt_variants::MAX_NOTAKTO_BOARDS));
+                }
+                Some(boards) => {
+                    game.width = game.height * boards;
+                    game.board = vec![None; game.width * game.height];
+                }
+                None => console.println("Keeping the current boards..."),
+            }
+        }
+        Mode::ConnectFour | Mode::TicTacToe => {      
+            if let Some(new_size) = get_new_size(console, BOARD_SIZE_SEL) {
+                game.width = new_size;
+                game.height = new_size;
+                game.win_length = new_size;
+                game.board = vec![None; game.width * game.height];
+            }
+        }
+        Mode::Qubic => {
+            if let Some(new_size) = get_new_size(console, BOARD_SIZE_SEL) {
+                game = Game { theme: game.theme, ..Game::qubic(new_size) };
+            }
+        }
+        Mode::Gomoku => {
+            match get_new_size(console, BOARD_SIZE_SEL) {
+                Some(new_size) if new_size < gomoku::WIN_LENGTH => {
+                    console.println("Error: A Gomoku board must fit five in a row");
+                }
+                Some(new_size) => {
+                    game.width = new_size;
+                    game.height = new_size;
+                    game.board = vec![None; game.width * game.height];
+                }
+                None => (),
+            }
+        }
+        Mode::Reversi => {
+            match get_new_size(console, BOARD_SIZE_SEL) {
+                Some(new_size) if !new_size.is_multiple_of(2) => {
+                    console.println("Error: A Reversi board must have an even size to start from the middle");
+                }
+                Some(new_size) => {
+                    game = Game { theme: game.theme, ..Game::reversi(new_size) };
+                }
+                None => (),
+            }
+        }
+        Mode::Hex => {
+            match get_new_size(console, BOARD_SIZE_SEL) {
+                Some(new_size) if new_size > hex::MAX_SIZE => {
+                    console.println(format!("Error: A Hex board can be at most {max} across, one letter for each column", max = hex::MAX_SIZE));
+                }
+                Some(new_size) => {
+                    game = Game { theme: game.theme, rules: game.rules, ..Game::hex(new_size) };
+                }
+                None => (),
+            }
+        }
+        _ => {
+            console.println("Error: You can only edit board size for `tic-tac-toe`, `connect-4`, `qubic`, `gomoku`, `reversi` or `hex`");
+        }
+    }
+
+    game
+}
+
+/// This function is used to display the modes that can be played and get one
+/// for the new game. It automatically filters out incorrect output.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+///
+/// # Returns
+///
+/// * `Some(Mode)` - \
+///      One of `Mode::PLAYABLE`, which the game is then set up to play
 /// * `None` - \
 ///      A value that can be used to tell the calling function
 ///      that it does not need to change
-///
-/// # Panics
-///
-/// Will panic if input filtering did not work properly; there should be no 
-/// reason for this to happen but it is here for safety sake.
 /// 
 /// # Examples
 /// 
 /// Basic Usage:
 /// 
-/// ```
+/// ```ignore
 /// use super::game::{Mode};
 ///
+/// let mut console = Console::stdio();
 /// let mut mode = Mode::ConnectFour;
 ///
 /// println!("{mode}");
 ///
-/// mode = match get_new_mode("Edit the mode: ") {
+/// mode = match get_new_mode(&mut console) {
 ///     Some(new_mode) => new_mode,
 ///     None => mode,
 /// };
 ///
 /// println!("{mode}");
 /// ```
-fn get_new_mode(message: &str) -> Option<Mode> {
-    let mut new_mode: Option<usize> = None;
+fn get_new_mode<R: BufRead, W: Write>(console: &mut Console<R, W>) -> Option<Mode> {
+    let mut message = String::from("Which game mode would you like to play?");
 
-    loop {
-        if let Some(val) = get_int_input(message) {
-            if val == 0 || val > 4 {
-                eprintln!("invalid selection.");
-
-                continue;
-            }
+    for (number, mode) in Mode::PLAYABLE.iter().enumerate() {
+        message = format!("{message} \n`{number}` {mode}", number = number + 1);
+    }
 
-            new_mode = Some(val);
+    message = format!("{message} \n`q` To keep current mode \nSelection: ");
 
-            break;
-        } else {
-            eprintln!("Keeping the current mode...");
+    loop {
+        match get_int_input(console, &message) {
+            None => {
+                console.println("Keeping the current mode...");
 
-            break;
+                return None;
+            }
+            Some(val) if (1..=Mode::PLAYABLE.len()).contains(&val) => {
+                return Some(Mode::PLAYABLE[val - 1]);
+            }
+            Some(_) => {
+                console.println("invalid selection.");
+            }
         }
     }
-
-    match new_mode {
-        None => None,
-        Some(1) => Some(Mode::TicTacToe),
-        Some(2) => Some(Mode::ConnectFour),
-        Some(3) => Some(Mode::Chess),
-        Some(4) => Some(Mode:: Checkers),
-        _ => panic!("Something went terribly wrong in the game editor with changing \
-                    in get_mode"),
-    }
 }
 
 /// This function is used to display a prompt and get a new board size for the 
@@ -158,6 +297,8 @@ fn get_new_mode(message: &str) -> Option<Mode> {
 ///
 /// # Arguments
 ///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
 /// * `message` - \
 ///     A string literal used for the user prompt
 ///
@@ -178,35 +319,34 @@ fn get_new_mode(message: &str) -> Option<Mode> {
 /// 
 /// Basic Usage:
 /// 
-/// ```
+/// ```ignore
 /// use super::game::{Game, Mode};
 ///
+/// let mut console = Console::stdio();
 /// let mut game = Game::tic_tac_toe();
 /// let mut previous_size = 3;
 ///
 /// println!("{game}");
 ///
-/// game.size = match new_sprite("Edit the size: ") {
+/// game.width = match get_new_size(&mut console, "Edit the size: ") {
 ///     Some(new_size) => new_size,
 ///     None => previous_size,
 /// };
 ///
 /// println!("{game}");
 /// ```
-fn get_new_size(message: &str) -> Option<usize> {
+fn get_new_size<R: BufRead, W: Write>(console: &mut Console<R, W>, message: &str) -> Option<usize> {
     let mut new_size: Option<usize> = None;
 
     loop {
-        match get_int_input(message) {
+        match get_int_input(console, message) {
             None => {
-                eprintln!("Keeping the current size...");
+                console.println("Keeping the current size...");
 
                 break;
             }
-            Some(1 | 2) => {
-                    eprintln!("Must enter a value above `3`. Try again.");
-
-                continue;
+            Some(0..=2) => {
+                console.println("Must enter a value above `3`. Try again.");
             }
             Some(val) => {
                 new_size = Some(val);
@@ -216,5 +356,126 @@ fn get_new_size(message: &str) -> Option<usize> {
         }
     }
 
-    new_size.map(|val| val)
-}
\ No newline at end of file
+    new_size
+}
+
+/// This function is used to display a prompt and get a new theme for drawing
+/// the board. If the user chooses to not edit the current value, it will
+/// return `None`.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `message` - \
+///     A string literal used for the user prompt
+///
+/// # Returns
+///
+/// * `Some(Theme)` - \
+///     The palette the board should be drawn with
+/// * `None` - \
+///     A value that can be used to tell the calling function that it does not 
+///     need to change
+/// 
+/// # Examples
+/// 
+/// Basic Usage:
+/// 
+/// ```ignore
+/// use super::game::Game;
+///
+/// let mut console = Console::stdio();
+/// let mut game = Game::tic_tac_toe();
+///
+/// if let Some(new_theme) = get_new_theme(&mut console, "Edit the theme: ") {
+///     game.theme = new_theme;
+/// }
+///
+/// println!("{theme}", theme = game.theme);
+/// ```
+fn get_new_theme<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    message: &str,
+) -> Option<Theme> {
+    loop {
+        match get_int_input(console, message) {
+            None => {
+                console.println("Keeping the current theme...");
+
+                return None;
+            }
+            Some(val) if (1..=Theme::ALL.len()).contains(&val) => {
+                return Some(Theme::ALL[val - 1]);
+            }
+            Some(_) => {
+                console.println("invalid selection.");
+            }
+        }
+    }
+}
+
+/// This function is used to display the rules a mode can be played by and get
+/// the ones for the new game. If the user chooses to not edit the current
+/// value, it will return `None`.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `mode` - \
+///     The mode whose rules are listed, see `Rules::for_mode`
+///
+/// # Returns
+///
+/// * `Some(Rules)` - \
+///     The rules the game should be played by
+/// * `None` - \
+///     A value that can be used to tell the calling function that it does not 
+///     need to change
+/// 
+/// # Examples
+/// 
+/// Basic Usage:
+/// 
+/// ```ignore
+/// use super::game::{Game, Rules};
+///
+/// let mut console = Console::stdio();
+/// let mut game = Game::gomoku(Rules::Standard);
+///
+/// if let Some(new_rules) = get_new_rules(&mut console, game.current_mode) {
+///     game.rules = new_rules;
+/// }
+///
+/// println!("{rules}", rules = game.rules);
+/// ```
+fn get_new_rules<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    mode: Mode,
+) -> Option<Rules> {
+    let all_rules = Rules::for_mode(mode);
+    let mut message = String::from("Which rules would you like to play by?");
+
+    for (number, rules) in all_rules.iter().enumerate() {
+        message = format!("{message} \n`{number}` {rules}", number = number + 1);
+    }
+
+    message = format!("{message} \n`q` To keep the current rules \nSelection: ");
+
+    loop {
+        match get_int_input(console, &message) {
+            None => {
+                console.println("Keeping the current rules...");
+
+                return None;
+            }
+            Some(val) if (1..=all_rules.len()).contains(&val) => {
+                return Some(all_rules[val - 1]);
+            }
+            Some(_) => {
+                console.println("invalid selection.");
+            }
+        }
+    }
+}
@@ -1,5 +1,10 @@
-use super::game::{Game, Mode, State, MoveStatus};
-use super::player::Sprite;
+use std::mem;
+use super::game::{Cell, Game, Mode, Move, Rules, State, MoveStatus, Piece};
+use super::{gomoku, hex, order_chaos, popout, qubic, reversi, ttt_variants, ultimate};
+
+/// How many times the same position has to come up for the game to be drawn,
+/// under rules where a game could otherwise go on forever
+pub const REPEATS_TO_DRAW: usize = 3;
 
 /// This function is used to validate a players move based on the player's 
 /// input.
@@ -8,15 +13,11 @@ use super::player::Sprite;
 ///
 /// * `board` - \
 ///    A references to the game's board
-/// * `size` - \
-///    An unsigned integer that is used to represent one axis of the board's
-///    size. (i.e. `size` for tic-tac-toe would be 3)
+/// * `width` - \
+///    An unsigned integer that is used to represent the number of columns on 
+///    the board. (i.e. `width` for tic-tac-toe would be 3)
 /// * `mode` - \
 ///    Represents the games current mode via an enumerator
-/// * `current_player` - \
-///    A Sprite reference that is used to represent the current player
-/// * `other_player` - \
-///    A Sprite reference that is used to represent the opposite player
 /// * `selection` - \
 ///    An unsigned integer recieved from the player's input
 ///
@@ -32,58 +33,344 @@ use super::player::Sprite;
 /// Basic Usage:
 ///
 /// ```
-/// use super::game::{Mode, MoveStatus};
-/// use super::player::Sprite;
-///
-/// let mut game = Game::tictactoe();
-/// let player_1 = Player::human(String::from("P1"), Sprite::new("X"));
-/// let player_2 = Player::human(String::from("P2"), Sprite::new("O"));
-///
-/// game = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     1,
-/// );
-/// match validate_move(
-///     &game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_2.sprite, 
-///     &player_1.sprite, 
-///     1,
-/// ) {
-///     MoveStatus::Valid => (),
-///     MoveStatus::Invalid(err) => {
-///         eprintln!("A player is already there,")
-///     }
-/// }
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::{edit_board, ttt_cnct_four_board_move_chck};
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// // player 1 takes the top left cell
+/// game.board = edit_board(game.board, game.width, game.current_mode, 1, 1);
+///
+/// let status = ttt_cnct_four_board_move_chck(&game.board, game.width, game.current_mode, 1);
+///
+/// assert_eq!(status, MoveStatus::Invalid("A player was already there"));
 /// ```
-pub fn ttt_cnct_four_board_move_chck<'a>(
-    board: &'a [Sprite],
-    size: usize, 
+#[must_use]
+pub fn ttt_cnct_four_board_move_chck(
+    board: &[Cell],
+    width: usize, 
     mode: Mode,
-    current_player: &'a Sprite, 
-    other_player: &'a Sprite, 
     selection: usize
-) -> MoveStatus<'a> {
-    if selection == 0 || selection > size * size {
+) -> MoveStatus<'static> {
+    if selection == 0 || selection > board.len() {
         return MoveStatus::Invalid("Selected cell was out of range")
     }
 
-    if board[selection - 1] == *current_player || board[selection - 1] == *other_player {
+    if board[selection - 1].is_some() {
         return MoveStatus::Invalid("A player was already there")
     }
 
-    if selection > size  && mode == Mode::ConnectFour {
+    if selection > width && mode == Mode::ConnectFour {
         return MoveStatus::Invalid("Selected an invalid column")
     }
 
     MoveStatus::Valid
 }
 
+/// A funtion that, when called, is used to get the moves the current player is
+/// allowed to make
+///
+/// # Arguments
+/// 
+/// * `board` - \
+///    A references to the game's board
+/// * `width` - \
+///    An unsigned integer that is used to represent the number of columns on 
+///    the board. (i.e. `width` for tic-tac-toe would be 3)
+/// * `mode` - \
+///    Represents the games current mode via an enumerator
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///     A vector of unsigned integers that represent the moves that can be made
+///                  
+/// # Examples
+/// 
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::get_valid_moves;
+///
+/// let game = Game::connect_four();
+/// let valid_moves = get_valid_moves(&game.board, game.width, game.current_mode);
+///
+/// assert_eq!(valid_moves, vec![1, 2, 3, 4]);
+/// ```
+#[must_use]
+pub fn get_valid_moves(board: &[Cell], width: usize, mode: Mode) -> Vec<usize> {
+    let mut valid_moves = Vec::new();
+
+    match mode {
+        Mode::TicTacToe | Mode::UltimateTicTacToe | Mode::Qubic | Mode::Gomoku | Mode::Hex => {
+            for (index, cell) in board.iter().enumerate() {
+                if cell.is_none() {
+                    valid_moves.push(index + 1);
+                }
+            }
+        } 
+        Mode::ConnectFour => {
+            for (index, _) in board.iter().enumerate().take(width) {
+                if board[index].is_none() {
+                    valid_moves.push(index + 1);
+                }
+            }
+        }
+        // Reversi's moves depend on whose turn it is and Order and Chaos's
+        // carry a mark, see `valid_moves`
+        Mode::Chess | Mode::Checkers | Mode::Reversi | Mode::OrderAndChaos => (),
+    }
+
+    valid_moves
+}
+
+/// This function is used to validate a move in any mode. Grid games only need
+/// the board, but some rules (like Ultimate Tic-Tac-Toe's) also look at the
+/// moves played before, so this is what frontends should use.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    A reference to the game being played
+/// * `selection` - \
+///    An unsigned integer recieved from the player's input
+///
+/// # Returns
+///
+/// * `MoveStatus::Valid` - \
+///    An enum used to represent a valid move
+/// * `MoveStatus::Invalid(error_str)` - \
+///    An enum used to represent an invalid move that returns an error message
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::check_move;
+///
+/// let game = Game::connect_four();
+///
+/// assert_eq!(check_move(&game, 2), MoveStatus::Valid);
+/// assert_eq!(check_move(&game, 9), MoveStatus::Invalid("Selected an invalid column"));
+/// ```
+#[must_use]
+pub fn check_move(game: &Game, selection: usize) -> MoveStatus<'static> {
+    match game.current_mode {
+        Mode::UltimateTicTacToe => ultimate::check_move(game, selection),
+        Mode::Gomoku => gomoku::check_move(game, selection),
+        Mode::Reversi => reversi::check_move(game, selection),
+        Mode::OrderAndChaos => order_chaos::check_move(game, selection),
+        Mode::Hex => hex::check_move(game, selection),
+        Mode::ConnectFour if game.rules == Rules::PopOut => popout::check_move(game, selection),
+        Mode::TicTacToe if game.rules != Rules::Standard => ttt_variants::check_move(game, selection),
+        _ => ttt_cnct_four_board_move_chck(&game.board, game.width, game.current_mode, selection),
+    }
+}
+
+/// This function gives every move the next player is allowed to make in any
+/// mode.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    A reference to the game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///     The moves that can be made
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::valid_moves;
+///
+/// assert_eq!(valid_moves(&Game::connect_four()), vec![1, 2, 3, 4]);
+/// assert_eq!(valid_moves(&Game::ultimate_tic_tac_toe()).len(), 81);
+/// ```
+#[must_use]
+pub fn valid_moves(game: &Game) -> Vec<usize> {
+    match game.current_mode {
+        Mode::UltimateTicTacToe => ultimate::valid_moves(game),
+        Mode::Gomoku => gomoku::valid_moves(game),
+        Mode::Reversi => reversi::valid_moves(game),
+        Mode::OrderAndChaos => order_chaos::valid_moves(game),
+        Mode::Hex => hex::valid_moves(game),
+        Mode::ConnectFour if game.rules == Rules::PopOut => popout::valid_moves(game),
+        Mode::TicTacToe if game.rules != Rules::Standard => ttt_variants::valid_moves(game),
+        _ => get_valid_moves(&game.board, game.width, game.current_mode),
+    }
+}
+
+/// This function reads a move the way it is typed in the current mode. Most
+/// games take the number of a cell or column, while Qubic also takes a layer,
+/// row and column, Gomoku and Reversi a row and column, `PopOut` a column to
+/// pop a disc out of, Order and Chaos a mark and a cell, Hex a column letter
+/// and a row, and the Tic-Tac-Toe variants a mark, a board or a slide (see
+/// `ttt_variants::parse_move`).
+///
+/// # Arguments
+///
+/// * `game` - \
+///    A reference to the game being played
+/// * `input` - \
+///    What the player typed
+///
+/// # Returns
+///
+/// * `Some(usize)` - \
+///    The move, which still has to be checked by `check_move`
+/// * `None` - \
+///    The input isn't a move
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::read_move;
+///
+/// assert_eq!(read_move(&Game::tic_tac_toe(), " 5 "), Some(5));
+/// assert_eq!(read_move(&Game::qubic(4), "1 2 3"), Some(7));
+/// assert_eq!(read_move(&Game::tic_tac_toe(), "1 2 3"), None);
+/// ```
+#[must_use]
+pub fn read_move(game: &Game, input: &str) -> Option<usize> {
+    match game.current_mode {
+        Mode::Qubic => qubic::parse_move(game.width, input),
+        Mode::Gomoku | Mode::Reversi => gomoku::parse_move(game.width, game.height, input),
+        Mode::OrderAndChaos => order_chaos::parse_move(game, input),
+        Mode::Hex => hex::parse_move(game, input),
+        Mode::ConnectFour if game.rules == Rules::PopOut => popout::parse_move(game.width, game.height, input),
+        Mode::TicTacToe if game.rules != Rules::Standard => ttt_variants::parse_move(game, input),
+        _ => input.trim().parse().ok(),
+    }
+}
+
+/// This function gives the seat of the player who has to move next in a game
+/// only two can play, where the rules need to know it (like which discs are
+/// theirs in Reversi). The player who moved first this round moves on even
+/// turns, and since a player who has to pass still uses up a turn, this holds
+/// for the whole game.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    A reference to the game being played
+///
+/// # Returns
+///
+/// * `usize` - \
+///    The seat of the player to move, `1` or `2`
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::{play_move, player_to_move};
+///
+/// let mut game = Game::reversi(8);
+///
+/// assert_eq!(player_to_move(&game), 1);
+///
+/// play_move(&mut game, 1, 20);
+///
+/// assert_eq!(player_to_move(&game), 2);
+/// ```
+#[must_use]
+pub fn player_to_move(game: &Game) -> usize {
+    let first_player = game.first_player.clamp(1, 2);
+
+    if game.num_of_turns.is_multiple_of(2) {
+        first_player
+    } else {
+        3 - first_player
+    }
+}
+
+/// This function plays a move that has already been checked: the board is
+/// edited, the move is added to the game's moves, the turn is counted and the
+/// game's state is updated for the player who made it. In Reversi, when the
+/// next player has no legal move they pass, which is counted as a turn too.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `current_player` - \
+///    The seat of the player making the move, counted from 1
+/// * `selection` - \
+///    The move, as checked by `check_move`
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, State};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// for (current_player, selection) in [(1, 1), (2, 4), (1, 2), (2, 5), (1, 3)] {
+///     play_move(&mut game, current_player, selection);
+/// }
+///
+/// assert_eq!(game.current_state, State::Won);
+/// assert_eq!(game.moves, vec![1, 4, 2, 5, 3]);
+/// ```
+pub fn play_move(game: &mut Game, current_player: usize, selection: usize) {
+    let played = read_selection(game, selection);
+
+    game.board = apply_move(mem::take(&mut game.board), game.width, game.current_mode, current_player, played);
+    game.moves.push(selection);
+    game.update_turns();
+    game.current_state = change_status(game, current_player);
+
+    if game.current_mode == Mode::Reversi && game.current_state == State::NotOver && valid_moves(game).is_empty() {
+        game.update_turns();
+    }
+}
+
+/// This function is used to give the game a score for how close each player
+/// is to winning in any mode, which the `Ai` uses when it can't search to the
+/// end of the game.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    A reference to the game being played
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is closer to winning, negative when 
+///    the opponents are
+#[must_use]
+pub fn score(game: &Game, player: usize) -> i32 {
+    match game.current_mode {
+        Mode::UltimateTicTacToe => ultimate::score(game, player),
+        Mode::Qubic => qubic::score(game, player),
+        Mode::Gomoku => gomoku::score(game, player),
+        Mode::Reversi => reversi::score(game, player),
+        Mode::OrderAndChaos => order_chaos::score(game, player),
+        Mode::Hex => hex::score(game, player),
+        Mode::TicTacToe if game.rules != Rules::Standard => ttt_variants::score(game, player),
+        _ => window_score(&game.board, game.width, game.win_length, player),
+    }
+}
+
 /// This function is used to take the game board, edit it accordingly and 
 /// return the edited board.
 ///
@@ -91,80 +378,162 @@ pub fn ttt_cnct_four_board_move_chck<'a>(
 ///
 /// * `board` - \
 ///    The games board to use to edit
-/// * `size` - \
-///    An unsigned integer that is used to represent one axis of the board's
-///    size. (i.e. `size` for tic-tac-toe would be 3)
+/// * `width` - \
+///    An unsigned integer that is used to represent the number of columns on 
+///    the board. (i.e. `width` for tic-tac-toe would be 3)
 /// * `mode` - \
 ///    Represents the games current mode via an enumerator
 /// * `current_player` - \
-///    A Sprite reference that is used to represent the current player
-/// * `other_player` - \
-///    A Sprite reference that is used to represent the opposite player
+///    The seat of the player making the move, counted from 1
 /// * `selection` - \
-///    An unsigned integer recieved from the player's input
+///    An unsigned integer recieved from the player's input, read as a `Move`
+///    (see `Move::from_selection`)
 ///
 /// # Returns
 ///
-/// * `Vec<Sprite>` - \
-///    An enum used to represent a valid move
+/// * `Vec<Cell>` - \
+///    The board with the player's piece on it
 ///
 ///  # Examples
 ///
 /// Basic Usage:
 ///
 /// ```
-/// use super::game::{Mode, MoveStatus};
-/// use super::player::Sprite;
+/// use tic_tac_toe_and_connect_4::{Game, Piece};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::edit_board;
 ///
-/// let mut game = Game::tictactoe();
-/// let player_1 = Player::human(String::from("P1"), Sprite::new("X"));
+/// let mut game = Game::connect_four();
 ///
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     1,
-/// );
+/// game.board = edit_board(game.board, game.width, game.current_mode, 1, 1);
 ///
-/// println!("{game}");
+/// // in Connect-4 the piece falls to the bottom of the column
+/// assert_eq!(game.board[12], Some(Piece::stone(1)));
 /// ```
-pub fn edit_board<'a>(
-    mut board: Vec<Sprite>,
-    size: usize,
+#[must_use]
+pub fn edit_board(
+    board: Vec<Cell>,
+    width: usize,
     current_mode: Mode, 
-    current_player: &'a Sprite, 
-    other_player: &'a Sprite, 
+    current_player: usize, 
     selected_cell: usize
-) -> Vec<Sprite> {
-    match current_mode {
-        Mode::TicTacToe => {
-            board[selected_cell - 1] = current_player.clone();
-        }
-        Mode::ConnectFour => {
-            // connect-4 specific rules
-            let mut cell_below = selected_cell + (size - 1);
+) -> Vec<Cell> {
+    apply_move(board, width, current_mode, current_player, Move::from_selection(current_mode, width, selected_cell))
+}
 
-            for _ in 0..size {
-                if board[cell_below] == Sprite::default() && cell_below + size < board.capacity() {
-                    cell_below += size;
-                }
-            }
+/// This function reads what a selection stands for in the game being played.
+/// Most modes read it with `Move::from_selection`, but Order and Chaos, Hex
+/// and some of the Tic-Tac-Toe variants number marks, swaps and slides on
+/// from the last cell (see `Move::selection`).
+///
+/// # Arguments
+///
+/// * `game` - \
+///    A reference to the game being played
+/// * `selection` - \
+///    The move's number, as checked by `check_move`
+///
+/// # Returns
+///
+/// * `Move` - \
+///    What the move does
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Move, PieceKind, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::read_selection;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// assert_eq!(read_selection(&game, 5), Move::Place(5));
+///
+/// game.rules = Rules::Wild;
+///
+/// assert_eq!(read_selection(&game, 14), Move::Mark(5, PieceKind::Nought));
+/// assert_eq!(read_selection(&Game::order_and_chaos(), 44), Move::Mark(8, PieceKind::Nought));
+/// ```
+#[must_use]
+pub fn read_selection(game: &Game, selection: usize) -> Move {
+    let cells = game.board.len();
+
+    match game.current_mode {
+        Mode::OrderAndChaos => order_chaos::read_selection(cells, selection),
+        Mode::Hex => hex::read_selection(cells, selection),
+        Mode::TicTacToe if game.rules != Rules::Standard => ttt_variants::read_selection(game.rules, cells, selection),
+        _ => Move::from_selection(game.current_mode, game.width, selection),
+    }
+}
 
-            // adjust to place the player above the last player
-            if board[cell_below] == *current_player || board[cell_below] == *other_player {
-                cell_below -= size;
+/// This function does what a move says to the board, whichever mode and rules
+/// it was read by (see `read_selection`).
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The games board to use to edit
+/// * `width` - \
+///    An unsigned integer that is used to represent the number of columns on 
+///    the board. (i.e. `width` for tic-tac-toe would be 3)
+/// * `mode` - \
+///    Represents the games current mode via an enumerator
+/// * `current_player` - \
+///    The seat of the player making the move, counted from 1
+/// * `played` - \
+///    The move being played
+///
+/// # Returns
+///
+/// * `Vec<Cell>` - \
+///    The board after the move
+///
+///  # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Move, Piece, PieceKind};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::apply_move;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// game.board = apply_move(game.board, game.width, game.current_mode, 2, Move::Mark(5, PieceKind::Cross));
+///
+/// assert_eq!(game.board[4], Some(Piece { owner: 2, kind: PieceKind::Cross }));
+/// ```
+#[must_use]
+pub fn apply_move(
+    mut board: Vec<Cell>,
+    width: usize,
+    current_mode: Mode,
+    current_player: usize,
+    played: Move,
+) -> Vec<Cell> {
+    match played {
+        Move::Place(cell) if current_mode == Mode::Reversi => return reversi::place(board, width, current_player, cell),
+        Move::Place(cell) => {
+            board[cell - 1] = Some(Piece::stone(current_player));
+        }
+        Move::Drop(column) => {
+            // connect-4 specific rules, the piece falls until it lands
+            // on another piece or the bottom of the board
+            let mut cell_below = column - 1;
+
+            while cell_below + width < board.len() && board[cell_below + width].is_none() {
+                cell_below += width;
             }
 
-            board[cell_below] = current_player.clone();            
+            board[cell_below] = Some(Piece::stone(current_player));
         }
-        Mode::Chess => {
-            board[selected_cell - 1] = current_player.clone();
+        Move::Pop(column) => return popout::pop(board, width, column),
+        Move::Mark(cell, kind) => {
+            board[cell - 1] = Some(Piece { owner: current_player, kind });
         }
-        Mode::Checkers => {
-            board[selected_cell - 1] = current_player.clone();
+        Move::Slide(from, to) => {
+            board[to - 1] = board[from - 1].take();
         }
+        Move::Swap => return hex::swap(board, width, current_player),
     }
 
     board
@@ -177,12 +546,15 @@ pub fn edit_board<'a>(
 /// * `game` - \
 ///    A reference to a Game struct
 /// * `player` - \
-///    A reference to a Sprite struct that was used to represent the player
+///    The seat of the player who just moved, counted from 1
 ///
 /// # Returns
 ///
 /// * `State::Won` - \
 ///    Tells the game that a player has won
+/// * `State::Lost` - \
+///    Tells the game that the player lost, which only happens in Reversi,
+///    `PopOut`, Order and Chaos and the Tic-Tac-Toe variants
 /// * `State::NotOver` - \
 ///    Tella the game that the is still active
 /// * `State::Tie` - \
@@ -193,68 +565,38 @@ pub fn edit_board<'a>(
 /// Basic Usage:
 ///
 /// ```
-/// use super::game::{Game, Mode, MoveStatus};
-/// use super::player::Sprite;
+/// use tic_tac_toe_and_connect_4::{Game, State};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::{change_status, edit_board};
 ///
 /// let mut game = Game::tic_tac_toe();
-/// let player_1 = Player::human(String::from("P1"), Sprite::new("X"));
-/// let player_2 = Player::human(String::from("P2"), Sprite::new("0"));
-///
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     1,
-/// );
-/// game.update_turns();
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     2,
-/// );
-/// game.update_turns();
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     3,
-/// );
-/// game.update_turns();
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     5,
-/// );
-/// game.update_turns();
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     6,
-/// );
-/// game.update_turns();
-///
-/// assert_equals!(game.state, State::Won);
-/// ```
-pub fn change_status(game: &Game, player: &Sprite) -> State  {
-    if game.num_of_turns >= game.size + 2 {
-        if ttt_cnct_four_board_check(&game.board, game.size, player) {
-            eprintln!("fuck a duck");
+///
+/// for (current_player, selection) in [(1, 1), (2, 5), (1, 2), (2, 6), (1, 3)] {
+///     game.board = edit_board(game.board, game.width, game.current_mode, current_player, selection);
+///     game.update_turns();
+/// }
+///
+/// assert_eq!(change_status(&game, 1), State::Won);
+/// assert_eq!(change_status(&game, 2), State::NotOver);
+/// ```
+#[must_use]
+pub fn change_status(game: &Game, player: usize) -> State  {
+    match game.current_mode {
+        Mode::UltimateTicTacToe => return ultimate::status(game, player),
+        Mode::Qubic => return qubic::status(game, player),
+        Mode::Gomoku => return gomoku::status(game, player),
+        Mode::Reversi => return reversi::status(game, player),
+        Mode::OrderAndChaos => return order_chaos::status(game, player),
+        Mode::Hex => return hex::status(game, player),
+        Mode::ConnectFour if game.rules == Rules::PopOut => return popout::status(game, player),
+        Mode::TicTacToe if game.rules != Rules::Standard => return ttt_variants::status(game, player),
+        _ => (),
+    }
 
+    // the first player can't have a full line until they've had `win_length` turns
+    if game.num_of_turns >= game.win_length * 2 - 1 {
+        if ttt_cnct_four_board_check(&game.board, game.width, game.win_length, player) {
             return State::Won;
-        } else if game.num_of_turns == game.board.capacity() {  
+        } else if game.num_of_turns == game.board.len() {  
             return State::Tie;
         }
     }
@@ -268,11 +610,13 @@ pub fn change_status(game: &Game, player: &Sprite) -> State  {
 ///
 /// * `board` - \
 ///    The games board to use to edit
-/// * `size` - \
-///    An unsigned integer that is used to represent one axis of the board's
-///    size. (i.e. `size` for tic-tac-toe would be 3)
+/// * `width` - \
+///    An unsigned integer that is used to represent the number of columns on 
+///    the board. (i.e. `width` for tic-tac-toe would be 3)
+/// * `win_length` - \
+///    An unsigned integer for how many pieces in a line are needed to win
 /// * `player` - \
-///    A Sprite reference that is used to represent the current player
+///    The seat of the player to check, counted from 1
 ///
 /// # Returns
 ///
@@ -284,127 +628,317 @@ pub fn change_status(game: &Game, player: &Sprite) -> State  {
 /// Basic Usage:
 ///
 /// ```
-/// use super::game::{Mode, MoveStatus};
-/// use super::player::Sprite;
-///
-/// let mut game = Game::tictactoe();
-/// let player_1 = Player::human(String::from("P1"), Sprite::new("X"));
-///
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     1,
-/// );
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     2,
-/// );
-/// game.board = edit_board(
-///     game.board, 
-///     game.size, 
-///     game.mode, 
-///     &player_1.sprite, 
-///     &player_2.sprite, 
-///     3,
-/// );
-///
-/// println!("{game}");
-/// ```
-fn ttt_cnct_four_board_check(board: &[Sprite], size: usize, player: &Sprite) -> bool {
-    // testing new column logic
-    for column in 0..size {
-        let mut cell_below: usize = column; 
-        let mut player_counter: usize = 0; // reset counter
-
-        for _ in 0..size {
-            // seeing if the player is there
-            if board[cell_below] == *player {
-                player_counter += 1;
-            }
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::{edit_board, ttt_cnct_four_board_check};
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// for selection in [1, 2, 3] {
+///     game.board = edit_board(game.board, game.width, game.current_mode, 1, selection);
+/// }
+///
+/// assert!(ttt_cnct_four_board_check(&game.board, game.width, game.win_length, 1));
+/// ```
+#[must_use]
+pub fn ttt_cnct_four_board_check(
+    board: &[Cell], 
+    width: usize, 
+    win_length: usize, 
+    player: usize,
+) -> bool {
+    board_has_line(board, width, win_length, |piece| piece.owner == player)
+}
 
-            // did someone win
-            if player_counter == size {
-                return true;
-            }
+/// This function is used to see if there is a line of `win_length` pieces on
+/// the board that all fit what is asked for, like all belonging to a player or
+/// all being Os.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    A reference to the game's board
+/// * `width` - \
+///    An unsigned integer that is used to represent the number of columns on 
+///    the board. (i.e. `width` for tic-tac-toe would be 3)
+/// * `win_length` - \
+///    An unsigned integer for how many pieces in a line are needed
+/// * `in_line` - \
+///    Tells whether a piece can be part of the line
+///
+/// # Returns
+///
+/// * `bool` - \
+///    `true` when there is a line of pieces that fit
+///
+///  # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, PieceKind};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::board_has_line;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// // two players' Os down the middle column
+/// for (owner, cell) in [(1, 1), (2, 4), (1, 7)] {
+///     game.board[cell] = Some(Piece { owner, kind: PieceKind::Nought });
+/// }
+///
+/// assert!(board_has_line(&game.board, game.width, game.win_length, |piece| piece.kind == PieceKind::Nought));
+/// assert!(!board_has_line(&game.board, game.width, game.win_length, |piece| piece.owner == 1));
+/// ```
+#[must_use]
+pub fn board_has_line(
+    board: &[Cell],
+    width: usize,
+    win_length: usize,
+    in_line: impl Fn(&Piece) -> bool,
+) -> bool {
+    let height = board.len() / width;
+    let fits = |cell: &Cell| cell.as_ref().is_some_and(&in_line);
 
-            // moving forward
-            if cell_below + size <= (size * size) {
-                cell_below += size;
+    for (start, cell) in board.iter().enumerate() {
+        if !fits(cell) {
+            continue;
+        }
+
+        for step in line_steps(start, width, height, win_length) {
+            if (0..win_length).all(|offset| fits(&board[start + offset * step])) {
+                return true;
             }
         }
     }
 
-    // new row logic
-    for row in 0..size {
-        let mut next_cell: usize = row * size;
-        let mut player_counter: usize = 0; // reset counter
-
-        for _ in 0..size {
-            // compare cell
-            if board[next_cell] == *player {
-                player_counter += 1;
-            }
+    false
+}
 
-            // see if a player won
-            if player_counter == size {
-                return true;
-            }
+/// This function gives how many times the position on the board, with the same
+/// player to move, has come up this round, by playing the round's moves again
+/// from an empty board. When the moves don't lead to the board (like a
+/// position a bot was sent) it only counts once.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played, by two players
+///
+/// # Returns
+///
+/// * `usize` - \
+///    How many times the position has come up, counting this time
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::{play_move, repetitions};
+///
+/// let mut game = Game::connect_four();
+///
+/// game.rules = Rules::PopOut;
+///
+/// // both players drop a disc, pop it back out and drop it again
+/// for (current_player, selection) in [(1, 1), (2, 2), (1, 5), (2, 6), (1, 1), (2, 2)] {
+///     play_move(&mut game, current_player, selection);
+/// }
+///
+/// assert_eq!(repetitions(&game), 2);
+/// ```
+#[must_use]
+pub fn repetitions(game: &Game) -> usize {
+    let mut board = vec![None; game.board.len()];
+    let mut player = game.first_player.clamp(1, 2);
+    let mut positions = vec![board.clone()];
 
-            // don't increment if were at the end of a row
-            if next_cell + 1 < (size * row) + size {
-                next_cell += 1;
-            }
-        }
+    for selection in &game.moves {
+        board = apply_move(board, game.width, game.current_mode, player, read_selection(game, *selection));
+        player = 3 - player;
+        positions.push(board.clone());
     }
 
-    // new diag logic
-    let mut next_cell: usize = 0;
-    let mut player_counter: usize = 0; // reset counter
+    if board != game.board {
+        return 1;
+    }
 
-    for offset in 0..size {
-        // compare cell
-        if board[next_cell + offset] == *player {
-            player_counter += 1;
-        }
+    // the same player is to move every other position
+    positions.iter().rev().step_by(2).filter(|position| **position == game.board).count()
+}
 
-        // see if they won
-        if player_counter == size {
-            return true;
-        }
+/// This function is used to give the board a score for how close each player
+/// is to winning. Every line of `win_length` cells that only one player has
+/// pieces in is worth the square of how many pieces they have in it.
+/// Lines held by anyone else count against `player`.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    A reference to the game's board
+/// * `width` - \
+///    An unsigned integer that is used to represent the number of columns on 
+///    the board. (i.e. `width` for tic-tac-toe would be 3)
+/// * `win_length` - \
+///    An unsigned integer for how many pieces in a line are needed to win
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is closer to winning, negative when 
+///    the opponents are
+///
+///  # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::window_score;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// game.board[4] = Some(Piece::stone(1));
+///
+/// assert_eq!(window_score(&game.board, game.width, game.win_length, 1), 4);
+/// assert_eq!(window_score(&game.board, game.width, game.win_length, 2), -4);
+/// ```
+#[must_use]
+pub fn window_score(
+    board: &[Cell], 
+    width: usize, 
+    win_length: usize, 
+    player: usize, 
+) -> i32 {
+    let height = board.len() / width;
+    let mut score = 0;
 
-        // next cell
-        if next_cell +  size <= (size * size) {
-            next_cell += size;
+    for start in 0..board.len() {
+        for step in line_steps(start, width, height, win_length) {
+            score += line_score(board, (0..win_length).map(|offset| start + offset * step), player);
         }
     }
 
-    // new anti diag logic
-    let mut next_cell: usize = 0;
-    let mut player_counter: usize = 0; // reset counter
+    score
+}
 
-    for offset in (0..size).rev() {
-        // compare cell
-        if board[next_cell + offset] == *player {
-            player_counter += 1;
-        }
+/// This function scores one line of cells the way `window_score` scores each
+/// line on a grid: the square of how many pieces are in it when only one
+/// player has any there, counting against `player` when it isn't them.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    A reference to the game's board
+/// * `line` - \
+///    The indexes of the cells in the line
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    The line's score, `0` when nobody or more than one player is in it
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::line_score;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// game.board[0] = Some(Piece::stone(2));
+/// game.board[1] = Some(Piece::stone(2));
+///
+/// assert_eq!(line_score(&game.board, [0, 1, 2], 2), 4);
+/// assert_eq!(line_score(&game.board, [0, 1, 2], 1), -4);
+/// ```
+#[must_use]
+pub fn line_score(board: &[Cell], line: impl IntoIterator<Item = usize>, player: usize) -> i32 {
+    let mut player_counter: i32 = 0;
+    let mut other_counter: i32 = 0;
+    let mut line_owner: Option<usize> = None;
+    let mut is_shared = false;
 
-        // see if they won
-        if player_counter == size {
-            return true;
+    for index in line {
+        match owner(&board[index]) {
+            Some(owner) if owner == player => player_counter += 1,
+            Some(owner) => {
+                other_counter += 1;
+                is_shared |= line_owner.is_some_and(|line_owner| line_owner != owner);
+                line_owner = Some(owner);
+            }
+            None => (),
         }
+    }
 
-        // next cell
-        if next_cell + size <= (size * size) {
-            next_cell += size;
-        }
+    // a line with two players in it can't be won by either
+    if other_counter == 0 {
+        player_counter * player_counter
+    } else if player_counter == 0 && !is_shared {
+        -other_counter * other_counter
+    } else {
+        0
     }
+}
 
-    false
-}
\ No newline at end of file
+// gives the seat of the player whose piece is in the cell, if there is one
+fn owner(cell: &Cell) -> Option<usize> {
+    cell.map(|piece| piece.owner)
+}
+
+/// This function gives the distance to the next cell for every line of
+/// `win_length` cells that starts at `start` and fits on the board. Each line
+/// is walked from its top (or left) end so only four directions are needed.
+///
+/// # Arguments
+///
+/// * `start` - \
+///    The index of the cell the lines start from
+/// * `width` - \
+///    How many columns are on the board
+/// * `height` - \
+///    How many rows are on the board
+/// * `win_length` - \
+///    How many cells are in a line
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    How far apart the cells are in each line, `1` along a row and `width`
+///    down a column
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::line_steps;
+///
+/// // the top left cell of Tic-Tac-Toe starts a row, a column and a diagonal
+/// assert_eq!(line_steps(0, 3, 3, 3), vec![1, 3, 4]);
+/// assert!(line_steps(4, 3, 3, 3).is_empty());
+/// ```
+#[must_use]
+pub fn line_steps(start: usize, width: usize, height: usize, win_length: usize) -> Vec<usize> {
+    let row = start / width;
+    let column = start % width;
+    let fits_right = column + win_length <= width;
+    let fits_left = column + 1 >= win_length;
+    let fits_down = row + win_length <= height;
+
+    [
+        (fits_right, 1),                      // row
+        (fits_down, width),                   // column
+        (fits_down && fits_right, width + 1), // diagonal
+        (fits_down && fits_left, width - 1),  // anti diagonal
+    ]
+    .into_iter()
+    .filter_map(|(fits, step)| fits.then_some(step))
+    .collect()
+}
@@ -0,0 +1,870 @@
+use super::game::{Cell, Game, MoveStatus, Piece, Rules, State};
+use super::game_engine::{player_to_move, ttt_cnct_four_board_move_chck, window_score};
+
+/// How many cells are on each side of the board when none is picked
+pub const SIZE: usize = 15;
+/// How many pieces in a row win
+pub const WIN_LENGTH: usize = 5;
+/// How far a move can be from every piece on the board and still be worth the
+/// `Ai` looking at
+const NEAR: usize = 2;
+/// How many moves deep a three is followed to check that the four it makes
+/// isn't forbidden itself
+const MAX_FOUL_DEPTH: usize = 2;
+/// The four ways a line can go, as steps in rows and columns
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+/// An enumerator used to tell why black isn't allowed a move in Renju
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Foul {
+    /// `DoubleThree` - \
+    ///  The move makes two open threes at once
+    DoubleThree,
+    /// `DoubleFour` - \
+    ///  The move makes two fours at once
+    DoubleFour,
+    /// `Overline` - \
+    ///  The move makes six or more in a row
+    Overline,
+}
+
+impl Foul {
+    /// This function gives the error shown to black when they try the move.
+    ///
+    /// # Returns
+    ///
+    /// * `&str` - \
+    ///    Why the move isn't allowed
+    #[must_use]
+    pub fn reason(self) -> &'static str {
+        match self {
+            Foul::DoubleThree => "Black can't make a double-three in Renju",
+            Foul::DoubleFour => "Black can't make a double-four in Renju",
+            Foul::Overline => "Black can't make more than five in a row in Renju",
+        }
+    }
+}
+
+// how a move leaves a line, from the strongest
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Shape {
+    Five,
+    OpenFour,
+    Four,
+    OpenThree,
+    Three,
+    Two,
+    One,
+    Dead,
+}
+
+/// This function reads a move typed as a row and a column, separated by a
+/// space, comma or slash. A single number is read as the cell itself.
+///
+/// # Arguments
+///
+/// * `width` - \
+///    How many columns are on the board
+/// * `height` - \
+///    How many rows are on the board
+/// * `input` - \
+///    What the player typed
+///
+/// # Returns
+///
+/// * `Some(usize)` - \
+///    The cell, counted from 1 row by row from the top left
+/// * `None` - \
+///    The input isn't a cell on the board
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::parse_move;
+///
+/// assert_eq!(parse_move(15, 15, "8 8"), Some(113));
+/// assert_eq!(parse_move(15, 15, "1,15"), Some(15));
+/// assert_eq!(parse_move(15, 15, "113"), Some(113));
+/// assert_eq!(parse_move(15, 15, "16 1"), None);
+/// ```
+#[must_use]
+pub fn parse_move(width: usize, height: usize, input: &str) -> Option<usize> {
+    let numbers = input
+        .split(|character: char| character.is_whitespace() || character == ',' || character == '/')
+        .filter(|number| !number.is_empty())
+        .map(|number| number.parse::<usize>().ok())
+        .collect::<Option<Vec<usize>>>()?;
+
+    match numbers.as_slice() {
+        [cell] => (1..=width * height).contains(cell).then_some(*cell),
+        [row, column] if (1..=height).contains(row) && (1..=width).contains(column) => {
+            Some((row - 1) * width + column)
+        }
+        _ => None,
+    }
+}
+
+/// This function gives the seat of the player with the black stones, who is
+/// whoever moved first this round and the only one held to Renju's forbidden
+/// moves.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `usize` - \
+///    The seat playing black, `1` or `2`
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::black;
+///
+/// let mut game = Game::gomoku(Rules::Renju);
+///
+/// assert_eq!(black(&game), 1);
+///
+/// // in the next game of a series the other player can move first
+/// game.first_player = 2;
+///
+/// assert_eq!(black(&game), 2);
+/// ```
+#[must_use]
+pub fn black(game: &Game) -> usize {
+    game.first_player.clamp(1, 2)
+}
+
+/// This function checks if black playing a cell would be a foul in Renju: a
+/// double-three, a double-four or an overline. Making exactly five is never a
+/// foul, since it wins the game first. A three only counts when the four it
+/// can become isn't a foul itself.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The free cell black would play, counted from 1
+///
+/// # Returns
+///
+/// * `Some(Foul)` - \
+///    Why the move isn't allowed
+/// * `None` - \
+///    Black can play there
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::{foul, Foul};
+///
+/// let mut game = Game::gomoku(Rules::Renju);
+///
+/// // two black stones in the middle row and two more above the middle
+/// for index in [110, 111, 82, 97] {
+///     game.board[index] = Some(Piece::stone(1));
+/// }
+///
+/// // the middle of the board would make an open three both ways
+/// assert_eq!(foul(&game, 113), Some(Foul::DoubleThree));
+/// assert_eq!(foul(&game, 114), None);
+/// ```
+#[must_use]
+pub fn foul(game: &Game, selection: usize) -> Option<Foul> {
+    let mut board = game.board.clone();
+
+    foul_at(&mut board, game.width, game.win_length, black(game), selection - 1, 0)
+}
+
+/// This function is used to validate a move in Gomoku. On top of the cell
+/// being free, black can't make a foul in Renju.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The cell the player picked, counted from 1
+///
+/// # Returns
+///
+/// * `MoveStatus::Valid` - \
+///    The move can be played
+/// * `MoveStatus::Invalid(error_str)` - \
+///    The move can't be played and the error explains why
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus, Piece, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::check_move;
+///
+/// let mut game = Game::gomoku(Rules::Renju);
+///
+/// // black has five in the middle row with a gap in it
+/// for index in [109, 110, 111, 113, 114] {
+///     game.board[index] = Some(Piece::stone(1));
+/// }
+///
+/// assert_eq!(check_move(&game, 113), MoveStatus::Invalid("Black can't make more than five in a row in Renju"));
+///
+/// // it is only a foul in Renju
+/// game.rules = Rules::Freestyle;
+///
+/// assert_eq!(check_move(&game, 113), MoveStatus::Valid);
+/// ```
+///
+/// Black is whoever moves first, so when player 2 starts the round they are
+/// the one held to the fouls:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus, Rules, State};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::check_move;
+///
+/// let mut game = Game::gomoku(Rules::Renju);
+///
+/// game.first_player = 2;
+///
+/// // player 2 fills the top row but for the fifth cell, player 1 plays far away
+/// for (current_player, selection) in [(2, 1), (1, 200), (2, 2), (1, 202), (2, 3), (1, 204), (2, 4), (1, 206), (2, 6), (1, 208)] {
+///     play_move(&mut game, current_player, selection);
+/// }
+///
+/// assert_eq!(check_move(&game, 5), MoveStatus::Invalid("Black can't make more than five in a row in Renju"));
+/// assert_eq!(game.current_state, State::NotOver);
+/// ```
+#[must_use]
+pub fn check_move(game: &Game, selection: usize) -> MoveStatus<'static> {
+    let status = ttt_cnct_four_board_move_chck(&game.board, game.width, game.current_mode, selection);
+
+    if status != MoveStatus::Valid {
+        return status;
+    }
+
+    match is_held_to_fouls(game, player_to_move(game)).then(|| foul(game, selection)).flatten() {
+        Some(foul) => MoveStatus::Invalid(foul.reason()),
+        None => MoveStatus::Valid,
+    }
+}
+
+/// This function gives every move the next player is allowed to make, which is
+/// every free cell besides black's fouls in Renju.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The cells that can be picked, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::valid_moves;
+///
+/// assert_eq!(valid_moves(&Game::gomoku(Rules::Renju)).len(), 225);
+/// ```
+#[must_use]
+pub fn valid_moves(game: &Game) -> Vec<usize> {
+    let is_held_to_fouls = is_held_to_fouls(game, player_to_move(game));
+
+    (1..=game.board.len())
+        .filter(|selection| game.board[selection - 1].is_none())
+        .filter(|selection| !is_held_to_fouls || foul(game, *selection).is_none())
+        .collect()
+}
+
+/// This function is used to check how a Gomoku game stands after a player's
+/// move. Five in a row wins, or more than five in Freestyle (and for white in
+/// Renju), and it is a tie once the board is full.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player who just moved, counted from 1
+///
+/// # Returns
+///
+/// * `State::Won` - \
+///    The player has won the game
+/// * `State::NotOver` - \
+///    The game goes on
+/// * `State::Tie` - \
+///    The board is full and nobody has five in a row
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, Rules, State};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::status;
+///
+/// let mut game = Game::gomoku(Rules::Standard);
+///
+/// // six in a row along the top
+/// for index in 0..6 {
+///     game.board[index] = Some(Piece::stone(2));
+/// }
+///
+/// assert_eq!(status(&game, 2), State::NotOver);
+///
+/// game.rules = Rules::Freestyle;
+///
+/// assert_eq!(status(&game, 2), State::Won);
+/// ```
+#[must_use]
+pub fn status(game: &Game, player: usize) -> State {
+    let is_won = (0..game.board.len())
+        .filter(|index| owner(&game.board[*index]) == Some(player))
+        .any(|index| {
+            DIRECTIONS.iter().any(|direction| {
+                let length = run_length(&game.board, game.width, index, *direction, player);
+
+                counts_as_five(game.rules, player == black(game), length, game.win_length)
+            })
+        });
+
+    if is_won {
+        State::Won
+    } else if game.board.iter().all(Option::is_some) {
+        State::Tie
+    } else {
+        State::NotOver
+    }
+}
+
+/// This function is used to give a Gomoku game a score for how close each
+/// player is to winning, the same way `window_score` scores every grid.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is closer to winning, negative when the
+///    opponents are
+#[must_use]
+pub fn score(game: &Game, player: usize) -> i32 {
+    window_score(&game.board, game.width, game.win_length, player)
+}
+
+/// This function gives the free cells near the pieces on the board, which are
+/// the only moves worth looking at on a board this big. On an empty board it
+/// is the middle cell.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The cells, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::candidate_moves;
+///
+/// let mut game = Game::gomoku(Rules::Standard);
+///
+/// assert_eq!(candidate_moves(&game), vec![113]);
+///
+/// // a piece in the corner has 8 free cells within two steps of it
+/// game.board[0] = Some(Piece::stone(1));
+///
+/// assert_eq!(candidate_moves(&game).len(), 8);
+/// ```
+#[must_use]
+pub fn candidate_moves(game: &Game) -> Vec<usize> {
+    if game.board.iter().all(Option::is_none) {
+        return vec![game.height / 2 * game.width + game.width / 2 + 1];
+    }
+
+    let is_near = |index: usize| {
+        let (row, column) = (index / game.width, index % game.width);
+
+        (row.saturating_sub(NEAR)..=(row + NEAR).min(game.height - 1)).any(|near_row| {
+            (column.saturating_sub(NEAR)..=(column + NEAR).min(game.width - 1))
+                .any(|near_column| game.board[near_row * game.width + near_column].is_some())
+        })
+    };
+
+    (0..game.board.len())
+        .filter(|index| game.board[*index].is_none() && is_near(*index))
+        .map(|index| index + 1)
+        .collect()
+}
+
+/// This function gives the free cells where a player would win by playing.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player, counted from 1
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The cells, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::winning_moves;
+///
+/// let mut game = Game::gomoku(Rules::Standard);
+///
+/// for index in [1, 2, 3, 4] {
+///     game.board[index] = Some(Piece::stone(1));
+/// }
+///
+/// assert_eq!(winning_moves(&game, 1), vec![1, 6]);
+/// assert!(winning_moves(&game, 2).is_empty());
+/// ```
+#[must_use]
+pub fn winning_moves(game: &Game, player: usize) -> Vec<usize> {
+    (0..game.board.len())
+        .filter(|index| game.board[*index].is_none() && makes_five(game, *index, player))
+        .map(|index| index + 1)
+        .collect()
+}
+
+/// This function gives the free cells where a player would make a four, so
+/// that they could win with their next move unless it is blocked. Black's
+/// fouls are left out in Renju.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player, counted from 1
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The cells, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::four_moves;
+///
+/// let mut game = Game::gomoku(Rules::Standard);
+///
+/// for index in [1, 2, 3] {
+///     game.board[index] = Some(Piece::stone(2));
+/// }
+///
+/// assert_eq!(four_moves(&game, 2), vec![1, 5, 6]);
+/// ```
+#[must_use]
+pub fn four_moves(game: &Game, player: usize) -> Vec<usize> {
+    let mut board = game.board.clone();
+    let is_held_to_fouls = is_held_to_fouls(game, player);
+    let is_black = player == black(game);
+    let reach = isize::try_from(game.win_length).unwrap_or(isize::MAX) - 1;
+
+    (0..game.board.len())
+        .filter(|index| {
+            game.board[*index].is_none()
+                && !makes_five(game, *index, player)
+                // a four needs pieces of theirs nearby on one of its lines
+                && DIRECTIONS.iter().any(|direction| {
+                    (-reach..=reach).any(|steps| {
+                        step(game.width, game.height, *index, *direction, steps)
+                            .is_some_and(|cell| steps != 0 && owner(&game.board[cell]) == Some(player))
+                    })
+                })
+        })
+        .filter(|index| {
+            board[*index] = Some(Piece::stone(player));
+
+            let is_four = DIRECTIONS.iter().any(|direction| {
+                (-reach..=reach)
+                    .filter_map(|steps| step(game.width, game.height, *index, *direction, steps))
+                    .any(|cell| {
+                        board[cell].is_none()
+                            && counts_as_five(
+                                game.rules,
+                                is_black,
+                                run_length(&board, game.width, cell, *direction, player),
+                                game.win_length,
+                            )
+                    })
+            });
+
+            board[*index] = None;
+
+            is_four && !(is_held_to_fouls && foul_at(&mut board, game.width, game.win_length, player, *index, 0).is_some())
+        })
+        .map(|index| index + 1)
+        .collect()
+}
+
+/// This function scores how much a move would do for a player, by the shapes
+/// it leaves on each of the four lines through it. Shapes that can't be
+/// stopped, like an open four or a four and an open three together, score far
+/// above the rest. The `Ai` scores a cell for itself and for its opponents to
+/// find where it should build and where it should block.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The free cell, counted from 1
+/// * `player` - \
+///    The seat of the player who would play there, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    How strong the move would be for the player
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::gomoku::threat_score;
+///
+/// let mut game = Game::gomoku(Rules::Standard);
+///
+/// for index in [111, 112, 113] {
+///     game.board[index] = Some(Piece::stone(1));
+/// }
+///
+/// // an open four beats a cell out of the way
+/// assert!(threat_score(&game, 111, 1) > threat_score(&game, 1, 1));
+/// ```
+#[must_use]
+pub fn threat_score(game: &Game, selection: usize, player: usize) -> i32 {
+    let shapes = DIRECTIONS.map(|direction| shape(game, selection - 1, direction, player));
+    let count = |wanted: &[Shape]| shapes.iter().filter(|shape| wanted.contains(shape)).count();
+    let fours = count(&[Shape::OpenFour, Shape::Four]);
+    let open_threes = count(&[Shape::OpenThree]);
+
+    if shapes.contains(&Shape::Five) {
+        100_000
+    } else if shapes.contains(&Shape::OpenFour) || fours > 1 {
+        20_000
+    } else if fours == 1 && open_threes > 0 {
+        10_000
+    } else if open_threes > 1 {
+        5_000
+    } else {
+        shapes
+            .iter()
+            .map(|shape| match shape {
+                Shape::Four => 1_000,
+                Shape::OpenThree => 800,
+                Shape::Three => 100,
+                Shape::Two => 20,
+                Shape::One => 2,
+                _ => 0,
+            })
+            .sum()
+    }
+}
+
+// black has to avoid fouls in Renju
+fn is_held_to_fouls(game: &Game, player: usize) -> bool {
+    game.rules == Rules::Renju && player == black(game)
+}
+
+// checks whether a line this long wins for black or white under the rules
+fn counts_as_five(rules: Rules, is_black: bool, length: usize, win_length: usize) -> bool {
+    match rules {
+        Rules::Freestyle => length >= win_length,
+        Rules::Renju if !is_black => length >= win_length,
+        _ => length == win_length,
+    }
+}
+
+// checks whether the player would win by playing at the free cell
+fn makes_five(game: &Game, index: usize, player: usize) -> bool {
+    DIRECTIONS.iter().any(|direction| {
+        let length = run_length(&game.board, game.width, index, *direction, player);
+
+        counts_as_five(game.rules, player == black(game), length, game.win_length)
+    })
+}
+
+// the cell a number of steps away going one way, if it is on the board
+fn step(width: usize, height: usize, index: usize, direction: (isize, isize), steps: isize) -> Option<usize> {
+    let row = usize::try_from(isize::try_from(index / width).ok()? + direction.0 * steps).ok()?;
+    let column = usize::try_from(isize::try_from(index % width).ok()? + direction.1 * steps).ok()?;
+
+    (row < height && column < width).then_some(row * width + column)
+}
+
+// how many of the player's pieces are next to the cell going back and going
+// forward along a line
+fn run_ends(board: &[Cell], width: usize, index: usize, direction: (isize, isize), player: usize) -> (isize, isize) {
+    let height = board.len() / width;
+    let longest = isize::try_from(width.max(height)).unwrap_or(isize::MAX);
+    let count = |sign: isize| {
+        (1..longest)
+            .take_while(|steps| {
+                step(width, height, index, direction, sign * steps)
+                    .is_some_and(|cell| owner(&board[cell]) == Some(player))
+            })
+            .count()
+    };
+
+    (
+        isize::try_from(count(-1)).unwrap_or(isize::MAX),
+        isize::try_from(count(1)).unwrap_or(isize::MAX),
+    )
+}
+
+// how long the player's line through the cell is, counting the cell as theirs
+fn run_length(board: &[Cell], width: usize, index: usize, direction: (isize, isize), player: usize) -> usize {
+    let (back, forward) = run_ends(board, width, index, direction, player);
+
+    usize::try_from(back + forward + 1).unwrap_or(usize::MAX)
+}
+
+// finds the foul black (the player in the `black_seat` seat) would make at the
+// free cell, trying the moves on the board and taking them back before
+// returning
+fn foul_at(board: &mut [Cell], width: usize, win_length: usize, black_seat: usize, index: usize, depth: usize) -> Option<Foul> {
+    let lengths = DIRECTIONS.map(|direction| run_length(board, width, index, direction, black_seat));
+
+    // five in a row wins, even if the move makes a foul as well
+    if lengths.contains(&win_length) {
+        return None;
+    }
+
+    if lengths.iter().any(|length| *length > win_length) {
+        return Some(Foul::Overline);
+    }
+
+    board[index] = Some(Piece::stone(black_seat));
+
+    let fours: usize = DIRECTIONS
+        .iter()
+        .map(|direction| fours(board, width, win_length, black_seat, index, *direction))
+        .sum();
+    let threes = if fours > 1 {
+        0
+    } else {
+        DIRECTIONS
+            .iter()
+            .filter(|direction| is_three(board, width, win_length, black_seat, index, **direction, depth))
+            .count()
+    };
+
+    board[index] = None;
+
+    if fours > 1 {
+        Some(Foul::DoubleFour)
+    } else if threes > 1 {
+        Some(Foul::DoubleThree)
+    } else {
+        None
+    }
+}
+
+// counts black's fours through the cell along a line, which are the different
+// sets of four pieces that one more move turns into exactly five. An open four
+// is one four that can be finished at either end.
+fn fours(board: &[Cell], width: usize, win_length: usize, black_seat: usize, index: usize, direction: (isize, isize)) -> usize {
+    let height = board.len() / width;
+    let reach = isize::try_from(win_length).unwrap_or(isize::MAX) - 1;
+    let mut fours: Vec<Vec<isize>> = Vec::new();
+
+    for steps in (-reach..=reach).filter(|steps| *steps != 0) {
+        let Some(cell) = step(width, height, index, direction, steps) else {
+            continue;
+        };
+
+        if board[cell].is_some() || run_length(board, width, cell, direction, black_seat) != win_length {
+            continue;
+        }
+
+        let (back, forward) = run_ends(board, width, cell, direction, black_seat);
+
+        // the five has to go through the new piece
+        if !(-back..=forward).contains(&-steps) {
+            continue;
+        }
+
+        let pieces: Vec<isize> = (-back..=forward)
+            .filter(|offset| *offset != 0)
+            .map(|offset| steps + offset)
+            .collect();
+
+        if !fours.contains(&pieces) {
+            fours.push(pieces);
+        }
+    }
+
+    fours.len()
+}
+
+// checks if black has a three through the cell along a line, which is a line
+// that one more move turns into a straight four, as long as that move isn't a
+// foul itself
+fn is_three(
+    board: &mut [Cell],
+    width: usize,
+    win_length: usize,
+    black_seat: usize,
+    index: usize,
+    direction: (isize, isize),
+    depth: usize,
+) -> bool {
+    let height = board.len() / width;
+    let reach = isize::try_from(win_length).unwrap_or(isize::MAX) - 1;
+    let free_cells: Vec<(isize, usize)> = (-reach..=reach)
+        .filter_map(|steps| Some((steps, step(width, height, index, direction, steps)?)))
+        .filter(|(_, cell)| board[*cell].is_none())
+        .collect();
+
+    free_cells.into_iter().any(|(steps, cell)| {
+        board[cell] = Some(Piece::stone(black_seat));
+
+        let is_straight_four = is_straight_four(board, width, win_length, black_seat, index, direction, steps);
+
+        board[cell] = None;
+
+        is_straight_four && (depth >= MAX_FOUL_DEPTH || foul_at(board, width, win_length, black_seat, cell, depth + 1).is_none())
+    })
+}
+
+// checks if black's line through the cell is four long, takes in the piece a
+// number of steps away, and can be made exactly five at both ends
+fn is_straight_four(
+    board: &[Cell],
+    width: usize,
+    win_length: usize,
+    black_seat: usize,
+    index: usize,
+    direction: (isize, isize),
+    steps: isize,
+) -> bool {
+    let height = board.len() / width;
+    let (back, forward) = run_ends(board, width, index, direction, black_seat);
+
+    if usize::try_from(back + forward + 1).ok() != Some(win_length - 1) || !(-back..=forward).contains(&steps) {
+        return false;
+    }
+
+    [-back - 1, forward + 1].into_iter().all(|end| {
+        step(width, height, index, direction, end).is_some_and(|cell| {
+            board[cell].is_none() && run_length(board, width, cell, direction, black_seat) == win_length
+        })
+    })
+}
+
+// how a move at the cell leaves one line through it for the player
+fn shape(game: &Game, index: usize, direction: (isize, isize), player: usize) -> Shape {
+    let (back, forward) = run_ends(&game.board, game.width, index, direction, player);
+    let length = usize::try_from(back + forward + 1).unwrap_or(usize::MAX);
+
+    if counts_as_five(game.rules, player == black(game), length, game.win_length) {
+        return Shape::Five;
+    }
+
+    let win_length = isize::try_from(game.win_length).unwrap_or(isize::MAX);
+    // what is in the cell a number of steps away: `Some(true)` for the
+    // player's piece (or the move itself), `Some(false)` for a free cell and
+    // `None` for off the board or someone else's piece
+    let cell_at = |steps: isize| -> Option<bool> {
+        if steps == 0 {
+            return Some(true);
+        }
+
+        let cell = step(game.width, game.height, index, direction, steps)?;
+
+        match owner(&game.board[cell]) {
+            None => Some(false),
+            Some(owner) if owner == player => Some(true),
+            Some(_) => None,
+        }
+    };
+    let is_free = |steps: isize| cell_at(steps) == Some(false);
+
+    if length == game.win_length - 1 && is_free(-back - 1) && is_free(forward + 1) {
+        return Shape::OpenFour;
+    }
+
+    // the most pieces in any stretch of `win_length` cells with nobody else's
+    // pieces in it
+    let most_pieces = (1 - win_length..=0)
+        .filter_map(|start| {
+            (start..start + win_length)
+                .map(cell_at)
+                .collect::<Option<Vec<bool>>>()
+                .map(|cells| cells.iter().filter(|is_theirs| **is_theirs).count())
+        })
+        .max();
+
+    // a three is open when it fits in a stretch one longer with both ends free
+    let is_open_three = (-win_length..=0).any(|start| {
+        is_free(start)
+            && is_free(start + win_length)
+            && (start + 1..start + win_length)
+                .map(cell_at)
+                .collect::<Option<Vec<bool>>>()
+                .is_some_and(|cells| cells.iter().filter(|is_theirs| **is_theirs).count() == cells.len() - 1)
+    });
+
+    match most_pieces.map(|pieces| game.win_length - pieces) {
+        Some(1) => Shape::Four,
+        _ if is_open_three => Shape::OpenThree,
+        Some(2) => Shape::Three,
+        Some(3) => Shape::Two,
+        Some(_) => Shape::One,
+        None => Shape::Dead,
+    }
+}
+
+// gives the seat of the player whose piece is in the cell, if there is one
+fn owner(cell: &Cell) -> Option<usize> {
+    cell.map(|piece| piece.owner)
+}
@@ -0,0 +1,479 @@
+use std::collections::VecDeque;
+use super::game::{Cell, Game, Mode, Move, MoveStatus, Piece, Rules, State};
+use super::game_engine::ttt_cnct_four_board_move_chck;
+use super::gomoku;
+
+/// The size of the board when none is picked
+pub const DEFAULT_SIZE: usize = 11;
+/// The biggest board that can be played, so that every column has a letter
+pub const MAX_SIZE: usize = 26;
+/// The seat of the player who connects the top and bottom sides. The other
+/// player connects the left and right sides.
+pub const TOP_TO_BOTTOM: usize = 1;
+/// The steps in rows and columns to the 6 cells next to a cell. Each row sits
+/// half a cell further right than the one above it, so the cell up and to the
+/// right and the one down and to the left touch it too.
+const NEIGHBOURS: [(isize, isize); 6] = [(-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0)];
+
+/// This function gives the cells next to a cell on a Hex board, which is a
+/// rhombus where every cell touches six others (fewer along the sides).
+///
+/// # Arguments
+///
+/// * `size` - \
+///    How many cells are on each side of the board
+/// * `index` - \
+///    The index of the cell, row by row from the top left
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The indexes of the cells next to it
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::hex::neighbours;
+///
+/// // the middle of a 3 by 3 board touches all but two corners
+/// assert_eq!(neighbours(3, 4), vec![1, 2, 3, 5, 6, 7]);
+/// assert_eq!(neighbours(3, 0), vec![1, 3]);
+/// ```
+#[must_use]
+pub fn neighbours(size: usize, index: usize) -> Vec<usize> {
+    let (row, column) = (index / size, index % size);
+
+    NEIGHBOURS
+        .iter()
+        .filter_map(|(row_step, column_step)| {
+            let row = row.checked_add_signed(*row_step).filter(|row| *row < size)?;
+            let column = column.checked_add_signed(*column_step).filter(|column| *column < size)?;
+
+            Some(row * size + column)
+        })
+        .collect()
+}
+
+/// This function reads what a selection stands for in Hex. The cells are
+/// numbered from 1, and one past the last cell is the swap (see
+/// `Rules::Swap`).
+///
+/// # Arguments
+///
+/// * `cells` - \
+///    How many cells are on the board
+/// * `selection` - \
+///    The move's number
+///
+/// # Returns
+///
+/// * `Move` - \
+///    What the move does
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Move;
+/// use tic_tac_toe_and_connect_4::game_lib::hex::read_selection;
+///
+/// assert_eq!(read_selection(121, 61), Move::Place(61));
+/// assert_eq!(read_selection(121, 122), Move::Swap);
+/// ```
+#[must_use]
+pub fn read_selection(cells: usize, selection: usize) -> Move {
+    if selection == cells + 1 {
+        Move::Swap
+    } else {
+        Move::Place(selection)
+    }
+}
+
+/// This function reads a move typed in Hex: a column letter and a row number
+/// like `c3`, a row and column as numbers, or `swap`. A single number is read
+/// as the move's selection, the way bots send them.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `input` - \
+///    What the player typed
+///
+/// # Returns
+///
+/// * `Some(usize)` - \
+///    The move's selection (see `read_selection`)
+/// * `None` - \
+///    The input isn't a move
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::hex::parse_move;
+///
+/// let game = Game::hex(11);
+///
+/// assert_eq!(parse_move(&game, "c2"), Some(14));
+/// assert_eq!(parse_move(&game, "C 2"), Some(14));
+/// assert_eq!(parse_move(&game, "2 3"), Some(14));
+/// assert_eq!(parse_move(&game, "swap"), Some(122));
+/// assert_eq!(parse_move(&game, "l1"), None);
+/// ```
+#[must_use]
+pub fn parse_move(game: &Game, input: &str) -> Option<usize> {
+    let input = input.trim().to_lowercase();
+    let cells = game.board.len();
+
+    if input == "swap" {
+        return Some(Move::Swap.selection(game.width, cells));
+    }
+
+    let Some(letter) = input.chars().next().filter(char::is_ascii_lowercase) else {
+        return gomoku::parse_move(game.width, game.height, &input).or_else(| | {
+            input.parse::<usize>().ok().filter(|selection| *selection == cells + 1)
+        });
+    };
+    let column = usize::from(letter as u8 - b'a') + 1;
+    let row = input[1..].trim().parse::<usize>().ok()?;
+
+    ((1..=game.width).contains(&column) && (1..=game.height).contains(&row)).then(| | (row - 1) * game.width + column)
+}
+
+/// This function is used to validate a move in Hex. A stone can go in any
+/// free cell, and under `Rules::Swap` the second move of the game can be a
+/// swap instead.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The move's selection (see `read_selection`)
+///
+/// # Returns
+///
+/// * `MoveStatus::Valid` - \
+///    The move can be played
+/// * `MoveStatus::Invalid(error_str)` - \
+///    The move can't be played and the error explains why
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::hex::check_move;
+///
+/// let mut game = Game::hex(11);
+///
+/// game.rules = Rules::Swap;
+///
+/// assert_eq!(check_move(&game, 122), MoveStatus::Invalid("Only the second move of the game can be a swap"));
+///
+/// play_move(&mut game, 1, 61);
+///
+/// assert_eq!(check_move(&game, 122), MoveStatus::Valid);
+/// ```
+#[must_use]
+pub fn check_move(game: &Game, selection: usize) -> MoveStatus<'static> {
+    match read_selection(game.board.len(), selection) {
+        Move::Swap if game.rules != Rules::Swap => MoveStatus::Invalid("Swapping is only allowed under the swap rule"),
+        Move::Swap if game.num_of_turns != 1 => MoveStatus::Invalid("Only the second move of the game can be a swap"),
+        Move::Swap => MoveStatus::Valid,
+        _ => ttt_cnct_four_board_move_chck(&game.board, game.width, Mode::Hex, selection),
+    }
+}
+
+/// This function gives every move the next player is allowed to make in Hex.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The moves' selections (see `read_selection`)
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::hex::valid_moves;
+///
+/// assert_eq!(valid_moves(&Game::hex(11)).len(), 121);
+/// ```
+#[must_use]
+pub fn valid_moves(game: &Game) -> Vec<usize> {
+    let cells = game.board.len();
+
+    (1..=cells + 1).filter(|selection| check_move(game, *selection) == MoveStatus::Valid).collect()
+}
+
+/// This function plays the swap: the only stone on the board becomes the
+/// swapping player's, reflected across the diagonal from the top left so it
+/// helps them connect their own sides as much as it helped their opponent.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The cells of the board, row by row from the top left
+/// * `size` - \
+///    How many cells are on each side of the board
+/// * `player` - \
+///    The seat of the player swapping, counted from 1
+///
+/// # Returns
+///
+/// * `Vec<Cell>` - \
+///    The board after the swap
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece};
+/// use tic_tac_toe_and_connect_4::game_lib::hex::swap;
+///
+/// let mut game = Game::hex(11);
+///
+/// // b1 becomes a2
+/// game.board[1] = Some(Piece::stone(1));
+/// game.board = swap(game.board, game.width, 2);
+///
+/// assert_eq!(game.board[11], Some(Piece::stone(2)));
+/// assert_eq!(game.board.iter().filter(|cell| cell.is_some()).count(), 1);
+/// ```
+#[must_use]
+pub fn swap(mut board: Vec<Cell>, size: usize, player: usize) -> Vec<Cell> {
+    if let Some(index) = board.iter().position(Option::is_some) {
+        board[index] = None;
+        board[index % size * size + index / size] = Some(Piece::stone(player));
+    }
+
+    board
+}
+
+/// This function checks if a player's stones connect their two sides of the
+/// board, by flooding out from their stones along one side.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The cells of the board, row by row from the top left
+/// * `size` - \
+///    How many cells are on each side of the board
+/// * `player` - \
+///    The seat of the player to check, counted from 1
+///
+/// # Returns
+///
+/// * `bool` - \
+///    `true` when the player's sides are connected
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Piece;
+/// use tic_tac_toe_and_connect_4::game_lib::hex::connects;
+///
+/// let mut board = vec![None; 9];
+///
+/// // a bent line from the top to the bottom of a 3 by 3 board
+/// for index in [2, 4, 7] {
+///     board[index] = Some(Piece::stone(1));
+/// }
+///
+/// assert!(connects(&board, 3, 1));
+/// assert!(!connects(&board, 3, 2));
+/// ```
+#[must_use]
+pub fn connects(board: &[Cell], size: usize, player: usize) -> bool {
+    let is_players = |index: usize| board[index].is_some_and(|piece| piece.owner == player);
+    let mut seen = vec![false; board.len()];
+    let mut to_visit: Vec<usize> = (0..size).map(|step| start_cell(size, player, step)).filter(|index| is_players(*index)).collect();
+
+    while let Some(index) = to_visit.pop() {
+        if seen[index] {
+            continue;
+        }
+
+        if is_far_side(size, player, index) {
+            return true;
+        }
+
+        seen[index] = true;
+        to_visit.extend(neighbours(size, index).into_iter().filter(|next| !seen[*next] && is_players(*next)));
+    }
+
+    false
+}
+
+/// This function is used to check how a Hex game stands after a player's
+/// move. Hex can't end in a tie, since a full board always connects one
+/// player's sides, so the game goes on until somebody connects theirs.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player who just moved, counted from 1
+///
+/// # Returns
+///
+/// * `State::Won` - \
+///    The player has connected their sides
+/// * `State::NotOver` - \
+///    The game goes on
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, State};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::hex::status;
+///
+/// let mut game = Game::hex(3);
+///
+/// // player 1 goes down the first column while player 2 plays beside it
+/// for (current_player, selection) in [(1, 1), (2, 2), (1, 4), (2, 5)] {
+///     play_move(&mut game, current_player, selection);
+/// }
+///
+/// assert_eq!(status(&game, 2), State::NotOver);
+///
+/// play_move(&mut game, 1, 7);
+///
+/// assert_eq!(status(&game, 1), State::Won);
+/// ```
+#[must_use]
+pub fn status(game: &Game, player: usize) -> State {
+    if connects(&game.board, game.width, player) {
+        State::Won
+    } else {
+        State::NotOver
+    }
+}
+
+/// This function is used to give a Hex game a score for how well a player is
+/// doing, by how many more stones their opponent still needs to connect
+/// their sides than they do.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is doing better, negative when the
+///    opponent is
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::hex::score;
+///
+/// let mut game = Game::hex(11);
+///
+/// assert_eq!(score(&game, 1), 0);
+///
+/// play_move(&mut game, 1, 61);
+///
+/// assert_eq!(score(&game, 1), 1);
+/// assert_eq!(score(&game, 2), -1);
+/// ```
+#[must_use]
+pub fn score(game: &Game, player: usize) -> i32 {
+    let opponent = if player == TOP_TO_BOTTOM { 2 } else { TOP_TO_BOTTOM };
+    let to_connect = |seat: usize| i32::try_from(stones_to_connect(&game.board, game.width, seat)).unwrap_or(i32::MAX);
+
+    to_connect(opponent) - to_connect(player)
+}
+
+// finds the fewest stones the player still has to place to connect their
+// sides, where their own stones are free to go through and their opponent's
+// can't be gone through at all. A board they can't connect any more counts
+// as needing every cell.
+fn stones_to_connect(board: &[Cell], size: usize, player: usize) -> usize {
+    let cost = |index: usize| match board[index] {
+        Some(piece) if piece.owner == player => Some(0),
+        Some(_) => None,
+        None => Some(1),
+    };
+    let mut best = vec![usize::MAX; board.len()];
+    let mut to_visit = VecDeque::new();
+
+    for index in (0..size).map(|step| start_cell(size, player, step)) {
+        if let Some(cost) = cost(index) {
+            best[index] = cost;
+            to_visit.push_back(index);
+        }
+    }
+
+    // a cell can be reached more cheaply later on, so it's looked at again
+    // whenever it is
+    while let Some(index) = to_visit.pop_front() {
+        for next in neighbours(size, index) {
+            let Some(cost) = cost(next) else {
+                continue;
+            };
+
+            if best[index] + cost < best[next] {
+                best[next] = best[index] + cost;
+                to_visit.push_back(next);
+            }
+        }
+    }
+
+    (0..board.len())
+        .filter(|index| is_far_side(size, player, *index))
+        .map(|index| best[index])
+        .min()
+        .filter(|stones| *stones != usize::MAX)
+        .unwrap_or(board.len())
+}
+
+// gives the cell a step along the side the player's connection starts from,
+// the top for the player going top to bottom and the left for the other
+fn start_cell(size: usize, player: usize, step: usize) -> usize {
+    if player == TOP_TO_BOTTOM {
+        step
+    } else {
+        step * size
+    }
+}
+
+// checks if the cell is on the side the player's connection ends at
+fn is_far_side(size: usize, player: usize, index: usize) -> bool {
+    if player == TOP_TO_BOTTOM {
+        index / size == size - 1
+    } else {
+        index % size == size - 1
+    }
+}
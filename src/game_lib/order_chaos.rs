@@ -0,0 +1,329 @@
+use super::game::{Game, Mode, Move, MoveStatus, PieceKind, State};
+use super::game_engine::{board_has_line, line_steps, ttt_cnct_four_board_move_chck};
+use super::gomoku;
+
+/// How many cells are on each side of the board
+pub const SIZE: usize = 6;
+/// How many of the same mark in a row Order needs to win
+pub const WIN_LENGTH: usize = 5;
+
+/// This function reads what a selection stands for in Order and Chaos. The
+/// cells are numbered from 1 for an X and on from the last cell for an O (see
+/// `Move::selection`).
+///
+/// # Arguments
+///
+/// * `cells` - \
+///    How many cells are on the board
+/// * `selection` - \
+///    The move's number
+///
+/// # Returns
+///
+/// * `Move` - \
+///    The mark the move places and where
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Move, PieceKind};
+/// use tic_tac_toe_and_connect_4::game_lib::order_chaos::read_selection;
+///
+/// assert_eq!(read_selection(36, 8), Move::Mark(8, PieceKind::Cross));
+/// assert_eq!(read_selection(36, 44), Move::Mark(8, PieceKind::Nought));
+/// ```
+#[must_use]
+pub fn read_selection(cells: usize, selection: usize) -> Move {
+    if selection > cells {
+        Move::Mark(selection - cells, PieceKind::Nought)
+    } else {
+        Move::Mark(selection, PieceKind::Cross)
+    }
+}
+
+/// This function reads a move typed in Order and Chaos: `x` or `o` and then a
+/// cell, as its number or as a row and column. A single number is read as the
+/// move's selection, the way bots send them.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `input` - \
+///    What the player typed
+///
+/// # Returns
+///
+/// * `Some(usize)` - \
+///    The move's selection (see `read_selection`)
+/// * `None` - \
+///    The input isn't a move
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::order_chaos::parse_move;
+///
+/// let game = Game::order_and_chaos();
+///
+/// assert_eq!(parse_move(&game, "x 2 3"), Some(9));
+/// assert_eq!(parse_move(&game, "O9"), Some(45));
+/// assert_eq!(parse_move(&game, "45"), Some(45));
+/// assert_eq!(parse_move(&game, "o 7 1"), None);
+/// ```
+#[must_use]
+pub fn parse_move(game: &Game, input: &str) -> Option<usize> {
+    let input = input.trim().to_lowercase();
+    let cells = game.board.len();
+    let kind = match input.chars().next() {
+        Some('x') => PieceKind::Cross,
+        Some('o') => PieceKind::Nought,
+        _ => return input.parse::<usize>().ok().filter(|selection| (1..=cells * 2).contains(selection)),
+    };
+
+    gomoku::parse_move(game.width, game.height, &input[1..]).map(|cell| Move::Mark(cell, kind).selection(game.width, cells))
+}
+
+/// This function is used to validate a move in Order and Chaos, where either
+/// mark can go in any free cell.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The move's selection (see `read_selection`)
+///
+/// # Returns
+///
+/// * `MoveStatus::Valid` - \
+///    The move can be played
+/// * `MoveStatus::Invalid(error_str)` - \
+///    The move can't be played and the error explains why
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::order_chaos::check_move;
+///
+/// let mut game = Game::order_and_chaos();
+///
+/// play_move(&mut game, 1, 8);
+///
+/// // an O can't go on top of the X either
+/// assert_eq!(check_move(&game, 44), MoveStatus::Invalid("A player was already there"));
+/// assert_eq!(check_move(&game, 73), MoveStatus::Invalid("Selected cell was out of range"));
+/// ```
+#[must_use]
+pub fn check_move(game: &Game, selection: usize) -> MoveStatus<'static> {
+    match read_selection(game.board.len(), selection) {
+        Move::Mark(cell, _) => ttt_cnct_four_board_move_chck(&game.board, game.width, Mode::TicTacToe, cell),
+        _ => MoveStatus::Invalid("Selected cell was out of range"),
+    }
+}
+
+/// This function gives every move the next player is allowed to make in
+/// Order and Chaos, an X or an O in every free cell.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The moves' selections (see `read_selection`)
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::order_chaos::valid_moves;
+///
+/// assert_eq!(valid_moves(&Game::order_and_chaos()).len(), 72);
+/// ```
+#[must_use]
+pub fn valid_moves(game: &Game) -> Vec<usize> {
+    let cells = game.board.len();
+
+    (1..=cells * 2).filter(|selection| game.board[(selection - 1) % cells].is_none()).collect()
+}
+
+/// This function gives the seat of the player who plays Order this round,
+/// which is whoever moved first. The other player plays Chaos.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `usize` - \
+///    The seat playing Order, `1` or `2`
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::order_chaos::order;
+///
+/// let mut game = Game::order_and_chaos();
+///
+/// assert_eq!(order(&game), 1);
+///
+/// game.first_player = 2;
+///
+/// assert_eq!(order(&game), 2);
+/// ```
+#[must_use]
+pub fn order(game: &Game) -> usize {
+    game.first_player.clamp(1, 2)
+}
+
+/// This function is used to check how a game of Order and Chaos stands after
+/// a player's move. The marks don't belong to anyone, so who wins depends on
+/// the roles: a line of `win_length` Xs or Os wins for Order, even when Chaos
+/// was made to finish it, and a full board without one wins for Chaos.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player who just moved, counted from 1
+///
+/// # Returns
+///
+/// * `State::Won` - \
+///    The player has won the game
+/// * `State::Lost` - \
+///    The player has lost the game, so their opponent won
+/// * `State::NotOver` - \
+///    The game goes on
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, State};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::order_chaos::status;
+///
+/// let mut game = Game::order_and_chaos();
+///
+/// // Order (player 1) places Xs along the top row while Chaos places Os
+/// // along the bottom
+/// for (current_player, selection) in [(1, 1), (2, 67), (1, 2), (2, 68), (1, 3), (2, 69), (1, 4), (2, 70)] {
+///     play_move(&mut game, current_player, selection);
+/// }
+///
+/// assert_eq!(status(&game, 2), State::NotOver);
+///
+/// play_move(&mut game, 1, 5);
+///
+/// assert_eq!(status(&game, 1), State::Won);
+/// ```
+#[must_use]
+pub fn status(game: &Game, player: usize) -> State {
+    let order_won = [PieceKind::Cross, PieceKind::Nought]
+        .iter()
+        .any(|kind| board_has_line(&game.board, game.width, game.win_length, |piece| piece.kind == *kind));
+    let is_full = game.board.iter().all(Option::is_some);
+    let is_order = player == order(game);
+
+    match (order_won, is_full) {
+        (true, _) if is_order => State::Won,
+        (true, _) => State::Lost,
+        (false, true) if is_order => State::Lost,
+        (false, true) => State::Won,
+        (false, false) => State::NotOver,
+    }
+}
+
+/// This function is used to give a game of Order and Chaos a score for how
+/// well a player is doing. Every line of `win_length` cells with only one
+/// kind of mark in it is worth the square of how many are there to Order, and
+/// the same against Chaos.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is doing better, negative when the
+///    opponent is
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::order_chaos::score;
+///
+/// let mut game = Game::order_and_chaos();
+///
+/// play_move(&mut game, 1, 15);
+///
+/// assert!(score(&game, 1) > 0);
+/// assert_eq!(score(&game, 2), -score(&game, 1));
+/// ```
+#[must_use]
+pub fn score(game: &Game, player: usize) -> i32 {
+    let height = game.board.len() / game.width;
+    let mut score = 0;
+
+    for start in 0..game.board.len() {
+        for step in line_steps(start, game.width, height, game.win_length) {
+            score += line_score(game, (0..game.win_length).map(|offset| start + offset * step));
+        }
+    }
+
+    if player == order(game) {
+        score
+    } else {
+        -score
+    }
+}
+
+// scores a line for Order: the square of how many marks are in it, as long
+// as they are all the same
+fn line_score(game: &Game, line: impl Iterator<Item = usize>) -> i32 {
+    let mut kinds = line.filter_map(|index| game.board[index].map(|piece| piece.kind));
+    let Some(first) = kinds.next() else {
+        return 0;
+    };
+    let mut count = 1;
+
+    for kind in kinds {
+        if kind != first {
+            return 0;
+        }
+
+        count += 1;
+    }
+
+    count * count
+}
@@ -1,21 +1,426 @@
 use std::fmt;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use super::theme::Colour;
+use super::ai_engine::Difficulty;
+use super::game::{Mode, Piece};
+use super::rating::{Rating, STARTING_RATING};
 
-/// A List struct to contain two different players
+/// The most players a match can have, one for each seat the engine protocol
+/// can name (`1` to `9` then `a` to `z`)
+pub const MAX_PLAYERS: usize = 35;
+/// Sprites handed out to new players, in order, before falling back to letters
+/// and then digits
+const DEFAULT_SPRITES: [&str; 8] = ["X", "O", "H", "Z", "V", "S", "N", "W"];
+/// The most characters a player's name can have
+pub const MAX_NAME_LENGTH: usize = 32;
+/// The most characters of a name that are shown in scoreboards, longer names
+/// are cut short with `…`
+pub const SHORT_NAME_LENGTH: usize = 12;
+/// The most characters (grapheme clusters, so an emoji with a skin tone counts
+/// as one) a player's sprite can have
+pub const MAX_SPRITE_LENGTH: usize = 3;
+/// The narrowest a cell on the board is drawn, in terminal columns
+pub const MIN_CELL_WIDTH: usize = 3;
+
+/// A List struct to contain the players in a match. The players take their turns
+/// in the order they are listed, so player 1 is the first one in `players`.
 #[derive(Debug, PartialEq, Clone)]
 pub struct List {
-    /// `player_1` - \
-    ///  A Player struct used to represent player 1
-    pub player_1: Player,
-    /// `player_2` - \
-    ///  A Player struct used to represent player 2
-    pub player_2: Player,
+    /// `players` - \
+    ///  The Player structs in the match, in turn order
+    pub players: Vec<Player>,
+}
+
+impl List {
+    /// This constructor is used to make a list of players in turn order.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - \
+    ///    The players, starting with the one who moves first
+    ///
+    /// # Returns
+    ///
+    /// * `List` - \
+    ///    A List struct containing the players
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{List, Player, Sprite};
+    ///
+    /// let player_list = List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("H")),
+    ///     Player::ai(Sprite::new("Z")),
+    /// ]);
+    ///
+    /// assert_eq!(player_list.players[2].sprite, Sprite::new("Z"));
+    /// ```
+    #[must_use]
+    pub fn new(players: Vec<Player>) -> List {
+        List { players }
+    }
+
+    /// This function makes an `Ai` player that can join the list without
+    /// sharing a name or sprite with anyone already in it. It is not added to
+    /// the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Player)` - \
+    ///    A Player named after the seat it would take, like `P3`
+    /// * `None` - \
+    ///    The list already has `MAX_PLAYERS` players, or every sprite handed
+    ///    out to new players is taken
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{List, Player, Sprite};
+    /// use tic_tac_toe_and_connect_4::game_lib::player::MAX_PLAYERS;
+    ///
+    /// let player_list = List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("O")),
+    /// ]);
+    ///
+    /// let new_player = player_list.new_player().unwrap();
+    ///
+    /// assert_eq!(new_player.name, "P3");
+    /// assert_eq!(new_player.sprite, Sprite::new("H"));
+    ///
+    /// // players can be added until the list is full
+    /// let mut player_list = player_list;
+    ///
+    /// while let Some(new_player) = player_list.new_player() {
+    ///     player_list.players.push(new_player);
+    /// }
+    ///
+    /// assert_eq!(player_list.players.len(), MAX_PLAYERS);
+    /// ```
+    #[must_use]
+    pub fn new_player(&self) -> Option<Player> {
+        if self.players.len() >= MAX_PLAYERS {
+            return None;
+        }
+
+        let is_free = |sprite: &Sprite| self.players.iter().all(|player| player.sprite != *sprite);
+        let sprite = DEFAULT_SPRITES
+            .iter()
+            .map(|sprite| Sprite::new(sprite))
+            .chain(('A'..='Z').chain('1'..='9').map(|symbol| Sprite(symbol.to_string())))
+            .find(is_free)?;
+        let name = (self.players.len() + 1..=self.players.len() * 2 + 1)
+            .map(|number| format!("P{number}"))
+            .find(|name| self.players.iter().all(|player| player.name != *name))
+            .unwrap_or_default();
+        let mut player = Player::ai(sprite);
+
+        player.name = name;
+        player.colour = Colour::ALL[self.players.len() % Colour::ALL.len()];
+
+        Some(player)
+    }
+
+    /// This function takes a player out of the list. The players after them
+    /// move up a seat.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_number` - \
+    ///    The player's seat, counted from 1
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Player)` - \
+    ///    The player that was removed
+    /// * `None` - \
+    ///    There is no player in that seat
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{List, Player, Sprite};
+    ///
+    /// let mut player_list = List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("O")),
+    /// ]);
+    ///
+    /// assert_eq!(player_list.remove(1).map(|player| player.name), Some(String::from("P1")));
+    /// assert_eq!(player_list.remove(2), None);
+    /// ```
+    pub fn remove(&mut self, player_number: usize) -> Option<Player> {
+        (1..=self.players.len())
+            .contains(&player_number)
+            .then(|| self.players.remove(player_number - 1))
+    }
+
+    /// This function checks a name a player wants to use. Names can be 1 to
+    /// `MAX_NAME_LENGTH` characters of any kind, with the spaces around them
+    /// trimmed and their case kept. Two players can't have the same name, even
+    /// in a different case.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - \
+    ///    The name the player typed in
+    /// * `player_number` - \
+    ///    The seat of the player being named, counted from 1, who can keep
+    ///    their own name
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - \
+    ///    The name, trimmed, ready to use
+    /// * `Err(&str)` - \
+    ///    An error explaining why the name can't be used
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the name is blank, too long or already taken.
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{List, Player, Sprite};
+    ///
+    /// let player_list = List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("O")),
+    /// ]);
+    ///
+    /// assert_eq!(player_list.check_name("  Zoë 🦀 ", 1), Ok(String::from("Zoë 🦀")));
+    /// assert_eq!(player_list.check_name("p1", 1), Ok(String::from("p1")));
+    /// assert!(player_list.check_name("hal", 1).is_err());
+    /// assert!(player_list.check_name("   ", 1).is_err());
+    /// ```
+    pub fn check_name(&self, name: &str, player_number: usize) -> Result<String, &'static str> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err("Names can't be blank");
+        }
+
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err("Names can be at most 32 characters");
+        }
+
+        let is_taken = self.players
+            .iter()
+            .enumerate()
+            .any(|(index, player)| index + 1 != player_number && player.name.to_lowercase() == name.to_lowercase());
+
+        if is_taken {
+            return Err("Another player already has that name");
+        }
+
+        Ok(name.to_string())
+    }
+
+    /// This function checks a sprite a player wants to use. Sprites are 1 to
+    /// `MAX_SPRITE_LENGTH` characters, such as letters, CJK or emoji, with the
+    /// spaces around them trimmed, and they keep their case. They have to be
+    /// visible and can't be the same as another player's, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// * `sprite` - \
+    ///    The sprite to check
+    /// * `player_number` - \
+    ///    The seat of the player who wants it, counted from 1, so their own
+    ///    sprite doesn't count as taken
+    ///
+    /// # Returns
+    ///
+    /// * `Sprite` - \
+    ///    The trimmed sprite
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the sprite is blank, too long, can't be seen or
+    /// is already taken.
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{List, Player, Sprite};
+    ///
+    /// let player_list = List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("O")),
+    /// ]);
+    ///
+    /// assert_eq!(player_list.check_sprite(" 🦀 ", 1), Ok(Sprite::new("🦀")));
+    /// assert_eq!(player_list.check_sprite("👍🏽猫x", 1), Ok(Sprite::new("👍🏽猫x")));
+    /// assert!(player_list.check_sprite("o", 1).is_err());
+    /// assert!(player_list.check_sprite("ABCD", 1).is_err());
+    /// assert!(player_list.check_sprite("\u{200B}", 1).is_err());
+    /// ```
+    pub fn check_sprite(&self, sprite: &str, player_number: usize) -> Result<Sprite, &'static str> {
+        let sprite = sprite.trim();
+
+        if sprite.is_empty() {
+            return Err("Sprites can't be blank");
+        }
+
+        if sprite.graphemes(true).count() > MAX_SPRITE_LENGTH {
+            return Err("Sprites can be at most 3 characters");
+        }
+
+        if sprite.width() == 0 || sprite.chars().any(char::is_control) {
+            return Err("Sprites have to be visible");
+        }
+
+        let is_taken = self.players
+            .iter()
+            .enumerate()
+            .any(|(index, player)| index + 1 != player_number && player.sprite.0.to_lowercase() == sprite.to_lowercase());
+
+        if is_taken {
+            return Err("Another player already has that sprite");
+        }
+
+        Ok(Sprite::new(sprite))
+    }
+
+    /// This function gives how wide the cells on the board need to be for
+    /// every player's sprite to fit, so the columns stay lined up.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The widest sprite in terminal columns, but at least `MIN_CELL_WIDTH`
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{List, Player, Sprite};
+    ///
+    /// let mut player_list = List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("O")),
+    /// ]);
+    ///
+    /// assert_eq!(player_list.cell_width(), 3);
+    ///
+    /// player_list.players[1].sprite = Sprite::new("🐱🐶");
+    ///
+    /// assert_eq!(player_list.cell_width(), 4);
+    /// ```
+    #[must_use]
+    pub fn cell_width(&self) -> usize {
+        self.players
+            .iter()
+            .map(|player| player.sprite.width())
+            .fold(MIN_CELL_WIDTH, usize::max)
+    }
+
+    /// This function gives a name based on the one asked for that nobody in
+    /// the list has yet, by adding a number to the end if it is taken.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - \
+    ///    The name to start from
+    ///
+    /// # Returns
+    ///
+    /// * `String` - \
+    ///    The name, or the name with the first free number after it
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{List, Player, Sprite};
+    ///
+    /// let player_list = List::new(vec![Player::ai(Sprite::new("X"))]);
+    ///
+    /// assert_eq!(player_list.free_name("HAL"), "HAL 2");
+    /// assert_eq!(player_list.free_name("BOT"), "BOT");
+    /// ```
+    #[must_use]
+    pub fn free_name(&self, name: &str) -> String {
+        let is_free = |name: &String| self.players.iter().all(|player| player.name.to_lowercase() != name.to_lowercase());
+
+        std::iter::once(name.to_string())
+            .chain((2..=self.players.len() + 1).map(|number| format!("{name} {number}")))
+            .find(is_free)
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// This function gives what a piece on the board is drawn as. Stones and
+    /// men are drawn with their owner's sprite, marks as an X or an O, and
+    /// every other kind with its letter, in upper case for player 1 and lower
+    /// case for everyone else.
+    ///
+    /// # Arguments
+    ///
+    /// * `piece` - \
+    ///    The piece to draw
+    ///
+    /// # Returns
+    ///
+    /// * `String` - \
+    ///    The piece's glyph, or its owner's number if nobody sits in their seat
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{List, Piece, PieceKind, Player, Sprite};
+    ///
+    /// let player_list = List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::human(String::from("P2"), Sprite::new("O")),
+    /// ]);
+    ///
+    /// assert_eq!(player_list.glyph(&Piece::stone(2)), "O");
+    /// assert_eq!(player_list.glyph(&Piece { owner: 1, kind: PieceKind::King }), "K");
+    /// assert_eq!(player_list.glyph(&Piece { owner: 2, kind: PieceKind::King }), "k");
+    /// assert_eq!(player_list.glyph(&Piece { owner: 2, kind: PieceKind::Cross }), "X");
+    /// assert_eq!(player_list.glyph(&Piece::stone(3)), "3");
+    /// ```
+    #[must_use]
+    pub fn glyph(&self, piece: &Piece) -> String {
+        match (piece.kind.letter(), self.players.get(piece.owner.wrapping_sub(1))) {
+            (Some(letter), _) if piece.owner == 1 || piece.kind.is_mark() => letter.to_string(),
+            (Some(letter), _) => letter.to_ascii_lowercase().to_string(),
+            (None, Some(player)) => player.sprite.to_string(),
+            (None, None) => piece.owner.to_string(),
+        }
+    }
 }
 
 // helps format the output of the list
 impl fmt::Display for List {
     fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
-        write!(format_buffer, "{player_1} | {player_2} ", 
-            player_1 = self.player_1, player_2 = self.player_2)
+        for (index, player) in self.players.iter().enumerate() {
+            if index > 0 {
+                write!(format_buffer, "| ")?;
+            }
+
+            write!(format_buffer, "{player} ")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -32,9 +437,18 @@ pub struct Player {
     /// `sprite` - \
     ///   A Sprite struct meant to represent the player on the board
     pub sprite: Sprite,
+    /// `colour` - \
+    ///  The colour the player's sprite is drawn with on the board
+    pub colour: Colour,
     /// `wins` - \
     ///  An arch-sized unsigned integer used to keep track of player wins
     pub wins: usize,
+    /// `records` - \
+    ///  The player's wins, losses and ties in each mode they have played
+    pub records: Vec<Record>,
+    /// `ratings` - \
+    ///  The player's rating and its history in each mode they have played
+    pub ratings: Vec<Rating>,
 }
 
 impl Player {
@@ -48,6 +462,8 @@ impl Player {
     ///    A String meant to label the player
     /// * `sprite` - \
     ///    A Sprite used as a way to represent the player on the board
+    /// * `colour` - \
+    ///    The colour the sprite is drawn with
     ///
     /// # Returns
     ///
@@ -59,20 +475,32 @@ impl Player {
     /// Basic Usage:
     ///
     /// ```
-    /// let player = Player::new(ControlMode::Human, String::from("Hughman"), Sprite::new("X"));
+    /// use tic_tac_toe_and_connect_4::{Colour, ControlMode, Player, Sprite};
+    ///
+    /// let player = Player::new(
+    ///     ControlMode::Human, 
+    ///     String::from("Hughman"), 
+    ///     Sprite::new("X"), 
+    ///     Colour::Blue,
+    /// );
     ///
     /// println!("{player}");
     /// ```
-    pub fn new(control: ControlMode, name: String, sprite: Sprite) -> Player {
+    #[must_use]
+    pub fn new(control: ControlMode, name: String, sprite: Sprite, colour: Colour) -> Player {
         Player {
             control,
             name,
             sprite,
+            colour,
             wins: 0,
+            records: Vec::new(),
+            ratings: Vec::new(),
         }
     }
 
     /// This function alllows for a convienent way to make a Human controlled player.
+    /// The player is drawn in red until they pick a different colour.
     ///
     /// # Arguments
     /// 
@@ -91,7 +519,9 @@ impl Player {
     /// Basic Usage:
     ///
     /// ```
-    /// let player = Player::human(String::from("Hughman"), Sprite("X"));
+    /// use tic_tac_toe_and_connect_4::{Player, Sprite};
+    ///
+    /// let player = Player::human(String::from("Hughman"), Sprite::new("X"));
     ///
     /// println!("{player}");
     /// ```
@@ -101,11 +531,15 @@ impl Player {
             control: ControlMode::Human,
             name,
             sprite,
+            colour: Colour::Red,
             wins: 0,
+            records: Vec::new(),
+            ratings: Vec::new(),
         }
     }
 
     /// This function alllows for a convienent way to make an Ai controlled player.
+    /// The player is drawn in yellow and plays on `Easy` until they are changed.
     ///
     /// # Arguments
     /// 
@@ -124,17 +558,22 @@ impl Player {
     /// Basic Usage:
     ///
     /// ```
-    /// let player = Player::Ai(String::from("Hal"), Sprite::new("O"));
+    /// use tic_tac_toe_and_connect_4::{Player, Sprite};
+    ///
+    /// let player = Player::ai(Sprite::new("O"));
     ///
     /// println!("{player}");
     /// ```
     #[must_use]
     pub fn ai(sprite: Sprite) -> Player {
         Player {
-            control: ControlMode::Ai,
+            control: ControlMode::Ai(Difficulty::default()),
             name: String::from("HAL"),
             sprite,
+            colour: Colour::Yellow,
             wins: 0,
+            records: Vec::new(),
+            ratings: Vec::new(),
         }
     }
 
@@ -150,7 +589,9 @@ impl Player {
     /// Basic Usage:
     ///
     /// ```
-    /// let mut player = Player::Ai(String::from("Hal"), Sprite::new("O"));
+    /// use tic_tac_toe_and_connect_4::{Player, Sprite};
+    ///
+    /// let mut player = Player::ai(Sprite::new("O"));
     ///
     /// player.update_wins(1);
     ///
@@ -160,7 +601,8 @@ impl Player {
         self.wins += update_scale;
     }
 
-    /// This function allows the caller toupdate a players wins
+    /// This function allows the caller to clear a player's wins and their
+    /// record in every mode. Their ratings are kept.
     ///
     /// # Arguments
     /// 
@@ -172,7 +614,9 @@ impl Player {
     /// Basic Usage:
     ///
     /// ```
-    /// let mut player = Player::Ai(String::from("Hal"), Sprite::new("O"));
+    /// use tic_tac_toe_and_connect_4::{Player, Sprite};
+    ///
+    /// let mut player = Player::ai(Sprite::new("O"));
     ///
     /// player.update_wins(1);
     ///
@@ -186,25 +630,342 @@ impl Player {
     /// ```
     pub fn reset(&mut self) {
         self.wins = 0;
+        self.records.clear();
+    }
+
+    /// This function adds the result of a finished round to the player's record
+    /// in its mode. A win is also added to `wins`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode the round was played in
+    /// * `outcome` - \
+    ///    How the round went for the player
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Mode, Outcome, Player, Sprite};
+    ///
+    /// let mut player = Player::human(String::from("Hughman"), Sprite::new("X"));
+    ///
+    /// player.add_result(Mode::TicTacToe, Outcome::Win);
+    /// player.add_result(Mode::TicTacToe, Outcome::Tie);
+    /// player.add_result(Mode::ConnectFour, Outcome::Loss);
+    ///
+    /// assert_eq!(player.wins, 1);
+    /// assert_eq!(player.record(Mode::TicTacToe).games_played(), 2);
+    /// assert_eq!(player.record(Mode::ConnectFour).losses, 1);
+    /// ```
+    pub fn add_result(&mut self, mode: Mode, outcome: Outcome) {
+        let index = if let Some(index) = self.records.iter().position(|record| record.mode == mode) {
+            index
+        } else {
+            self.records.push(Record::new(mode));
+            self.records.len() - 1
+        };
+        let record = &mut self.records[index];
+
+        match outcome {
+            Outcome::Win => {
+                record.wins += 1;
+                self.wins += 1;
+            }
+            Outcome::Loss => record.losses += 1,
+            Outcome::Tie => record.ties += 1,
+        }
+    }
+
+    /// This function gives the player's record in a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode to give the record for
+    ///
+    /// # Returns
+    ///
+    /// * `Record` - \
+    ///    The player's wins, losses and ties, all `0` if they have not played
+    ///    the mode
+    #[must_use]
+    pub fn record(&self, mode: Mode) -> Record {
+        self.records
+            .iter()
+            .find(|record| record.mode == mode)
+            .copied()
+            .unwrap_or_else(|| Record::new(mode))
+    }
+
+    /// This function gives the player's name cut down to `SHORT_NAME_LENGTH`
+    /// characters, so long names fit in scoreboards.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - \
+    ///    The name, ending in `…` if it had to be cut short
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Player, Sprite};
+    ///
+    /// let player = Player::human(String::from("Bartholomew the Bold"), Sprite::new("X"));
+    ///
+    /// assert_eq!(player.short_name(), "Bartholomew…");
+    /// assert_eq!(Player::ai(Sprite::new("O")).short_name(), "HAL");
+    /// ```
+    #[must_use]
+    pub fn short_name(&self) -> String {
+        if self.name.chars().count() <= SHORT_NAME_LENGTH {
+            return self.name.clone();
+        }
+
+        let mut name: String = self.name.chars().take(SHORT_NAME_LENGTH - 1).collect();
+
+        name.push('…');
+        name
+    }
+
+    /// This function gives the player's rating in a mode. An `Ai` player is
+    /// always rated at its difficulty's measured rating, and anyone else who
+    /// has not played the mode yet starts at `STARTING_RATING`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode to give the rating for
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - \
+    ///    The player's current rating
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Difficulty, Mode, Player, Sprite};
+    ///
+    /// let mut player = Player::human(String::from("Hughman"), Sprite::new("X"));
+    ///
+    /// player.set_rating(Mode::TicTacToe, 1250.0);
+    ///
+    /// assert_eq!(player.rating(Mode::TicTacToe), 1250.0);
+    /// assert_eq!(Player::ai(Sprite::new("O")).rating(Mode::TicTacToe), Difficulty::Easy.rating(Mode::TicTacToe));
+    /// ```
+    #[must_use]
+    pub fn rating(&self, mode: Mode) -> f64 {
+        if let ControlMode::Ai(difficulty) = self.control {
+            return difficulty.rating(mode);
+        }
+
+        self.ratings
+            .iter()
+            .find(|rating| rating.mode == mode)
+            .map_or(STARTING_RATING, Rating::value)
+    }
+
+    /// This function gives every value the player's rating in a mode has had,
+    /// oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode to give the history for
+    ///
+    /// # Returns
+    ///
+    /// * `&[f64]` - \
+    ///    The ratings, which is empty if the player has not been rated in the mode
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Mode, Player, Sprite};
+    ///
+    /// let mut player = Player::human(String::from("Hughman"), Sprite::new("X"));
+    ///
+    /// player.set_rating(Mode::ConnectFour, 1216.0);
+    ///
+    /// assert_eq!(player.rating_history(Mode::ConnectFour), &[1200.0, 1216.0]);
+    /// assert!(player.rating_history(Mode::TicTacToe).is_empty());
+    /// ```
+    #[must_use]
+    pub fn rating_history(&self, mode: Mode) -> &[f64] {
+        self.ratings
+            .iter()
+            .find(|rating| rating.mode == mode)
+            .map_or(&[], |rating| &rating.history)
+    }
+
+    /// This function gives the player a new rating in a mode and adds it to
+    /// the mode's history.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode the rating is for
+    /// * `value` - \
+    ///    The new rating
+    pub fn set_rating(&mut self, mode: Mode, value: f64) {
+        if let Some(rating) = self.ratings.iter_mut().find(|rating| rating.mode == mode) {
+            rating.history.push(value);
+        } else {
+            let mut rating = Rating::new(mode);
+
+            rating.history.push(value);
+            self.ratings.push(rating);
+        }
     }
 }
 
 impl fmt::Display for Player {
     fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
-        write!(format_buffer, "{name}: {wins} wins", name = self.name, wins = self.wins)
+        let wins: usize = self.records.iter().map(|record| record.wins).sum();
+        let losses: usize = self.records.iter().map(|record| record.losses).sum();
+        let ties: usize = self.records.iter().map(|record| record.ties).sum();
+        let games_played = wins + losses + ties;
+
+        write!(format_buffer, "{name}: {wins} wins, {losses} losses, {ties} ties ({games_played} played, {rate:.0}% won)",
+            name = self.short_name(), rate = win_rate(wins, games_played) * 100.0)
     }
 }
 
-/// An enum used to present the two types of players available
-/// `Human` and `Ai`
+/// A struct used to keep how many rounds a player has won, lost and tied in
+/// one mode.
 #[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Record {
+    /// `mode` - \
+    ///  The mode the record is for
+    pub mode: Mode,
+    /// `wins` - \
+    ///  The rounds the player won
+    pub wins: usize,
+    /// `losses` - \
+    ///  The rounds someone else won
+    pub losses: usize,
+    /// `ties` - \
+    ///  The rounds nobody won
+    pub ties: usize,
+}
+
+impl Record {
+    /// This constructor is used to start an empty record for a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode the record is for
+    ///
+    /// # Returns
+    ///
+    /// * `Record` - \
+    ///    A Record struct with no games in it
+    #[must_use]
+    pub fn new(mode: Mode) -> Record {
+        Record {
+            mode,
+            wins: 0,
+            losses: 0,
+            ties: 0,
+        }
+    }
+
+    /// This function gives how many rounds are in the record.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The wins, losses and ties added up
+    #[must_use]
+    pub fn games_played(&self) -> usize {
+        self.wins + self.losses + self.ties
+    }
+
+    /// This function gives the share of the rounds in the record that were won.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - \
+    ///    The win rate between `0` and `1`, which is `0` before any rounds
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Mode, Outcome, Player, Sprite};
+    ///
+    /// let mut player = Player::human(String::from("Hughman"), Sprite::new("X"));
+    ///
+    /// player.add_result(Mode::TicTacToe, Outcome::Win);
+    /// player.add_result(Mode::TicTacToe, Outcome::Loss);
+    ///
+    /// assert_eq!(player.record(Mode::TicTacToe).win_rate(), 0.5);
+    /// assert_eq!(player.record(Mode::ConnectFour).win_rate(), 0.0);
+    /// ```
+    #[must_use]
+    pub fn win_rate(&self) -> f64 {
+        win_rate(self.wins, self.games_played())
+    }
+}
+
+// Prints a record as wins-losses-ties with the win rate
+impl fmt::Display for Record {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        write!(format_buffer, "{wins}-{losses}-{ties} ({rate:.0}% won)", wins = self.wins,
+            losses = self.losses, ties = self.ties, rate = self.win_rate() * 100.0)
+    }
+}
+
+/// An enum used to say how a finished round went for one player
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    /// `Win` - \
+    ///  The player won the round
+    Win,
+    /// `Loss` - \
+    ///  Another player won the round
+    Loss,
+    /// `Tie` - \
+    ///  Nobody won the round
+    Tie,
+}
+
+// gives wins out of games played, or 0 before any games
+fn win_rate(wins: usize, games_played: usize) -> f64 {
+    let count = |games: usize| f64::from(u32::try_from(games).unwrap_or(u32::MAX));
+
+    if games_played == 0 {
+        0.0
+    } else {
+        count(wins) / count(games_played)
+    }
+}
+
+/// An enum used to present the types of players available
+/// `Human`, `Ai` and `Bot`
+#[derive(Debug, PartialEq, Clone)]
 pub enum ControlMode {
     /// `Human` - \
     ///  Represents a Human Player
     Human,
-    /// `Ai` - \
-    ///  Represents a Ai player
-    Ai,
+    /// `Ai(difficulty)` - \
+    ///  Represents a Ai player and how well it plays
+    Ai(Difficulty),
+    /// `Bot(command)` - \
+    ///  Represents an external engine that is started with the command and
+    ///  plays through the Grid Engine Protocol (see `Bot`)
+    Bot(String),
 }
 
 /// This tuple struct is used as a way to represent the player and give them the ability to 
@@ -230,12 +991,39 @@ impl Sprite {
     /// Basic Usage:
     ///
     /// ```
+    /// use tic_tac_toe_and_connect_4::Sprite;
+    ///
     /// let sprite = Sprite::new("7UP");
     ///
     /// println!("{sprite}");
     /// ```
+    #[must_use]
     pub fn new(new_sprite: &str) -> Sprite {
-        Sprite(new_sprite.to_owned().to_string())
+        Sprite(new_sprite.to_owned())
+    }
+
+    /// This function gives how many columns the sprite takes up in a terminal,
+    /// where emoji and CJK characters are two columns wide.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The sprite's display width
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::Sprite;
+    ///
+    /// assert_eq!(Sprite::new("X").width(), 1);
+    /// assert_eq!(Sprite::new("猫").width(), 2);
+    /// assert_eq!(Sprite::new("👍🏽").width(), 2);
+    /// ```
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.0.width()
     }
 }
 
@@ -251,6 +1039,8 @@ impl Sprite {
     /// Basic Usage:
     ///
     /// ```
+    /// use tic_tac_toe_and_connect_4::Sprite;
+    ///
     /// let sprite = Sprite::default();
     ///
     /// println!("{sprite}"); // should be nothing
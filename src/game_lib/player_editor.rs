@@ -1,14 +1,21 @@
-use super::cmdln_interface::{get_int_input, get_str_input};
-use super::player::{List, Player, ControlMode, Sprite};
-use super::cmdln_interface::{TO_MAIN, ESCAPE_CHAR, PLAYER_ATTRIBUTE_MENU, PROMPT_PLAYER_NAME,
-    PROMPT_PLAYER_SPRITE, PROMPT_PLAYER_TYPE};
+use std::io::{BufRead, Write};
+use super::cmdln_interface::{get_int_input, get_line_input, Console};
+use super::player::{List, Player, ControlMode, Sprite, MAX_PLAYERS};
+use super::theme::Colour;
+use super::game::Mode;
+use super::ai_engine::Difficulty;
+use super::cmdln_interface::{TO_MAIN, PLAYER_ATTRIBUTE_MENU, PROMPT_PLAYER_NAME,
+    PROMPT_PLAYER_SPRITE, PROMPT_PLAYER_TYPE, PROMPT_PLAYER_COLOUR, PROMPT_BOT_COMMAND, PROMPT_REMOVE_PLAYER};
 
 /// This function is used to take a player list to allow user selection of
-/// a specfic player. It will then return a new version of the list with updated
-/// player(s).
+/// a specfic player. Players can also be added to the end of the list or
+/// removed, as long as there are two left. It will then return a new version of
+/// the list with updated player(s).
 ///
 /// # Arguments
 ///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
 /// * `message` - \
 ///    A string literal used to prompt the user
 /// * `player_list` - \
@@ -19,99 +26,147 @@ use super::cmdln_interface::{TO_MAIN, ESCAPE_CHAR, PLAYER_ATTRIBUTE_MENU, PROMPT
 /// Basic Usage:
 ///
 /// ```
-/// use super::player::{List, Player};
-///
-/// let mut player_list = List {
-///     player_1: Player::human(String::from("P1"), Sprite::new("X")),
-///     player_2: Player::ai(Sprite::new("H")),
-/// };
-///
-/// println!("{player_list}");
-///
-/// player_list = player_editor("Select a Player to edit: ", player_list.clone());
-///
-/// println!("{player_list}");
+/// use std::io::Cursor;
+/// use tic_tac_toe_and_connect_4::{List, Player, Sprite};
+/// use tic_tac_toe_and_connect_4::game_lib::player_editor::player_editor;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+///
+/// // rename player 1 to `Bob` (`hal` is already taken), add a third player and
+/// // remove the second, then back out of both menus
+/// let mut console = Console::new(Cursor::new("1\n1\nhal\nBob\nq\n+\n-\n2\nq\n"), Vec::new());
+/// let mut player_list = List::new(vec![
+///     Player::human(String::from("P1"), Sprite::new("X")),
+///     Player::ai(Sprite::new("H")),
+/// ]);
+///
+/// player_list = player_editor(&mut console, "Select a Player to edit: ", player_list.clone());
+///
+/// assert_eq!(player_list.players.len(), 2);
+/// assert_eq!(player_list.players[0].name, "Bob");
+/// assert_eq!(player_list.players[1].name, "P3");
 /// ```
-pub fn player_editor(message: &str, mut player_list: List) -> List {
+pub fn player_editor<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    message: &str, 
+    mut player_list: List,
+) -> List {
     loop {
-        match get_int_input(message) {
+        for (index, player) in player_list.players.iter().enumerate() {
+            console.println(format!("`{number}` {player}", number = index + 1));
+        }
+
+        let selection = get_line_input(console, message);
+
+        match selection.as_deref() {
             None => {
-                eprintln!("{TO_MAIN}");
+                console.println(TO_MAIN);
 
                 break;
             }
-            Some(1) => {
-                let edited_player = edit_player(player_list.player_1.clone());
-                player_list.player_1 = edited_player;
-            }
-            Some(2) => {
-                let edited_player = edit_player(player_list.player_2.clone());
-                player_list.player_2 = edited_player;
-            }
-            _ => {
-                eprintln!("Invalid selection.");
-            }
+            Some("+") => match player_list.new_player() {
+                Some(new_player) => {
+                    console.println(format!("Added {name}...", name = new_player.name));
+                    player_list.players.push(new_player);
+                }
+                None => console.println(format!("A match can have at most {MAX_PLAYERS} players.")),
+            },
+            Some("-") => remove_player(console, &mut player_list),
+            Some(number) => match number.parse::<usize>() {
+                Ok(number @ 1..) if number <= player_list.players.len() => {
+                    let edited_player = edit_player(console, &player_list, number);
+                    player_list.players[number - 1] = edited_player;
+                }
+                _ => console.println("Invalid selection."),
+            },
         }
-        
     }
 
     player_list
 }
 
+// asks which player to take out of the list, keeping at least two in it
+fn remove_player<R: BufRead, W: Write>(console: &mut Console<R, W>, player_list: &mut List) {
+    if player_list.players.len() <= 2 {
+        console.println("A match needs at least two players.");
+
+        return;
+    }
+
+    match get_int_input(console, PROMPT_REMOVE_PLAYER).and_then(|number| player_list.remove(number)) {
+        Some(player) => console.println(format!("Removed {name}...", name = player.name)),
+        None => console.println("Invalid selection."),
+    }
+}
+
 /// This function is used to take a player struct to allow users to edit
 /// the fields of the selected player. After editing is finished, it will return 
 /// the new player to the calling code
 ///
 /// # Arguments
 ///
-/// * `player` - \
-///   A Player struct that was selected for editing
+/// * `console` - \
+///   A Console struct used to prompt the user and read their input
+/// * `player_list` - \
+///   The List the player is in, so their new name can be checked against it
+/// * `player_number` - \
+///   The seat of the player that was selected for editing, counted from 1
 ///
 /// # Examples
 ///
 /// Basic Usage:
 ///
-/// ```
+/// ```ignore
 /// use super::player::{Player};
 ///
-/// let mut player = Player::human(Sprite::new("X"));
+/// let mut console = Console::stdio();
+/// let player_list = List::new(vec![Player::human(String::from("P1"), Sprite::new("X"))]);
 ///
-/// println!("{player}");
-///
-/// player = edit_player("Editing a player: ", player.clone());
+/// let player = edit_player(&mut console, &player_list, 1);
 ///
 /// println!("{player}");
 /// ```
-fn edit_player(mut selected_player: Player) -> Player {
+fn edit_player<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    player_list: &List,
+    player_number: usize,
+) -> Player {
+    let mut selected_player = player_list.players[player_number - 1].clone();
+
     loop {
-        match get_int_input(PLAYER_ATTRIBUTE_MENU) {
+        match get_int_input(console, PLAYER_ATTRIBUTE_MENU) {
             None => {
-                eprintln!("{TO_MAIN}");
+                console.println(TO_MAIN);
 
                 break;
             }
             Some(1) => {
-                if let Some(new_name) = get_new_name(PROMPT_PLAYER_NAME) {
+                if let Some(new_name) = get_new_name(console, PROMPT_PLAYER_NAME, player_list, player_number) {
                     selected_player.name = new_name;
-                };
+                }
             }
             Some(2) => {
-                if let Some(new_type) = get_new_type(PROMPT_PLAYER_TYPE) {
+                if let Some(new_type) = get_new_type(console, PROMPT_PLAYER_TYPE) {
                     selected_player.control = new_type;
-                };
+                }
             }
             Some(3) => {
-                if let Some(new_sprite) = get_new_sprite(PROMPT_PLAYER_SPRITE) {
+                if let Some(new_sprite) = get_new_sprite(console, PROMPT_PLAYER_SPRITE, player_list, player_number) {
                     selected_player.sprite = new_sprite;
-                };
+                }
             }
             Some(4) => {
+                if let Some(new_colour) = get_new_colour(console, PROMPT_PLAYER_COLOUR) {
+                    selected_player.colour = new_colour;
+                }
+            }
+            Some(5) => {
                 selected_player.reset();
 
-                eprintln!("Player wins reset...");
+                console.println("Player results reset...");
             }
+            Some(6) => print_stats(console, &selected_player),
             _ => {
-                eprintln!("Invalid Selection. Try again.");
+                console.println("Invalid Selection. Try again.");
             }
         }
     }
@@ -119,15 +174,35 @@ fn edit_player(mut selected_player: Player) -> Player {
     selected_player
 }
 
-/// This function is used to prompt users for a new player sprite. After getting
-/// input from the user, it should return the sprite to the calling function(s).
-/// If the user inputs the `ESCAPE_WORD,` it will abort the change and leave it
-/// unchanged by returning `None`.
+// prints the player's record and rating history in every mode they have played
+fn print_stats<R: BufRead, W: Write>(console: &mut Console<R, W>, player: &Player) {
+    for mode in Mode::PLAYABLE {
+        let rating = match (&player.control, player.rating_history(mode)) {
+            (ControlMode::Ai(difficulty), _) => format!("{rating:.0} ({difficulty} Ai)", rating = player.rating(mode)),
+            (_, []) => format!("{rating:.0}", rating = player.rating(mode)),
+            (_, history) => history.iter().map(|value| format!("{value:.0}")).collect::<Vec<String>>().join(" -> "),
+        };
+
+        console.println(format!("{mode}: {record}, rated {rating}", record = player.record(mode)));
+    }
+}
+
+/// This function is used to prompt users for a new player sprite. Sprites can
+/// be 1 to 3 characters, including emoji and CJK, and the user is asked again
+/// if the sprite is blank, too long, can't be seen or is already used by
+/// another player. If the user inputs the `ESCAPE_WORD,` it will abort the
+/// change and leave it unchanged by returning `None`.
 ///
 /// # Arguments
 ///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
 /// * `message` - \
 ///   A string literal used to get a prompt to display to thee user
+/// * `player_list` - \
+///   The List the player is in, used to check for duplicate sprites
+/// * `player_number` - \
+///   The seat of the player whose sprite is changing, counted from 1
 ///
 /// # Returns
 ///
@@ -141,41 +216,55 @@ fn edit_player(mut selected_player: Player) -> Player {
 ///
 /// Basic Usage:
 ///
-/// ```
-/// use super::player::{Sprite};
-///
-/// let mut sprite = Sprite::default();
+/// ```ignore
+/// use super::player::{List, Player, Sprite};
 ///
-/// println!("{sprite}");
+/// let mut console = Console::stdio();
+/// let mut player_list = List::new(vec![Player::human(String::from("P1"), Sprite::new("X"))]);
 ///
-/// sprite = get_new_sprite("Editing a sprite: ");
+/// if let Some(new_sprite) = get_new_sprite(&mut console, "Editing a sprite: ", &player_list, 1) {
+///     player_list.players[0].sprite = new_sprite;
+/// }
 ///
-/// println!("{sprite}");
+/// println!("{player_list}");
 /// ```
-fn get_new_sprite(message: &str) -> Option<Sprite> {
-    let new_sprite = get_str_input(message, 1);
+fn get_new_sprite<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    message: &str,
+    player_list: &List,
+    player_number: usize,
+) -> Option<Sprite> {
+    loop {
+        let new_sprite = get_line_input(console, message)?;
 
-    if new_sprite == ESCAPE_CHAR {
-        None
-    } else {
-        Some(Sprite(new_sprite))
+        match player_list.check_sprite(&new_sprite, player_number) {
+            Ok(sprite) => return Some(sprite),
+            Err(error) => console.println(format!("Error: {error}")),
+        }
     }
 }
 
-/// This function is used to prompt users for a new player sprite. After getting
-/// input from the user, it should return the sprite to the calling function(s). If
+/// This function is used to prompt users for a new player name. Names can be
+/// 1 to 32 characters of any kind and keep their case, and the user is asked
+/// again if the name is blank, too long or already used by another player. If
 /// the user provides the `ESCAPE_WORD,` the function will abort and leave the
-/// the sprite unchanged by returning `None`.
+/// name unchanged by returning `None`.
 ///
 /// # Arguments
 ///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
 /// * `message` - \
 ///   A string literal used to get a prompt to display to thee user
+/// * `player_list` - \
+///   The List the player is in, used to check for duplicate names
+/// * `player_number` - \
+///   The seat of the player being named, counted from 1
 ///
 /// # Returns
 ///
 /// * `Some(string_value)` - \
-///      A 3 character string that represent the player's name
+///      The player's new name
 /// * `None` - \
 ///      Should tell the calling function that the user did not want to change
 ///      the current value
@@ -184,47 +273,54 @@ fn get_new_sprite(message: &str) -> Option<Sprite> {
 ///
 /// Basic Usage:
 ///
-/// ```
-/// use super::player::{Player};
-///
-/// let mut player = Player::human(String::from("P1"), Sprite::default("X"));
+/// ```ignore
+/// use super::player::{List, Player};
 ///
-/// println!("{player}");
+/// let mut console = Console::stdio();
+/// let mut player_list = List::new(vec![Player::human(String::from("P1"), Sprite::new("X"))]);
 ///
-/// let name = get_new_name("Editing the player name: ");
-/// player.name = match name {
-///     Some(new_name) => new_name,
-///     None => player.name,   
-/// };
+/// if let Some(new_name) = get_new_name(&mut console, "Editing the player name: ", &player_list, 1) {
+///     player_list.players[0].name = new_name;
+/// }
 ///
-/// println!("{player}");
+/// println!("{player_list}");
 /// ```
-fn get_new_name(message: &str) -> Option<String> {
-    let new_name = get_str_input(message, 3);
+fn get_new_name<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    message: &str,
+    player_list: &List,
+    player_number: usize,
+) -> Option<String> {
+    loop {
+        let new_name = get_line_input(console, message)?;
 
-    if new_name == ESCAPE_CHAR {
-        None
-    } else {
-        Some(new_name)
+        match player_list.check_name(&new_name, player_number) {
+            Ok(name) => return Some(name),
+            Err(error) => console.println(format!("Error: {error}")),
+        }
     }
 }
 
 /// This function is used to prompt users for changing their control mode. There
-/// are two control modes for the players:
+/// are three control modes for the players:
 ///
 /// # Arguments
 ///
+/// `console` - A Console struct used to prompt the user and read their input
 /// `message` - A string literal used to get a prompt to display to thee user
 ///
 /// # Returns
 ///
-/// * `Some(Type::Human)` - \  
+/// * `Some(Type::Human)` - \
 ///     Represents Human controlled player and allows the game engine to 
 ///     pick prompt the user to make a move
-/// * `Some(Type::Ai)` - \  
+/// * `Some(Type::Ai(difficulty))` - \
 ///     Representa an Ai controlled player and allows that game engine to
-///     pick how a move is made
-/// * `None` - \  
+///     pick how a move is made at an `Easy`, `Medium` or `Hard` difficulty
+/// * `Some(Type::Bot(command))` - \
+///     Represents an external engine that the game engine starts with the
+///     command the user typed in and asks for moves
+/// * `None` - \
 ///     A value that can be used to tell the calling function that it does not 
 ///     need to change
 ///
@@ -232,24 +328,28 @@ fn get_new_name(message: &str) -> Option<String> {
 ///
 /// Basic Usage:
 ///
-/// ```
+/// ```ignore
 /// use super::player::{Type};
 ///
+/// let mut console = Console::stdio();
 /// let mut control_type = Type::Human;
 ///
 /// println!("{:?}", control_type);
 ///
-/// control_type = get_new_type("Editing the type: ");
+/// control_type = get_new_type(&mut console, "Editing the type: ");
 ///
 /// println!("{:?}", control_type);
 /// ```
-fn get_new_type(message: &str) -> Option<ControlMode> {
+fn get_new_type<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    message: &str,
+) -> Option<ControlMode> {
     let mut selected_type = None;
 
     loop {
-        match get_int_input(message) {
+        match get_int_input(console, message) {
             None => {
-                eprintln!("Leaving the type editor...");
+                console.println("Leaving the type editor...");
 
                 break;
             }
@@ -258,13 +358,13 @@ fn get_new_type(message: &str) -> Option<ControlMode> {
 
                 break;
             }
-            Some(2) => {
-                selected_type = Some(2);
+            Some(val @ 2..=5) => {
+                selected_type = Some(val);
 
                 break;
             }
             Some(_) => {
-                eprintln!("Invalid selection");
+                console.println("Invalid selection");
             }
         }
     }
@@ -272,7 +372,69 @@ fn get_new_type(message: &str) -> Option<ControlMode> {
     match selected_type {
         None => None,
         Some(1) => Some(ControlMode::Human),
-        Some(2) => Some(ControlMode::Ai),
+        Some(2) => Some(ControlMode::Ai(Difficulty::Easy)),
+        Some(3) => Some(ControlMode::Ai(Difficulty::Medium)),
+        Some(4) => Some(ControlMode::Ai(Difficulty::Hard)),
+        Some(5) => get_line_input(console, PROMPT_BOT_COMMAND)
+            .filter(|command| !command.is_empty())
+            .map(ControlMode::Bot),
         _ => panic!("There was an error getting user type"),
     }
 }
+
+/// This function is used to prompt users for a new colour to draw their sprite
+/// with. If the user provides the `ESCAPE_WORD,` the function will abort and leave
+/// the colour unchanged by returning `None`.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `message` - \
+///   A string literal used to get a prompt to display to thee user
+///
+/// # Returns
+///
+/// * `Some(Colour)` - \
+///      The colour the player picked
+/// * `None` - \
+///      Should tell the calling function that the user did not want to change
+///      the current value
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```ignore
+/// use super::theme::Colour;
+///
+/// let mut console = Console::stdio();
+/// let mut colour = Colour::Red;
+///
+/// colour = match get_new_colour(&mut console, "Editing the colour: ") {
+///     Some(new_colour) => new_colour,
+///     None => colour,
+/// };
+///
+/// println!("{colour}");
+/// ```
+fn get_new_colour<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    message: &str,
+) -> Option<Colour> {
+    loop {
+        match get_int_input(console, message) {
+            None => {
+                console.println("Leaving the colour editor...");
+
+                return None;
+            }
+            Some(val) if (1..=Colour::ALL.len()).contains(&val) => {
+                return Some(Colour::ALL[val - 1]);
+            }
+            Some(_) => {
+                console.println("Invalid selection");
+            }
+        }
+    }
+}
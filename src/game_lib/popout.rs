@@ -0,0 +1,253 @@
+use super::game::{Cell, Game, Mode, Move, MoveStatus, State};
+use super::game_engine::{player_to_move, repetitions, ttt_cnct_four_board_check, ttt_cnct_four_board_move_chck, REPEATS_TO_DRAW};
+
+/// This function reads a move typed as a column to drop a disc down, or as `p`
+/// (or `pop`) and a column to pop a disc out of. A number past the last column
+/// is read as the move's selection, the way bots send pops.
+///
+/// # Arguments
+///
+/// * `width` - \
+///    How many columns are on the board
+/// * `height` - \
+///    How many rows are on the board
+/// * `input` - \
+///    What the player typed
+///
+/// # Returns
+///
+/// * `Some(usize)` - \
+///    The move's selection (see `Move::from_selection`)
+/// * `None` - \
+///    The input isn't a move
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::popout::parse_move;
+///
+/// assert_eq!(parse_move(7, 6, "3"), Some(3));
+/// assert_eq!(parse_move(7, 6, "p3"), Some(10));
+/// assert_eq!(parse_move(7, 6, "pop 3"), Some(10));
+/// assert_eq!(parse_move(7, 6, "p9"), None);
+/// ```
+#[must_use]
+pub fn parse_move(width: usize, height: usize, input: &str) -> Option<usize> {
+    let input = input.trim().to_lowercase();
+    let popped = input.strip_prefix("pop").or_else(| | input.strip_prefix('p'));
+
+    match popped {
+        Some(column) => column
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|column| (1..=width).contains(column))
+            .map(|column| Move::Pop(column).selection(width, width * height)),
+        None => input.parse::<usize>().ok().filter(|selection| (1..=width * 2).contains(selection)),
+    }
+}
+
+/// This function takes the disc at the bottom of a column out of the board,
+/// and every disc above it falls down one row.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The cells of the board, row by row from the top left
+/// * `width` - \
+///    How many columns are on the board
+/// * `column` - \
+///    The column, counted from 1
+///
+/// # Returns
+///
+/// * `Vec<Cell>` - \
+///    The board after the disc is popped out
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::edit_board;
+/// use tic_tac_toe_and_connect_4::game_lib::popout::pop;
+///
+/// let mut game = Game::connect_four();
+///
+/// game.board = edit_board(game.board, game.width, game.current_mode, 1, 2);
+/// game.board = edit_board(game.board, game.width, game.current_mode, 2, 2);
+/// game.board = pop(game.board, game.width, 2);
+///
+/// // player 2's disc fell into the bottom row
+/// assert_eq!(game.board[13], Some(Piece::stone(2)));
+/// assert_eq!(game.board[9], None);
+/// ```
+#[must_use]
+pub fn pop(mut board: Vec<Cell>, width: usize, column: usize) -> Vec<Cell> {
+    let mut index = board.len() - width + column - 1;
+
+    while index >= width {
+        board[index] = board[index - width];
+        index -= width;
+    }
+
+    board[index] = None;
+    board
+}
+
+/// This function is used to validate a move in `PopOut`. Drops are checked the
+/// same as in Connect-4, and a disc can only be popped out of the bottom row by
+/// the player it belongs to.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The move's selection (see `Move::from_selection`)
+///
+/// # Returns
+///
+/// * `MoveStatus::Valid` - \
+///    The move can be played
+/// * `MoveStatus::Invalid(error_str)` - \
+///    The move can't be played and the error explains why
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::popout::check_move;
+///
+/// let mut game = Game::connect_four();
+///
+/// game.rules = Rules::PopOut;
+/// play_move(&mut game, 1, 1);
+///
+/// // player 2 can't pop player 1's disc
+/// assert_eq!(check_move(&game, 5), MoveStatus::Invalid("You can only pop out your own discs from the bottom row"));
+/// assert_eq!(check_move(&game, 1), MoveStatus::Valid);
+/// ```
+#[must_use]
+pub fn check_move(game: &Game, selection: usize) -> MoveStatus<'static> {
+    if selection == 0 || selection > game.width * 2 {
+        return MoveStatus::Invalid("Selected an invalid column");
+    }
+
+    match Move::from_selection(Mode::ConnectFour, game.width, selection) {
+        Move::Pop(column) => {
+            let bottom = game.board[game.board.len() - game.width + column - 1];
+
+            if bottom.is_some_and(|piece| piece.owner == player_to_move(game)) {
+                MoveStatus::Valid
+            } else {
+                MoveStatus::Invalid("You can only pop out your own discs from the bottom row")
+            }
+        }
+        _ => ttt_cnct_four_board_move_chck(&game.board, game.width, Mode::ConnectFour, selection),
+    }
+}
+
+/// This function gives every move the next player is allowed to make: every
+/// column that isn't full to drop a disc down, then every column with one of
+/// their discs at the bottom to pop it out.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The moves' selections (see `Move::from_selection`)
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::popout::valid_moves;
+///
+/// let mut game = Game::connect_four();
+///
+/// game.rules = Rules::PopOut;
+/// play_move(&mut game, 1, 1);
+/// play_move(&mut game, 2, 2);
+///
+/// // player 1 can drop down any column or pop their disc out of the first
+/// assert_eq!(valid_moves(&game), vec![1, 2, 3, 4, 5]);
+/// ```
+#[must_use]
+pub fn valid_moves(game: &Game) -> Vec<usize> {
+    (1..=game.width * 2)
+        .filter(|selection| check_move(game, *selection) == MoveStatus::Valid)
+        .collect()
+}
+
+/// This function is used to check how a `PopOut` game stands after a player's
+/// move. A pop can connect four for the opponent as well, so whoever has four
+/// in a line wins, and if both do it is a draw. It is also a draw once the same
+/// position comes up `REPEATS_TO_DRAW` times, or when the next player can't
+/// move at all.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player who just moved, counted from 1
+///
+/// # Returns
+///
+/// * `State::Won` - \
+///    Only the player has four in a line
+/// * `State::Lost` - \
+///    Only the opponent has four in a line
+/// * `State::NotOver` - \
+///    The game goes on
+/// * `State::Tie` - \
+///    Both players have four in a line, the position was repeated or the next
+///    player can't move
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, Rules, State};
+/// use tic_tac_toe_and_connect_4::game_lib::popout::status;
+///
+/// let mut game = Game::connect_four();
+///
+/// game.rules = Rules::PopOut;
+///
+/// // both players have four along a row
+/// for column in 0..4 {
+///     game.board[12 + column] = Some(Piece::stone(1));
+///     game.board[8 + column] = Some(Piece::stone(2));
+/// }
+///
+/// assert_eq!(status(&game, 1), State::Tie);
+/// ```
+#[must_use]
+pub fn status(game: &Game, player: usize) -> State {
+    let opponent = 3 - player.clamp(1, 2);
+    let has_four = |seat: usize| ttt_cnct_four_board_check(&game.board, game.width, game.win_length, seat);
+
+    match (has_four(player), has_four(opponent)) {
+        (true, true) => State::Tie,
+        (true, false) => State::Won,
+        (false, true) => State::Lost,
+        (false, false) if repetitions(game) >= REPEATS_TO_DRAW || valid_moves(game).is_empty() => State::Tie,
+        (false, false) => State::NotOver,
+    }
+}
@@ -0,0 +1,262 @@
+use super::game::{Cell, Game, State};
+use super::game_engine::line_score;
+
+/// The size of the cube when none is picked, which has 76 lines to win along
+pub const DEFAULT_SIZE: usize = 4;
+
+/// This function gives every line of `win_length` cells in a cube: along its
+/// rows, columns and pillars, across the diagonals of each layer and of each
+/// upright slice, and through the four space diagonals. Like the scan in
+/// `ttt_cnct_four_board_check`, each line is only walked from one end, so only
+/// 13 of the 26 directions are needed.
+///
+/// # Arguments
+///
+/// * `size` - \
+///    How many cells are on each side of the cube
+/// * `win_length` - \
+///    How many cells are in each line
+///
+/// # Returns
+///
+/// * `Vec<Vec<usize>>` - \
+///    The indexes of the cells in each line, where the cells are counted from
+///    0 layer by layer, then row by row from the top left
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::qubic::lines;
+///
+/// assert_eq!(lines(4, 4).len(), 76);
+/// assert_eq!(lines(3, 3).len(), 49);
+/// assert!(lines(4, 4).contains(&vec![0, 21, 42, 63]));
+/// ```
+#[must_use]
+pub fn lines(size: usize, win_length: usize) -> Vec<Vec<usize>> {
+    let mut lines = Vec::new();
+
+    for start in 0..size * size * size {
+        let position = [start / (size * size), start / size % size, start % size];
+
+        for direction in directions() {
+            // the line is only kept if every cell in it is inside the cube
+            let line: Option<Vec<usize>> = (0..win_length)
+                .map(|offset| {
+                    let [layer, row, column] = [0, 1, 2].map(|axis| walk(position[axis], direction[axis], offset));
+
+                    match (layer?, row?, column?) {
+                        (layer, row, column) if layer < size && row < size && column < size => {
+                            Some((layer * size + row) * size + column)
+                        }
+                        _ => None,
+                    }
+                })
+                .collect();
+
+            lines.extend(line);
+        }
+    }
+
+    lines
+}
+
+/// This function turns a layer, row and column into the cell they point at,
+/// which is how moves are given to the rest of the engine.
+///
+/// # Arguments
+///
+/// * `size` - \
+///    How many cells are on each side of the cube
+/// * `layer` - \
+///    The layer, counted from 1
+/// * `row` - \
+///    The row in the layer, counted from 1 at the top
+/// * `column` - \
+///    The column in the layer, counted from 1 on the left
+///
+/// # Returns
+///
+/// * `Some(usize)` - \
+///    The cell, counted from 1 layer by layer, then row by row
+/// * `None` - \
+///    One of them is not on the cube
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::qubic::cell_at;
+///
+/// assert_eq!(cell_at(4, 1, 1, 1), Some(1));
+/// assert_eq!(cell_at(4, 2, 3, 4), Some(28));
+/// assert_eq!(cell_at(4, 5, 1, 1), None);
+/// ```
+#[must_use]
+pub fn cell_at(size: usize, layer: usize, row: usize, column: usize) -> Option<usize> {
+    [layer, row, column]
+        .iter()
+        .all(|coordinate| (1..=size).contains(coordinate))
+        .then(|| (layer - 1) * size * size + (row - 1) * size + column)
+}
+
+/// This function reads a move typed as a layer, row and column, separated by
+/// spaces, commas or slashes. A single number is read as the cell itself.
+///
+/// # Arguments
+///
+/// * `size` - \
+///    How many cells are on each side of the cube
+/// * `input` - \
+///    What the player typed
+///
+/// # Returns
+///
+/// * `Some(usize)` - \
+///    The cell, counted from 1 layer by layer, then row by row
+/// * `None` - \
+///    The input isn't a move on the cube
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::qubic::parse_move;
+///
+/// assert_eq!(parse_move(4, "2 3 4"), Some(28));
+/// assert_eq!(parse_move(4, "2/3/4"), Some(28));
+/// assert_eq!(parse_move(4, "28"), Some(28));
+/// assert_eq!(parse_move(4, "2 3"), None);
+/// ```
+#[must_use]
+pub fn parse_move(size: usize, input: &str) -> Option<usize> {
+    let numbers = input
+        .split(|character: char| character.is_whitespace() || character == ',' || character == '/')
+        .filter(|number| !number.is_empty())
+        .map(|number| number.parse::<usize>().ok())
+        .collect::<Option<Vec<usize>>>()?;
+
+    match numbers.as_slice() {
+        [cell] => (1..=size * size * size).contains(cell).then_some(*cell),
+        [layer, row, column] => cell_at(size, *layer, *row, *column),
+        _ => None,
+    }
+}
+
+/// This function is used to check how a Qubic game stands after a player's
+/// move. They win with `win_length` pieces in any line through the cube, and
+/// it is a tie once the cube is full.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player who just moved, counted from 1
+///
+/// # Returns
+///
+/// * `State::Won` - \
+///    The player has won the game
+/// * `State::NotOver` - \
+///    The game goes on
+/// * `State::Tie` - \
+///    The cube is full and nobody has a line
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, State};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::qubic::status;
+///
+/// let mut game = Game::qubic(4);
+///
+/// // player 1 goes through the cube from corner to corner, while player 2
+/// // plays along the top row of the last layer
+/// for (current_player, selection) in [(1, 1), (2, 49), (1, 22), (2, 50), (1, 43), (2, 51), (1, 64)] {
+///     play_move(&mut game, current_player, selection);
+/// }
+///
+/// assert_eq!(status(&game, 1), State::Won);
+/// ```
+#[must_use]
+pub fn status(game: &Game, player: usize) -> State {
+    let is_won = lines(game.width, game.win_length)
+        .iter()
+        .any(|line| line.iter().all(|index| owner(&game.board[*index]) == Some(player)));
+
+    if is_won {
+        State::Won
+    } else if game.board.iter().all(Option::is_some) {
+        State::Tie
+    } else {
+        State::NotOver
+    }
+}
+
+/// This function is used to give a Qubic game a score for how close each
+/// player is to winning, scoring every line through the cube the same way
+/// `window_score` scores the lines on a grid.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is closer to winning, negative when the
+///    opponents are
+#[must_use]
+pub fn score(game: &Game, player: usize) -> i32 {
+    lines(game.width, game.win_length)
+        .into_iter()
+        .map(|line| line_score(&game.board, line, player))
+        .sum()
+}
+
+// the 13 directions a line can go in, one from each pair of opposites, as
+// steps in layers, rows and columns
+fn directions() -> Vec<[isize; 3]> {
+    let steps = [-1, 0, 1];
+    let mut directions = Vec::new();
+
+    for layer in steps {
+        for row in steps {
+            for column in steps {
+                let direction = [layer, row, column];
+
+                // the first step that isn't 0 going forward picks one of each pair
+                if direction.iter().find(|step| **step != 0) == Some(&1) {
+                    directions.push(direction);
+                }
+            }
+        }
+    }
+
+    directions
+}
+
+// moves a coordinate a number of steps forward, back or not at all
+fn walk(coordinate: usize, step: isize, offset: usize) -> Option<usize> {
+    match step {
+        1 => coordinate.checked_add(offset),
+        -1 => coordinate.checked_sub(offset),
+        _ => Some(coordinate),
+    }
+}
+
+// gives the seat of the player whose piece is in the cell, if there is one
+fn owner(cell: &Cell) -> Option<usize> {
+    cell.map(|piece| piece.owner)
+}
@@ -0,0 +1,287 @@
+use rand::Rng;
+use super::game::{Game, Mode};
+use super::player::{ControlMode, List, Player, Sprite};
+use super::ai_engine::Difficulty;
+use super::session::Session;
+
+/// The rating a player starts on in a mode they have not played yet
+pub const STARTING_RATING: f64 = 1200.0;
+/// The most a rating can move after one round against one opponent
+const K_FACTOR: f64 = 32.0;
+/// The rating `Difficulty::Easy` is held at when the difficulties are calibrated
+pub const EASY_RATING: f64 = 1000.0;
+
+/// A struct used to keep a player's Elo rating in one mode, along with every
+/// value it has had so it can be shown in their profile.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rating {
+    /// `mode` - \
+    ///  The mode the rating is for
+    pub mode: Mode,
+    /// `history` - \
+    ///  Every value the rating has had, oldest first, so the last one is the
+    ///  current rating
+    pub history: Vec<f64>,
+}
+
+impl Rating {
+    /// This constructor is used to start a rating for a mode at `STARTING_RATING`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - \
+    ///    The mode the rating is for
+    ///
+    /// # Returns
+    ///
+    /// * `Rating` - \
+    ///    A Rating struct with only the starting rating in its history
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::Mode;
+    /// use tic_tac_toe_and_connect_4::game_lib::rating::{Rating, STARTING_RATING};
+    ///
+    /// let rating = Rating::new(Mode::ConnectFour);
+    ///
+    /// assert_eq!(rating.value(), STARTING_RATING);
+    /// ```
+    #[must_use]
+    pub fn new(mode: Mode) -> Rating {
+        Rating {
+            mode,
+            history: vec![STARTING_RATING],
+        }
+    }
+
+    /// This function gives the current value of the rating.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - \
+    ///    The last value in the rating's history
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.history.last().copied().unwrap_or(STARTING_RATING)
+    }
+}
+
+/// This function gives the score a player is expected to get against an
+/// opponent, where a win is `1`, a tie is `0.5` and a loss is `0`.
+///
+/// # Arguments
+///
+/// * `rating` - \
+///    The player's rating
+/// * `opponent_rating` - \
+///    The opponent's rating
+///
+/// # Returns
+///
+/// * `f64` - \
+///    The expected score, between `0` and `1`
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::rating::expected_score;
+///
+/// assert_eq!(expected_score(1500.0, 1500.0), 0.5);
+/// assert!(expected_score(1600.0, 1200.0) > 0.9);
+/// ```
+#[must_use]
+pub fn expected_score(rating: f64, opponent_rating: f64) -> f64 {
+    1.0 / (1.0 + 10_f64.powf((opponent_rating - rating) / 400.0))
+}
+
+/// This function updates the ratings of everyone who played a finished round.
+/// Every pair of players is scored as its own game: the winner beat everyone
+/// else, and anyone who did not win drew with each other. With more than two
+/// players the changes are scaled down so a round is worth the same as a game
+/// between two. `Ai` players keep their measured ratings (see
+/// `Difficulty::rating`) and are not changed.
+///
+/// # Arguments
+///
+/// * `players` - \
+///    The players in the round
+/// * `mode` - \
+///    The mode the round was played in
+/// * `winner` - \
+///    The index of the player who won in `players`, or `None` for a tie
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Mode, Player, Sprite};
+/// use tic_tac_toe_and_connect_4::game_lib::rating::{update_ratings, STARTING_RATING};
+///
+/// let mut players = vec![
+///     Player::human(String::from("P1"), Sprite::new("X")),
+///     Player::human(String::from("P2"), Sprite::new("O")),
+/// ];
+///
+/// update_ratings(&mut players, Mode::TicTacToe, Some(0));
+///
+/// assert_eq!(players[0].rating(Mode::TicTacToe), STARTING_RATING + 16.0);
+/// assert_eq!(players[1].rating(Mode::TicTacToe), STARTING_RATING - 16.0);
+/// assert_eq!(players[0].rating(Mode::ConnectFour), STARTING_RATING);
+/// ```
+pub fn update_ratings(players: &mut [Player], mode: Mode, winner: Option<usize>) {
+    let ratings: Vec<f64> = players.iter().map(|player| player.rating(mode)).collect();
+    let num_of_opponents = u32::try_from(players.len().saturating_sub(1)).unwrap_or(u32::MAX).max(1);
+    let k_factor = K_FACTOR / f64::from(num_of_opponents);
+
+    for (index, player) in players.iter_mut().enumerate() {
+        if let ControlMode::Ai(_) = player.control {
+            continue;
+        }
+
+        let change: f64 = ratings
+            .iter()
+            .enumerate()
+            .filter(|(opponent, _)| *opponent != index)
+            .map(|(opponent, opponent_rating)| {
+                let score = match winner {
+                    Some(winner) if winner == index => 1.0,
+                    Some(winner) if winner == opponent => 0.0,
+                    _ => 0.5,
+                };
+
+                k_factor * (score - expected_score(ratings[index], *opponent_rating))
+            })
+            .sum();
+
+        player.set_rating(mode, ratings[index] + change);
+    }
+}
+
+/// This function picks the `Ai` difficulty whose measured rating is closest
+/// to a player's, so they can be offered an opponent they have about an even
+/// chance against.
+///
+/// # Arguments
+///
+/// * `rating` - \
+///    The rating of the player looking for an opponent
+/// * `mode` - \
+///    The mode they want to play
+///
+/// # Returns
+///
+/// * `Difficulty` - \
+///    The fairest difficulty for them
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Difficulty, Mode};
+/// use tic_tac_toe_and_connect_4::game_lib::rating::fair_difficulty;
+///
+/// assert_eq!(fair_difficulty(900.0, Mode::TicTacToe), Difficulty::Easy);
+/// assert_eq!(fair_difficulty(2000.0, Mode::ConnectFour), Difficulty::Hard);
+/// ```
+#[must_use]
+pub fn fair_difficulty(rating: f64, mode: Mode) -> Difficulty {
+    Difficulty::ALL
+        .into_iter()
+        .min_by(|first, second| {
+            (first.rating(mode) - rating).abs().total_cmp(&(second.rating(mode) - rating).abs())
+        })
+        .unwrap_or_default()
+}
+
+/// This function measures the rating each `Ai` difficulty plays at in a game
+/// by having them play each other. `Easy` plays `Medium` and `Medium` plays
+/// `Hard` for `games` rounds each, taking turns to move first, and the score
+/// the stronger one gets is turned into a rating gap with the Elo formula,
+/// starting from `Easy` at `EASY_RATING`. A pair that never drops a point is
+/// counted as dropping half of one, so the gap stays finite. The ratings in
+/// `Difficulty::rating` were measured this way.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game to play, set up with its board and rules
+/// * `games` - \
+///    How many rounds each pair of difficulties plays
+/// * `rng` - \
+///    The random number generator used by the `Ai`
+///
+/// # Returns
+///
+/// * `[f64; 3]` - \
+///    The ratings of `Easy`, `Medium` and `Hard`, in the order of
+///    `Difficulty::ALL`
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::rating::{calibrate, EASY_RATING};
+///
+/// let ratings = calibrate(&Game::tic_tac_toe(), 10, &mut StdRng::seed_from_u64(1));
+///
+/// assert_eq!(ratings[0], EASY_RATING);
+/// assert!(ratings[2] > ratings[0]);
+/// ```
+pub fn calibrate(game: &Game, games: u32, rng: &mut impl Rng) -> [f64; 3] {
+    let mut ratings = [EASY_RATING; 3];
+
+    for stronger in 1..Difficulty::ALL.len() {
+        let score = pair_score(game, Difficulty::ALL[stronger - 1], Difficulty::ALL[stronger], games, rng);
+
+        ratings[stronger] = ratings[stronger - 1] + rating_gap(score, games);
+    }
+
+    ratings
+}
+
+// plays two difficulties against each other, giving the share of the points
+// the stronger one took
+fn pair_score(game: &Game, weaker: Difficulty, stronger: Difficulty, games: u32, rng: &mut impl Rng) -> f64 {
+    let mut players = vec![Player::ai(Sprite::new("X")), Player::ai(Sprite::new("O"))];
+
+    players[0].control = ControlMode::Ai(weaker);
+    players[1].control = ControlMode::Ai(stronger);
+
+    let mut session = Session::new(game.clone(), List::new(players));
+    let mut points = 0.0;
+
+    for round in 0..games {
+        session.next_round_from(if round % 2 == 0 { 1 } else { 2 });
+
+        while let Some(selection) = session.ai_move(rng) {
+            let _ = session.submit_move(selection);
+        }
+
+        points += match session.winner() {
+            Some(winner) if winner.control == ControlMode::Ai(stronger) => 1.0,
+            Some(_) => 0.0,
+            None => 0.5,
+        };
+    }
+
+    points / f64::from(games.max(1))
+}
+
+// the rating gap that makes `score` the expected score (see `expected_score`)
+fn rating_gap(score: f64, games: u32) -> f64 {
+    let margin = 0.5 / f64::from(games.max(1));
+    let score = score.clamp(margin, 1.0 - margin);
+
+    400.0 * (score / (1.0 - score)).log10()
+}
@@ -0,0 +1,395 @@
+use std::cmp::Ordering;
+use super::game::{Cell, Game, MoveStatus, Piece, State};
+use super::game_engine::player_to_move;
+
+/// The size of the board when none is picked
+pub const DEFAULT_SIZE: usize = 8;
+/// How much more a corner is worth than any other disc when the `Ai` scores
+/// the game, since a disc there can never be flipped
+const CORNER_WEIGHT: i32 = 20;
+/// How much each move a player could make is worth when the `Ai` scores the
+/// game
+const MOBILITY_WEIGHT: i32 = 2;
+/// The 8 directions a line of discs can be flanked in, as steps in rows and
+/// columns
+const DIRECTIONS: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// This function gives the board a game of Reversi starts on: two discs for
+/// each player crossed in the middle, player 2 (white) on the top left and
+/// bottom right of them and player 1 (black) on the other two.
+///
+/// # Arguments
+///
+/// * `width` - \
+///    How many columns are on the board, which should be even
+/// * `height` - \
+///    How many rows are on the board, which should be even
+///
+/// # Returns
+///
+/// * `Vec<Cell>` - \
+///    The cells of the board, row by row from the top left
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Piece;
+/// use tic_tac_toe_and_connect_4::game_lib::reversi::starting_board;
+///
+/// let board = starting_board(8, 8);
+///
+/// // d4 and e5 are white, e4 and d5 are black
+/// assert_eq!(board[27], Some(Piece::stone(2)));
+/// assert_eq!(board[28], Some(Piece::stone(1)));
+/// assert_eq!(board.iter().filter(|cell| cell.is_some()).count(), 4);
+/// ```
+#[must_use]
+pub fn starting_board(width: usize, height: usize) -> Vec<Cell> {
+    let mut board = vec![None; width * height];
+    let (row, column) = (height / 2, width / 2);
+
+    if row > 0 && column > 0 {
+        for (row, column, player) in [(row - 1, column - 1, 2), (row - 1, column, 1), (row, column - 1, 1), (row, column, 2)] {
+            board[row * width + column] = Some(Piece::stone(player));
+        }
+    }
+
+    board
+}
+
+/// This function gives the discs a player would flip by playing in a cell:
+/// every line of the opponent's discs running from the cell that ends in one
+/// of the player's own discs.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The cells of the board, row by row from the top left
+/// * `width` - \
+///    How many columns are on the board
+/// * `player` - \
+///    The seat of the player moving, counted from 1
+/// * `selection` - \
+///    The cell being played, counted from 1
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The cells that would be flipped, counted from 1. The move is only legal
+///    when there is at least one
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::reversi::{flips, starting_board};
+///
+/// let board = starting_board(8, 8);
+///
+/// // d3 flanks the white disc on d4 against the black disc on d5
+/// assert_eq!(flips(&board, 8, 1, 20), vec![28]);
+/// assert!(flips(&board, 8, 1, 1).is_empty());
+/// ```
+#[must_use]
+pub fn flips(board: &[Cell], width: usize, player: usize, selection: usize) -> Vec<usize> {
+    if selection == 0 || selection > board.len() || board[selection - 1].is_some() {
+        return Vec::new();
+    }
+
+    let height = board.len() / width;
+    let start = (selection - 1) / width;
+    let start_column = (selection - 1) % width;
+    let mut flipped = Vec::new();
+
+    for (row_step, column_step) in DIRECTIONS {
+        let mut line = Vec::new();
+        let mut row = start;
+        let mut column = start_column;
+
+        while let (Some(next_row), Some(next_column)) = (step(row, row_step, height), step(column, column_step, width)) {
+            row = next_row;
+            column = next_column;
+
+            match board[row * width + column] {
+                Some(piece) if piece.owner == player => {
+                    flipped.append(&mut line);
+
+                    break;
+                }
+                Some(_) => line.push(row * width + column + 1),
+                None => break,
+            }
+        }
+    }
+
+    flipped.sort_unstable();
+    flipped
+}
+
+/// This function gives every cell a player could play in, which is every free
+/// cell where they would flip at least one disc.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The cells of the board, row by row from the top left
+/// * `width` - \
+///    How many columns are on the board
+/// * `player` - \
+///    The seat of the player moving, counted from 1
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The cells that can be played, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::reversi::{legal_moves, starting_board};
+///
+/// assert_eq!(legal_moves(&starting_board(8, 8), 8, 1), vec![20, 27, 38, 45]);
+/// ```
+#[must_use]
+pub fn legal_moves(board: &[Cell], width: usize, player: usize) -> Vec<usize> {
+    (1..=board.len())
+        .filter(|selection| !flips(board, width, player, *selection).is_empty())
+        .collect()
+}
+
+/// This function is used to validate a move in Reversi. On top of the cell
+/// being free, the disc has to flank at least one of the opponent's.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The cell the player picked, counted from 1
+///
+/// # Returns
+///
+/// * `MoveStatus::Valid` - \
+///    The move can be played
+/// * `MoveStatus::Invalid(error_str)` - \
+///    The move can't be played and the error explains why
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus};
+/// use tic_tac_toe_and_connect_4::game_lib::reversi::check_move;
+///
+/// let game = Game::reversi(8);
+///
+/// assert_eq!(check_move(&game, 20), MoveStatus::Valid);
+/// assert_eq!(check_move(&game, 1), MoveStatus::Invalid("Your disc has to flank at least one of your opponent's"));
+/// ```
+#[must_use]
+pub fn check_move(game: &Game, selection: usize) -> MoveStatus<'static> {
+    if selection == 0 || selection > game.board.len() {
+        return MoveStatus::Invalid("Selected cell was out of range");
+    }
+
+    if game.board[selection - 1].is_some() {
+        return MoveStatus::Invalid("A player was already there");
+    }
+
+    if flips(&game.board, game.width, player_to_move(game), selection).is_empty() {
+        return MoveStatus::Invalid("Your disc has to flank at least one of your opponent's");
+    }
+
+    MoveStatus::Valid
+}
+
+/// This function gives every move the next player is allowed to make.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The cells that can be picked, counted from 1
+#[must_use]
+pub fn valid_moves(game: &Game) -> Vec<usize> {
+    legal_moves(&game.board, game.width, player_to_move(game))
+}
+
+/// This function puts a player's disc in a cell and flips every disc of the
+/// opponent's it flanks, in all 8 directions at once.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The cells of the board, row by row from the top left
+/// * `width` - \
+///    How many columns are on the board
+/// * `player` - \
+///    The seat of the player moving, counted from 1
+/// * `selection` - \
+///    The cell being played, counted from 1
+///
+/// # Returns
+///
+/// * `Vec<Cell>` - \
+///    The board after the move
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Piece;
+/// use tic_tac_toe_and_connect_4::game_lib::reversi::{place, starting_board};
+///
+/// let board = place(starting_board(8, 8), 8, 1, 20);
+///
+/// assert_eq!(board[19], Some(Piece::stone(1)));
+/// assert_eq!(board[27], Some(Piece::stone(1)));
+/// ```
+#[must_use]
+pub fn place(mut board: Vec<Cell>, width: usize, player: usize, selection: usize) -> Vec<Cell> {
+    for flipped in flips(&board, width, player, selection) {
+        board[flipped - 1] = Some(Piece::stone(player));
+    }
+
+    board[selection - 1] = Some(Piece::stone(player));
+    board
+}
+
+/// This function is used to check how a Reversi game stands after a player's
+/// move. The game goes on while either player can move, and once neither can
+/// whoever has the most discs wins.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player who just moved, counted from 1
+///
+/// # Returns
+///
+/// * `State::Won` - \
+///    Nobody can move and the player has more discs
+/// * `State::Lost` - \
+///    Nobody can move and the opponent has more discs
+/// * `State::NotOver` - \
+///    The game goes on
+/// * `State::Tie` - \
+///    Nobody can move and both players have as many discs
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, State};
+/// use tic_tac_toe_and_connect_4::game_lib::reversi::status;
+///
+/// let mut game = Game::reversi(8);
+///
+/// assert_eq!(status(&game, 1), State::NotOver);
+///
+/// // a board with only player 2's discs left on it can't be played on
+/// game.board = vec![None; 64];
+/// game.board[0] = Some(Piece::stone(2));
+///
+/// assert_eq!(status(&game, 1), State::Lost);
+/// assert_eq!(status(&game, 2), State::Won);
+/// ```
+#[must_use]
+pub fn status(game: &Game, player: usize) -> State {
+    let opponent = opponent_of(player);
+
+    if [player, opponent].iter().any(|seat| !legal_moves(&game.board, game.width, *seat).is_empty()) {
+        return State::NotOver;
+    }
+
+    match discs(&game.board, player).cmp(&discs(&game.board, opponent)) {
+        Ordering::Greater => State::Won,
+        Ordering::Less => State::Lost,
+        Ordering::Equal => State::Tie,
+    }
+}
+
+/// This function is used to give a Reversi game a score for how well a player
+/// is doing. Discs count for a little, corners for a lot, and so does having
+/// more moves to pick from than the opponent.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is doing better, negative when the
+///    opponent is
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece};
+/// use tic_tac_toe_and_connect_4::game_lib::reversi::score;
+///
+/// let mut game = Game::reversi(8);
+///
+/// assert_eq!(score(&game, 1), 0);
+///
+/// game.board[0] = Some(Piece::stone(1));
+///
+/// assert!(score(&game, 1) > 0);
+/// ```
+#[must_use]
+pub fn score(game: &Game, player: usize) -> i32 {
+    let last = game.board.len().saturating_sub(1);
+    let corners = [0, game.width - 1, last + 1 - game.width, last];
+    let position: i32 = game
+        .board
+        .iter()
+        .enumerate()
+        .filter_map(|(index, cell)| {
+            let weight = if corners.contains(&index) { CORNER_WEIGHT } else { 1 };
+
+            cell.map(|piece| if piece.owner == player { weight } else { -weight })
+        })
+        .sum();
+    let mobility = |seat: usize| i32::try_from(legal_moves(&game.board, game.width, seat).len()).unwrap_or(i32::MAX);
+
+    position + (mobility(player) - mobility(opponent_of(player))) * MOBILITY_WEIGHT
+}
+
+// black and white are the only sides in Reversi
+fn opponent_of(player: usize) -> usize {
+    if player == 1 {
+        2
+    } else {
+        1
+    }
+}
+
+// counts a player's discs on the board
+fn discs(board: &[Cell], player: usize) -> usize {
+    board.iter().flatten().filter(|piece| piece.owner == player).count()
+}
+
+// moves a row or column one step, as long as it stays on the board
+fn step(coordinate: usize, step: isize, limit: usize) -> Option<usize> {
+    coordinate.checked_add_signed(step).filter(|next| *next < limit)
+}
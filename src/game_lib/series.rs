@@ -0,0 +1,246 @@
+use std::cmp::Reverse;
+use std::fmt;
+use rand::Rng;
+
+/// An enumerator used to pick who moves first in each game of a series
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FirstMove {
+    /// `Alternate` - \
+    ///  The players take turns moving first, starting with player 1
+    #[default]
+    Alternate,
+    /// `LoserChooses` - \
+    ///  Whoever lost the last game picks who moves first. Player 1 moves first
+    ///  in the first game, and after a tie the first move passes on as it does
+    ///  with `Alternate`
+    LoserChooses,
+    /// `CoinFlip` - \
+    ///  A random player moves first in each game
+    CoinFlip,
+}
+
+impl FirstMove {
+    /// Every way of picking the first player a user can choose from
+    pub const ALL: [FirstMove; 3] = [FirstMove::Alternate, FirstMove::LoserChooses, FirstMove::CoinFlip];
+}
+
+// Just a println formatter for the ways of picking the first player
+impl fmt::Display for FirstMove {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FirstMove::Alternate => write!(format_buffer, "Alternating"),
+            FirstMove::LoserChooses => write!(format_buffer, "Loser Chooses"),
+            FirstMove::CoinFlip => write!(format_buffer, "Coin Flip"),
+        }
+    }
+}
+
+/// A struct used to keep score in a best-of-N series between the players in a
+/// Session. The series is over as soon as one player has clinched it, which is
+/// when nobody could catch them in the games that are left, or once every game
+/// has been played. Players are counted from 1, the same as
+/// `Session::current_player_number`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Series {
+    /// `best_of` - \
+    ///  The most games the series can last
+    pub best_of: usize,
+    /// `first_move` - \
+    ///  How the player who moves first in each game is picked
+    pub first_move: FirstMove,
+    /// `num_of_players` - \
+    ///  How many players are in the series
+    pub num_of_players: usize,
+    /// `games` - \
+    ///  The player who moved first and the winner (`None` for a tie) of every
+    ///  game played so far, in order
+    pub games: Vec<(usize, Option<usize>)>,
+}
+
+impl Series {
+    /// This constructor is used to start a series with no games played.
+    ///
+    /// # Arguments
+    ///
+    /// * `best_of` - \
+    ///    The most games the series can last
+    /// * `first_move` - \
+    ///    How the player who moves first in each game is picked
+    /// * `num_of_players` - \
+    ///    How many players are in the series
+    ///
+    /// # Returns
+    ///
+    /// * `Series` - \
+    ///    A Series struct ready for its first game
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::game_lib::series::{FirstMove, Series};
+    ///
+    /// let mut series = Series::new(5, FirstMove::Alternate, 2);
+    ///
+    /// series.record(1, Some(1));
+    /// series.record(2, Some(1));
+    /// series.record(1, None);
+    ///
+    /// assert!(!series.is_over());
+    ///
+    /// series.record(2, Some(1));
+    ///
+    /// // three wins can't be caught with one game left
+    /// assert!(series.is_over());
+    /// assert_eq!(series.winner(), Some(1));
+    /// ```
+    #[must_use]
+    pub fn new(best_of: usize, first_move: FirstMove, num_of_players: usize) -> Series {
+        Series {
+            best_of,
+            first_move,
+            num_of_players,
+            games: Vec::new(),
+        }
+    }
+
+    /// This function gives how many games a player has won in the series.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_number` - \
+    ///    The player's seat, counted from 1
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The player's wins
+    #[must_use]
+    pub fn wins(&self, player_number: usize) -> usize {
+        self.games.iter().filter(|(_, winner)| *winner == Some(player_number)).count()
+    }
+
+    /// This function gives how many games in the series were tied.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The number of ties
+    #[must_use]
+    pub fn ties(&self) -> usize {
+        self.games.iter().filter(|(_, winner)| winner.is_none()).count()
+    }
+
+    /// This function gives the player who has won the series. While the series
+    /// is going this is only someone who has clinched it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - \
+    ///    The winner's seat, counted from 1
+    /// * `None` - \
+    ///    Nobody has clinched the series yet, or it ended level
+    #[must_use]
+    pub fn winner(&self) -> Option<usize> {
+        let games_left = self.best_of.saturating_sub(self.games.len());
+        let leader = (1..=self.num_of_players).max_by_key(|player| (self.wins(*player), Reverse(*player)))?;
+
+        (1..=self.num_of_players)
+            .filter(|player| *player != leader)
+            .all(|player| self.wins(player) + games_left < self.wins(leader))
+            .then_some(leader)
+    }
+
+    /// This function checks if the series is over, either because someone has
+    /// clinched it or every game has been played.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - \
+    ///    `true` if no more games should be played
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.games.len() >= self.best_of || self.winner().is_some()
+    }
+
+    /// This function gives the player who lost the last game, who gets to pick
+    /// who moves first next when the loser chooses. When there are more than
+    /// two players, it is whichever player who didn't win has the fewest wins
+    /// in the series.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - \
+    ///    The loser's seat, counted from 1
+    /// * `None` - \
+    ///    No game has been played yet, or the last one was a tie
+    #[must_use]
+    pub fn last_loser(&self) -> Option<usize> {
+        let (_, Some(winner)) = self.games.last()? else {
+            return None;
+        };
+
+        (1..=self.num_of_players)
+            .filter(|player| player != winner)
+            .min_by_key(|player| (self.wins(*player), *player))
+    }
+
+    /// This function picks the player who moves first in the next game. When
+    /// the loser chooses, this is the loser, who can be asked to pick someone
+    /// else.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - \
+    ///    The random number generator used to flip the coin
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The first player's seat, counted from 1
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::game_lib::series::{FirstMove, Series};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut series = Series::new(3, FirstMove::Alternate, 2);
+    ///
+    /// assert_eq!(series.next_first_player(&mut rng), 1);
+    ///
+    /// series.record(1, Some(1));
+    ///
+    /// assert_eq!(series.next_first_player(&mut rng), 2);
+    ///
+    /// let mut series = Series::new(3, FirstMove::LoserChooses, 2);
+    ///
+    /// series.record(1, Some(2));
+    ///
+    /// assert_eq!(series.next_first_player(&mut rng), 1);
+    /// ```
+    pub fn next_first_player(&self, rng: &mut impl Rng) -> usize {
+        let alternate = self.games.last().map_or(1, |(first, _)| first % self.num_of_players.max(1) + 1);
+
+        match self.first_move {
+            FirstMove::Alternate => alternate,
+            FirstMove::LoserChooses => self.last_loser().unwrap_or(alternate),
+            FirstMove::CoinFlip => rng.gen_range(1..=self.num_of_players.max(1)),
+        }
+    }
+
+    /// This function adds a finished game to the series.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_player` - \
+    ///    The seat of the player who moved first
+    /// * `winner` - \
+    ///    The seat of the player who won, or `None` for a tie
+    pub fn record(&mut self, first_player: usize, winner: Option<usize>) {
+        self.games.push((first_player, winner));
+    }
+}
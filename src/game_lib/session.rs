@@ -0,0 +1,432 @@
+use rand::Rng;
+use super::game::{Game, State, MoveStatus};
+use super::player::{List, Player, ControlMode, Outcome};
+use super::ai_engine::{think, Difficulty};
+use super::bot::Bots;
+use super::rating::update_ratings;
+use super::game_engine::{check_move, play_move, valid_moves};
+
+/// A struct used to run a match between the players in a List. It keeps track
+/// of whose turn it is, checks and applies moves, and updates the winner's score,
+/// so any frontend can drive a game without its own turn loop. The players take
+/// turns in the order they are listed, going back to player 1 after the last,
+/// skipping anyone who has to pass.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Session {
+    /// `game` - \
+    ///  The Game being played
+    pub game: Game,
+    /// `player_list` - \
+    ///  The players in the match and their scores
+    pub player_list: List,
+    turn: usize,
+    bots: Bots,
+}
+
+impl Session {
+    /// This constructor is used to start a session with player 1 moving first.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - \
+    ///    The Game to play, it should be ready for a new round
+    /// * `player_list` - \
+    ///    The players in the match
+    ///
+    /// # Returns
+    ///
+    /// * `Session` - \
+    ///    A Session struct ready for the first move
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Player, Session, Sprite};
+    ///
+    /// let session = Session::new(Game::tic_tac_toe(), List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("H")),
+    /// ]));
+    ///
+    /// assert_eq!(session.whose_turn().name, "P1");
+    /// ```
+    #[must_use]
+    pub fn new(mut game: Game, player_list: List) -> Session {
+        game.first_player = 1;
+
+        Session {
+            game,
+            player_list,
+            turn: 0,
+            bots: Bots::default(),
+        }
+    }
+
+    /// This function gives the player who has to make the next move. Once the
+    /// round is won, this is the player who won it.
+    ///
+    /// # Returns
+    ///
+    /// * `&Player` - \
+    ///    A reference to the current player
+    #[must_use]
+    pub fn whose_turn(&self) -> &Player {
+        &self.player_list.players[self.turn]
+    }
+
+    /// This function gives the number of the player who has to make the next
+    /// move, so a frontend can tell which of its seats should be asked.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The player's seat, counted from 1
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Player, Session, Sprite};
+    ///
+    /// let mut session = Session::new(Game::tic_tac_toe(), List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::human(String::from("P2"), Sprite::new("O")),
+    ///     Player::human(String::from("P3"), Sprite::new("Z")),
+    /// ]));
+    ///
+    /// for selection in [1, 2, 3] {
+    ///     let _ = session.submit_move(selection);
+    /// }
+    ///
+    /// // after the last player it is player 1's turn again
+    /// assert_eq!(session.current_player_number(), 1);
+    /// ```
+    #[must_use]
+    pub fn current_player_number(&self) -> usize {
+        self.turn + 1
+    }
+
+    /// This function gives the players who are waiting for the current player
+    /// to move, in the order they will move.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<&Player>` - \
+    ///    References to every other player, starting with the next one
+    #[must_use]
+    pub fn opponents(&self) -> Vec<&Player> {
+        let players = &self.player_list.players;
+
+        (1..players.len())
+            .map(|offset| &players[(self.turn + offset) % players.len()])
+            .collect()
+    }
+
+    /// This function gives the seats of the players who are waiting for the
+    /// current player to move, in the order they will move.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - \
+    ///    Every other player's seat, counted from 1, starting with the next one
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Player, Session, Sprite};
+    ///
+    /// let session = Session::new(Game::tic_tac_toe(), List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::human(String::from("P2"), Sprite::new("O")),
+    ///     Player::human(String::from("P3"), Sprite::new("Z")),
+    /// ]));
+    ///
+    /// assert_eq!(session.opponent_numbers(), vec![2, 3]);
+    /// ```
+    #[must_use]
+    pub fn opponent_numbers(&self) -> Vec<usize> {
+        let num_of_players = self.player_list.players.len();
+
+        (1..num_of_players)
+            .map(|offset| (self.turn + offset) % num_of_players + 1)
+            .collect()
+    }
+
+    /// This function gives every move the current player is allowed to make.
+    /// When the round is over there are no legal moves.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - \
+    ///    The cells (or columns for Connect-4) that can be picked
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Player, Session, Sprite};
+    ///
+    /// let mut session = Session::new(Game::tic_tac_toe(), List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::human(String::from("P2"), Sprite::new("O")),
+    /// ]));
+    ///
+    /// let _ = session.submit_move(5);
+    ///
+    /// assert_eq!(session.legal_moves(), vec![1, 2, 3, 4, 6, 7, 8, 9]);
+    /// ```
+    #[must_use]
+    pub fn legal_moves(&self) -> Vec<usize> {
+        if self.game.current_state != State::NotOver {
+            return Vec::new();
+        }
+
+        valid_moves(&self.game)
+    }
+
+    /// This function checks a move for the current player and, if it is valid,
+    /// plays it. The game's state is updated, and if the round goes on it becomes
+    /// the next player's turn, or the same player's again if everyone else has
+    /// to pass. Once the round is over everyone's record and rating in the mode
+    /// is updated, and when the player who moved lost it the next player wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `selection` - \
+    ///    The cell (or column for Connect-4) the current player picked
+    ///
+    /// # Returns
+    ///
+    /// * `MoveStatus::Valid` - \
+    ///    The move was played, see `result` for how the round stands
+    /// * `MoveStatus::Invalid(error_str)` - \
+    ///    The move was not played and the error explains why
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Mode, MoveStatus, Player, Session, Sprite, State};
+    ///
+    /// let mut session = Session::new(Game::tic_tac_toe(), List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::human(String::from("P2"), Sprite::new("O")),
+    /// ]));
+    ///
+    /// for selection in [1, 4, 2, 5, 3] {
+    ///     assert_eq!(session.submit_move(selection), MoveStatus::Valid);
+    /// }
+    ///
+    /// assert_eq!(session.submit_move(6), MoveStatus::Invalid("The round is already over"));
+    /// assert_eq!(session.result(), State::Won);
+    /// assert_eq!(session.player_list.players[0].wins, 1);
+    /// assert_eq!(session.player_list.players[1].record(Mode::TicTacToe).losses, 1);
+    /// assert!(session.player_list.players[0].rating(Mode::TicTacToe) > session.player_list.players[1].rating(Mode::TicTacToe));
+    /// ```
+    pub fn submit_move(&mut self, selection: usize) -> MoveStatus<'static> {
+        if self.game.current_state != State::NotOver {
+            return MoveStatus::Invalid("The round is already over");
+        }
+
+        let current_player = self.current_player_number();
+        let status = check_move(&self.game, selection);
+
+        if status != MoveStatus::Valid {
+            return status;
+        }
+
+        let turns_before = self.game.num_of_turns;
+
+        play_move(&mut self.game, current_player, selection);
+
+        let mode = self.game.current_mode;
+        let num_of_players = self.player_list.players.len();
+
+        match self.game.current_state {
+            State::Won | State::Lost => {
+                if self.game.current_state == State::Lost {
+                    self.turn = (self.turn + 1) % num_of_players;
+                }
+
+                for (index, player) in self.player_list.players.iter_mut().enumerate() {
+                    player.add_result(mode, if index == self.turn { Outcome::Win } else { Outcome::Loss });
+                }
+
+                update_ratings(&mut self.player_list.players, mode, Some(self.turn));
+            }
+            State::Tie => {
+                for player in &mut self.player_list.players {
+                    player.add_result(mode, Outcome::Tie);
+                }
+
+                update_ratings(&mut self.player_list.players, mode, None);
+            }
+            // a player who has to pass still uses up a turn
+            State::NotOver => self.turn = (self.turn + self.game.num_of_turns - turns_before) % num_of_players,
+        }
+
+        MoveStatus::Valid
+    }
+
+    /// This function picks a move for the current player if they are an `Ai` or
+    /// a `Bot`. The move still has to be played with `submit_move`. If a bot can't
+    /// be started, stops answering or picks a move that can't be played, the
+    /// `Hard` `Ai` moves for it instead so the match can go on.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - \
+    ///    The random number generator used by the `Ai`
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - \
+    ///    The move the `Ai` picked
+    /// * `None` - \
+    ///    The current player is a human or the round is over
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Player, Session, Sprite};
+    ///
+    /// let mut session = Session::new(Game::tic_tac_toe(), List::new(vec![
+    ///     Player::ai(Sprite::new("H")),
+    ///     Player::human(String::from("P2"), Sprite::new("O")),
+    /// ]));
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// if let Some(selection) = session.ai_move(&mut rng) {
+    ///     let _ = session.submit_move(selection);
+    /// }
+    ///
+    /// assert_eq!(session.ai_move(&mut rng), None);
+    /// ```
+    pub fn ai_move(&mut self, rng: &mut impl Rng) -> Option<usize> {
+        if self.game.current_state != State::NotOver {
+            return None;
+        }
+
+        let difficulty = match self.whose_turn().control.clone() {
+            ControlMode::Human => return None,
+            ControlMode::Ai(difficulty) => difficulty,
+            ControlMode::Bot(command) => {
+                let player_number = self.current_player_number();
+                let num_of_players = self.player_list.players.len();
+
+                match self.bots.best_move(player_number, &command, &self.game, num_of_players) {
+                    Ok(selection) if self.legal_moves().contains(&selection) => return Some(selection),
+                    _ => Difficulty::Hard,
+                }
+            }
+        };
+
+        Some(think(
+            &self.game,
+            self.current_player_number(),
+            &self.opponent_numbers(),
+            difficulty,
+            rng,
+        ))
+    }
+
+    /// This function gives how the current round stands.
+    ///
+    /// # Returns
+    ///
+    /// * `State` - \
+    ///    `NotOver` while the round is being played, then `Won`, `Lost` (by the
+    ///    player who moved last) or `Tie`
+    #[must_use]
+    pub fn result(&self) -> State {
+        self.game.current_state
+    }
+
+    /// This function gives the player who won the round, if anyone has.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Player)` - \
+    ///    A reference to the winner
+    /// * `None` - \
+    ///    The round is still going or it was a tie
+    #[must_use]
+    pub fn winner(&self) -> Option<&Player> {
+        match self.game.current_state {
+            State::Won | State::Lost => Some(self.whose_turn()),
+            State::Tie | State::NotOver => None,
+        }
+    }
+
+    /// This function clears the board for a new round. The players keep their
+    /// scores and player 1 moves first again.
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Player, Session, Sprite, State};
+    ///
+    /// let mut session = Session::new(Game::tic_tac_toe(), List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::human(String::from("P2"), Sprite::new("O")),
+    /// ]));
+    ///
+    /// for selection in [1, 4, 2, 5, 3] {
+    ///     let _ = session.submit_move(selection);
+    /// }
+    ///
+    /// session.next_round();
+    ///
+    /// assert_eq!(session.result(), State::NotOver);
+    /// assert_eq!(session.player_list.players[0].wins, 1);
+    /// ```
+    pub fn next_round(&mut self) {
+        self.next_round_from(1);
+    }
+
+    /// This function clears the board for a new round that a chosen player
+    /// starts. The players keep their scores and still take turns in the order
+    /// they are listed, going back to player 1 after the last.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_number` - \
+    ///    The seat of the player who moves first, counted from 1. Player 1
+    ///    moves first if there is no such seat
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, List, Player, Session, Sprite};
+    ///
+    /// let mut session = Session::new(Game::tic_tac_toe(), List::new(vec![
+    ///     Player::human(String::from("P1"), Sprite::new("X")),
+    ///     Player::human(String::from("P2"), Sprite::new("O")),
+    /// ]));
+    ///
+    /// session.next_round_from(2);
+    ///
+    /// assert_eq!(session.whose_turn().name, "P2");
+    /// ```
+    pub fn next_round_from(&mut self, player_number: usize) {
+        self.game.reset();
+        self.turn = player_number
+            .checked_sub(1)
+            .filter(|turn| *turn < self.player_list.players.len())
+            .unwrap_or(0);
+        self.game.first_player = self.turn + 1;
+    }
+}
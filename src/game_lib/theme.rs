@@ -0,0 +1,180 @@
+use std::env;
+use std::fmt;
+use std::io::{self, IsTerminal};
+
+/// The escape sequence that puts the terminal back to its normal colours
+const RESET: &str = "\x1b[0m";
+
+/// An enumerator used to give each player their own colour on the board
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Colour {
+    /// `Red` - \
+    ///  The classic colour of the first player in Connect-4
+    #[default]
+    Red,
+    /// `Yellow` - \
+    ///  The classic colour of the second player in Connect-4
+    Yellow,
+    /// `Blue` - \
+    ///  A blue player
+    Blue,
+    /// `Green` - \
+    ///  A green player
+    Green,
+    /// `Magenta` - \
+    ///  A magenta player
+    Magenta,
+    /// `Cyan` - \
+    ///  A cyan player
+    Cyan,
+}
+
+impl Colour {
+    /// Every colour a player can pick from, in the order they are shown in the
+    /// player editor
+    pub const ALL: [Colour; 6] = [
+        Colour::Red,
+        Colour::Yellow,
+        Colour::Blue,
+        Colour::Green,
+        Colour::Magenta,
+        Colour::Cyan,
+    ];
+}
+
+// Just a println formatter for the colours
+impl fmt::Display for Colour {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Colour::Red => write!(format_buffer, "Red"),
+            Colour::Yellow => write!(format_buffer, "Yellow"),
+            Colour::Blue => write!(format_buffer, "Blue"),
+            Colour::Green => write!(format_buffer, "Green"),
+            Colour::Magenta => write!(format_buffer, "Magenta"),
+            Colour::Cyan => write!(format_buffer, "Cyan"),
+        }
+    }
+}
+
+/// An enumerator used to pick the palette the board is drawn with
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Theme {
+    /// `Classic` - \
+    ///  The standard 8 terminal colours
+    #[default]
+    Classic,
+    /// `HighContrast` - \
+    ///  Bold, bright colours that stand out on dim or washed out screens
+    HighContrast,
+    /// `ColourBlindSafe` - \
+    ///  The Okabe-Ito palette, which stays distinguishable for the common
+    ///  forms of colour blindness
+    ColourBlindSafe,
+}
+
+impl Theme {
+    /// Every theme a user can pick from, in the order they are shown in the
+    /// game editor
+    pub const ALL: [Theme; 3] = [Theme::Classic, Theme::HighContrast, Theme::ColourBlindSafe];
+
+    /// This function wraps a piece of text in the ANSI escape codes needed to
+    /// draw it in the given colour with this theme's palette.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - \
+    ///    The theme whose palette is used
+    /// * `text` - \
+    ///    A string slice that should be coloured
+    /// * `colour` - \
+    ///    The colour to draw the text in
+    ///
+    /// # Returns
+    ///
+    /// * `String` - \
+    ///    The text surrounded by the escape codes
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Colour, Theme};
+    ///
+    /// let painted = Theme::Classic.paint("X", Colour::Red);
+    ///
+    /// assert_eq!(painted, "\x1b[31mX\x1b[0m");
+    /// ```
+    #[must_use]
+    pub fn paint(self, text: &str, colour: Colour) -> String {
+        format!("{code}{text}{RESET}", code = self.escape_code(colour))
+    }
+
+    // the start sequence for a colour in this palette
+    fn escape_code(self, colour: Colour) -> &'static str {
+        match self {
+            Theme::Classic => match colour {
+                Colour::Red => "\x1b[31m",
+                Colour::Yellow => "\x1b[33m",
+                Colour::Blue => "\x1b[34m",
+                Colour::Green => "\x1b[32m",
+                Colour::Magenta => "\x1b[35m",
+                Colour::Cyan => "\x1b[36m",
+            },
+            Theme::HighContrast => match colour {
+                Colour::Red => "\x1b[1;91m",
+                Colour::Yellow => "\x1b[1;93m",
+                Colour::Blue => "\x1b[1;94m",
+                Colour::Green => "\x1b[1;92m",
+                Colour::Magenta => "\x1b[1;95m",
+                Colour::Cyan => "\x1b[1;96m",
+            },
+            Theme::ColourBlindSafe => match colour {
+                Colour::Red => "\x1b[38;5;166m",     // vermillion
+                Colour::Yellow => "\x1b[38;5;220m",  // yellow
+                Colour::Blue => "\x1b[38;5;32m",     // blue
+                Colour::Green => "\x1b[38;5;36m",    // bluish green
+                Colour::Magenta => "\x1b[38;5;175m", // reddish purple
+                Colour::Cyan => "\x1b[38;5;117m",    // sky blue
+            },
+        }
+    }
+}
+
+// Just a println formatter for the themes
+impl fmt::Display for Theme {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Theme::Classic => write!(format_buffer, "Classic"),
+            Theme::HighContrast => write!(format_buffer, "High Contrast"),
+            Theme::ColourBlindSafe => write!(format_buffer, "Colour-Blind Safe"),
+        }
+    }
+}
+
+/// This function is used to decide if the output should be coloured at all. Colour
+/// is turned off when stdout is not a terminal (i.e. it is piped to a file) or
+/// when the `NO_COLOR` environment variable is set to anything.
+///
+/// # Returns
+///
+/// * `bool` - \
+///    `true` if escape codes should be written or `false` for plain text
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::theme::colour_enabled;
+///
+/// if colour_enabled() {
+///     println!("Your terminal is getting colours");
+/// }
+/// ```
+#[must_use]
+pub fn colour_enabled() -> bool {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|val| !val.is_empty());
+
+    !no_color && io::stdout().is_terminal()
+}
@@ -0,0 +1,923 @@
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use serde_json::{json, Value};
+use super::game::{Game, Mode, Rules};
+use super::player::{List, Player, ControlMode, Outcome, Record, Sprite, SHORT_NAME_LENGTH};
+use super::theme::Colour;
+use super::ai_engine::Difficulty;
+use super::session::Session;
+use super::bot::mode_name;
+use super::rating::{update_ratings, Rating};
+
+/// How many games past `best_of` a knockout match can go to break a tie before
+/// the higher seed goes through
+const MAX_TIEBREAK_GAMES: usize = 5;
+/// The points an entrant gets for winning a match (or having a bye)
+const WIN_POINTS: usize = 2;
+/// The points each entrant gets for a drawn match
+const DRAW_POINTS: usize = 1;
+
+/// An enumerator used to pick how the matches in a tournament are drawn up
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    /// `RoundRobin` - \
+    ///  Every entrant plays every other entrant once
+    RoundRobin,
+    /// `Swiss` - \
+    ///  Entrants on the same points are paired each round, without rematches,
+    ///  for as many rounds as a knockout would take
+    Swiss,
+    /// `SingleElimination` - \
+    ///  A seeded knockout bracket where the loser of every match is out
+    SingleElimination,
+}
+
+impl Format {
+    /// Every format a tournament can be played in, in the order they are shown
+    /// in the menus
+    pub const ALL: [Format; 3] = [Format::RoundRobin, Format::Swiss, Format::SingleElimination];
+}
+
+// Just a println formatter for the formats
+impl fmt::Display for Format {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::RoundRobin => write!(format_buffer, "Round-Robin"),
+            Format::Swiss => write!(format_buffer, "Swiss"),
+            Format::SingleElimination => write!(format_buffer, "Single-Elimination"),
+        }
+    }
+}
+
+/// A struct used to hold one match between two entrants, played as a series of
+/// games where the entrants take turns moving first.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Match {
+    /// `round` - \
+    ///  The round the match is in, counted from 1
+    pub round: usize,
+    /// `entrants` - \
+    ///  The entrants playing, as indexes into the tournament's entrants. There
+    ///  is no second entrant when the first has a bye
+    pub entrants: (usize, Option<usize>),
+    /// `games` - \
+    ///  The entrant who won each game so far, or `None` for a tie
+    pub games: Vec<Option<usize>>,
+    /// `best_of` - \
+    ///  How many games the match is played over
+    pub best_of: usize,
+    /// `must_win` - \
+    ///  Whether a tied match goes on until someone wins, as in a knockout
+    pub must_win: bool,
+}
+
+impl Match {
+    /// This function counts the games an entrant has won in the match.
+    ///
+    /// # Arguments
+    ///
+    /// * `entrant` - \
+    ///    The entrant's index
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    How many games they won
+    #[must_use]
+    pub fn wins(&self, entrant: usize) -> usize {
+        self.games.iter().filter(|winner| **winner == Some(entrant)).count()
+    }
+
+    /// This function checks if the match is finished. It is finished once an
+    /// entrant has won more than half of the games, or all of them have been
+    /// played. A knockout match that is still tied keeps going, one game at a
+    /// time, for up to five more games.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - \
+    ///    `true` once no more games need to be played
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::game_lib::tournament::Match;
+    ///
+    /// let mut best_of_three = Match { round: 1, entrants: (0, Some(1)), games: Vec::new(), best_of: 3, must_win: false };
+    ///
+    /// best_of_three.games = vec![Some(1), Some(1)];
+    ///
+    /// assert!(best_of_three.is_over());
+    /// assert_eq!(best_of_three.winner(), Some(1));
+    /// ```
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        let Some(second) = self.entrants.1 else {
+            return true;
+        };
+        let first_wins = self.wins(self.entrants.0);
+        let second_wins = self.wins(second);
+        let wins_needed = self.best_of / 2 + 1;
+
+        if first_wins >= wins_needed || second_wins >= wins_needed {
+            true
+        } else if self.games.len() < self.best_of {
+            false
+        } else {
+            !self.must_win || first_wins != second_wins || self.games.len() >= self.best_of + MAX_TIEBREAK_GAMES
+        }
+    }
+
+    /// This function gives the entrant who won the match.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - \
+    ///    The winner's index, which for a knockout match still tied after its
+    ///    tiebreak games is the higher seed
+    /// * `None` - \
+    ///    The match isn't over or it was a draw
+    #[must_use]
+    pub fn winner(&self) -> Option<usize> {
+        let (first, second) = self.entrants;
+        let Some(second) = second else {
+            return Some(first);
+        };
+
+        if !self.is_over() {
+            return None;
+        }
+
+        match self.wins(first).cmp(&self.wins(second)) {
+            Ordering::Greater => Some(first),
+            Ordering::Less => Some(second),
+            Ordering::Equal => self.must_win.then_some(first.min(second)),
+        }
+    }
+
+    /// This function gives the entrants in the order they move in the next
+    /// game. The first entrant moves first in the odd games and the second in
+    /// the even ones.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<(usize, usize)>` - \
+    ///    The entrant who moves first and then the other, or `None` for a bye
+    #[must_use]
+    pub fn next_turn_order(&self) -> Option<(usize, usize)> {
+        let (first, second) = self.entrants;
+        let second = second?;
+
+        if self.games.len().is_multiple_of(2) {
+            Some((first, second))
+        } else {
+            Some((second, first))
+        }
+    }
+}
+
+/// A struct used to hold how an entrant stands in a tournament.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Standing {
+    /// `entrant` - \
+    ///  The entrant's index
+    pub entrant: usize,
+    /// `played` - \
+    ///  The matches they have finished, including byes
+    pub played: usize,
+    /// `won` - \
+    ///  The matches they won, including byes
+    pub won: usize,
+    /// `drawn` - \
+    ///  The matches they drew
+    pub drawn: usize,
+    /// `lost` - \
+    ///  The matches they lost
+    pub lost: usize,
+    /// `points` - \
+    ///  Two for every match won and one for every match drawn
+    pub points: usize,
+    /// `buchholz` - \
+    ///  The first tie-break, the points of every opponent they have played
+    pub buchholz: usize,
+    /// `games_won` - \
+    ///  The games they won, used with `games_lost` for the next tie-break
+    pub games_won: usize,
+    /// `games_lost` - \
+    ///  The games they lost
+    pub games_lost: usize,
+    /// `reached` - \
+    ///  The last round they played in, which ranks a knockout
+    pub reached: usize,
+}
+
+impl Standing {
+    // the games won take away the games lost, the second tie-break
+    fn game_difference(&self) -> i64 {
+        i64::try_from(self.games_won).unwrap_or(i64::MAX) - i64::try_from(self.games_lost).unwrap_or(i64::MAX)
+    }
+}
+
+/// A struct used to run a tournament between any number of players. It draws
+/// up the matches a round at a time, keeps every game's result and ranks the
+/// entrants, and can be saved to a file and picked up again later.
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Player, Sprite};
+/// use tic_tac_toe_and_connect_4::game_lib::tournament::{Format, Tournament};
+///
+/// let entrants = ["AAA", "BBB", "CCC"]
+///     .iter()
+///     .zip(["X", "O", "H"])
+///     .map(|(name, sprite)| Player::human(name.to_string(), Sprite::new(sprite)))
+///     .collect();
+/// let mut tournament = Tournament::new(String::from("Office"), Format::RoundRobin, Game::tic_tac_toe(), entrants, 1);
+///
+/// // every game is won by whoever moves first
+/// while let Some(index) = tournament.next_match() {
+///     tournament.record_game(index, Some(1));
+/// }
+///
+/// assert!(tournament.is_over());
+/// assert_eq!(tournament.matches.len(), 6); // three matches and three byes
+///
+/// println!("{tournament}");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tournament {
+    /// `name` - \
+    ///  What the tournament is called
+    pub name: String,
+    /// `format` - \
+    ///  How the matches are drawn up
+    pub format: Format,
+    /// `game` - \
+    ///  The game every match is played on
+    pub game: Game,
+    /// `best_of` - \
+    ///  How many games each match is played over
+    pub best_of: usize,
+    /// `entrants` - \
+    ///  The players in the tournament, from the top seed down. Their wins count
+    ///  every game they win in it
+    pub entrants: Vec<Player>,
+    /// `matches` - \
+    ///  Every match drawn up so far, in order
+    pub matches: Vec<Match>,
+}
+
+impl Tournament {
+    /// This constructor is used to set up a tournament. No matches are drawn
+    /// up until `next_match` is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - \
+    ///    What the tournament is called
+    /// * `format` - \
+    ///    How the matches are drawn up
+    /// * `game` - \
+    ///    The game every match is played on
+    /// * `entrants` - \
+    ///    The players, from the top seed down. There need to be at least two for
+    ///    there to be any matches
+    /// * `best_of` - \
+    ///    How many games each match is played over, at least 1
+    ///
+    /// # Returns
+    ///
+    /// * `Tournament` - \
+    ///    A Tournament ready for its first round
+    #[must_use]
+    pub fn new(name: String, format: Format, mut game: Game, entrants: Vec<Player>, best_of: usize) -> Tournament {
+        game.reset();
+
+        Tournament {
+            name,
+            format,
+            game,
+            best_of: best_of.max(1),
+            entrants,
+            matches: Vec::new(),
+        }
+    }
+
+    /// This function gives how many rounds the tournament is played over.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    One round for each opponent in a round-robin, and enough rounds for a
+    ///    knockout to find a winner otherwise
+    #[must_use]
+    pub fn num_of_rounds(&self) -> usize {
+        let num_of_entrants = self.entrants.len();
+
+        if num_of_entrants < 2 {
+            return 0;
+        }
+
+        match self.format {
+            Format::RoundRobin => num_of_entrants - 1 + num_of_entrants % 2,
+            Format::Swiss | Format::SingleElimination => {
+                num_of_entrants.next_power_of_two().trailing_zeros() as usize
+            }
+        }
+    }
+
+    /// This function gives the round being played.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - \
+    ///    The round counted from 1, or 0 before the first is drawn up
+    #[must_use]
+    pub fn current_round(&self) -> usize {
+        self.matches.iter().map(|played| played.round).max().unwrap_or(0)
+    }
+
+    /// This function checks if every round has been played.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - \
+    ///    `true` once the tournament is finished
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.current_round() == self.num_of_rounds() && self.matches.iter().all(Match::is_over)
+    }
+
+    /// This function gives the next match that has games left to play, drawing
+    /// up the next round once every match in the current one is finished.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - \
+    ///    The match's index in `matches`
+    /// * `None` - \
+    ///    The tournament is over
+    pub fn next_match(&mut self) -> Option<usize> {
+        loop {
+            if let Some(index) = self.matches.iter().position(|played| !played.is_over()) {
+                return Some(index);
+            }
+
+            let round = self.current_round() + 1;
+
+            if round > self.num_of_rounds() {
+                return None;
+            }
+
+            let new_matches = match self.format {
+                Format::RoundRobin => self.round_robin_round(round),
+                Format::Swiss => self.swiss_round(round),
+                Format::SingleElimination => self.knockout_round(round),
+            };
+
+            self.matches.extend(new_matches);
+        }
+    }
+
+    /// This function sets up the next game of a match, with the entrant who
+    /// moves first as player 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `match_index` - \
+    ///    The match's index in `matches`
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Session)` - \
+    ///    A Session ready for the game's first move
+    /// * `None` - \
+    ///    There is no such match, it is a bye or it is already over
+    #[must_use]
+    pub fn session_for(&self, match_index: usize) -> Option<Session> {
+        let played = self.matches.get(match_index).filter(|played| !played.is_over())?;
+        let (first, second) = played.next_turn_order()?;
+        let player_list = List::new(vec![self.entrants[first].clone(), self.entrants[second].clone()]);
+
+        Some(Session::new(self.game.clone(), player_list))
+    }
+
+    /// This function records how a game in a match went, and adds it to both
+    /// entrants' records and ratings.
+    ///
+    /// # Arguments
+    ///
+    /// * `match_index` - \
+    ///    The match's index in `matches`
+    /// * `winner_number` - \
+    ///    The winner's player number in the game's Session (`1` for whoever
+    ///    moved first), or `None` for a tie
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - \
+    ///    `true` if the game was recorded, `false` if the match doesn't need it
+    pub fn record_game(&mut self, match_index: usize, winner_number: Option<usize>) -> bool {
+        let Some(played) = self.matches.get_mut(match_index).filter(|played| !played.is_over()) else {
+            return false;
+        };
+        let Some((first, second)) = played.next_turn_order() else {
+            return false;
+        };
+        let winner = match winner_number {
+            Some(1) => Some(first),
+            Some(_) => Some(second),
+            None => None,
+        };
+
+        played.games.push(winner);
+
+        let mode = self.game.current_mode;
+
+        for (entrant, opponent) in [(first, second), (second, first)] {
+            let outcome = match winner {
+                Some(winner) if winner == entrant => Outcome::Win,
+                Some(winner) if winner == opponent => Outcome::Loss,
+                _ => Outcome::Tie,
+            };
+
+            self.entrants[entrant].add_result(mode, outcome);
+        }
+
+        let mut pair = [self.entrants[first].clone(), self.entrants[second].clone()];
+
+        update_ratings(&mut pair, mode, winner.map(|winner| usize::from(winner == second)));
+        [self.entrants[first], self.entrants[second]] = pair;
+
+        true
+    }
+
+    /// This function ranks the entrants. They are ranked on points, then on
+    /// the points of the opponents they played (Buchholz), then on games won
+    /// take away games lost, then on games won and last on their seed. In a
+    /// knockout, going further comes first.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Standing>` - \
+    ///    Every entrant's standing, from first place down
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, Player, Sprite};
+    /// use tic_tac_toe_and_connect_4::game_lib::tournament::{Format, Tournament};
+    ///
+    /// let entrants = vec![
+    ///     Player::human(String::from("AAA"), Sprite::new("X")),
+    ///     Player::human(String::from("BBB"), Sprite::new("O")),
+    /// ];
+    /// let mut tournament = Tournament::new(String::from("Final"), Format::SingleElimination, Game::tic_tac_toe(), entrants, 3);
+    ///
+    /// // the second seed wins two games in a row
+    /// for winner_number in [Some(2), Some(1)] {
+    ///     let index = tournament.next_match().unwrap();
+    ///
+    ///     tournament.record_game(index, winner_number);
+    /// }
+    ///
+    /// assert!(tournament.is_over());
+    /// assert_eq!(tournament.standings()[0].entrant, 1);
+    /// ```
+    #[must_use]
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = (0..self.entrants.len())
+            .map(|entrant| Standing { entrant, ..Standing::default() })
+            .collect();
+        let finished: Vec<&Match> = self.matches.iter().filter(|played| played.is_over()).collect();
+
+        for played in &finished {
+            let (first, second) = played.entrants;
+            let winner = played.winner();
+
+            for (entrant, opponent) in [(Some(first), second), (second, Some(first))] {
+                let Some(entrant) = entrant else {
+                    continue;
+                };
+                let standing = &mut standings[entrant];
+
+                standing.played += 1;
+                standing.reached = standing.reached.max(played.round);
+
+                if let Some(opponent) = opponent {
+                    standing.games_won += played.wins(entrant);
+                    standing.games_lost += played.wins(opponent);
+                }
+
+                match winner {
+                    Some(winner) if winner == entrant => {
+                        standing.won += 1;
+                        standing.points += WIN_POINTS;
+                    }
+                    Some(_) => standing.lost += 1,
+                    None => {
+                        standing.drawn += 1;
+                        standing.points += DRAW_POINTS;
+                    }
+                }
+            }
+        }
+
+        let points: Vec<usize> = standings.iter().map(|standing| standing.points).collect();
+
+        for played in &finished {
+            if let (first, Some(second)) = played.entrants {
+                standings[first].buchholz += points[second];
+                standings[second].buchholz += points[first];
+            }
+        }
+
+        let is_knockout = self.format == Format::SingleElimination;
+
+        standings.sort_by_key(|standing| {
+            (
+                Reverse(if is_knockout { standing.reached } else { 0 }),
+                Reverse(standing.points),
+                Reverse(standing.buchholz),
+                Reverse(standing.game_difference()),
+                Reverse(standing.games_won),
+                standing.entrant,
+            )
+        });
+
+        standings
+    }
+
+    /// This function saves the tournament to a file as JSON, so it can be
+    /// picked up again with `load`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - \
+    ///    The file to write, which is replaced if it is already there
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the file could not be written.
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::{Game, Player, Sprite};
+    /// use tic_tac_toe_and_connect_4::game_lib::tournament::{Format, Tournament};
+    ///
+    /// let entrants = vec![
+    ///     Player::human(String::from("AAA"), Sprite::new("X")),
+    ///     Player::ai(Sprite::new("O")),
+    /// ];
+    /// let mut tournament = Tournament::new(String::from("Office"), Format::Swiss, Game::connect_four(), entrants, 3);
+    /// let path = std::env::temp_dir().join("office_tournament.json");
+    ///
+    /// let index = tournament.next_match().unwrap();
+    /// tournament.record_game(index, None);
+    /// tournament.save(&path).unwrap();
+    ///
+    /// assert_eq!(Tournament::load(&path).unwrap(), tournament);
+    /// ```
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(&self.to_json())?)
+    }
+
+    /// This function loads a tournament that was saved with `save`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - \
+    ///    The file to read
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Tournament)` - \
+    ///    The tournament as it was when it was saved
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the file could not be read or does not hold a
+    /// tournament.
+    pub fn load(path: &Path) -> io::Result<Tournament> {
+        let value: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        Tournament::from_json(&value)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "The file does not hold a tournament"))
+    }
+
+    // draws up a round-robin round with the circle method: the first seat stays
+    // put and everyone else moves round one seat each round
+    fn round_robin_round(&self, round: usize) -> Vec<Match> {
+        let mut seats: Vec<Option<usize>> = (0..self.entrants.len()).map(Some).collect();
+
+        if seats.len() % 2 == 1 {
+            seats.push(None);
+        }
+
+        let num_of_seats = seats.len();
+        let turned: Vec<Option<usize>> = (0..num_of_seats)
+            .map(|seat| match seat {
+                0 => seats[0],
+                _ => seats[1 + (seat - 1 + round - 1) % (num_of_seats - 1)],
+            })
+            .collect();
+
+        (0..num_of_seats / 2)
+            .filter_map(|seat| match (turned[seat], turned[num_of_seats - 1 - seat]) {
+                // who is listed first (and so moves first) swaps every round
+                (Some(first), Some(second)) if round.is_multiple_of(2) => Some(self.new_match(round, second, Some(first))),
+                (Some(first), Some(second)) => Some(self.new_match(round, first, Some(second))),
+                (Some(entrant), None) | (None, Some(entrant)) => Some(self.new_match(round, entrant, None)),
+                (None, None) => None,
+            })
+            .collect()
+    }
+
+    // draws up a swiss round, pairing the entrants from the top of the
+    // standings down with the next one they haven't played yet
+    fn swiss_round(&self, round: usize) -> Vec<Match> {
+        let mut unpaired: Vec<usize> = self.standings().iter().map(|standing| standing.entrant).collect();
+        let mut new_matches = Vec::new();
+
+        // the lowest ranked entrant who hasn't had a bye yet sits this round out
+        if unpaired.len() % 2 == 1 {
+            let bye = unpaired
+                .iter()
+                .rposition(|&entrant| !self.matches.iter().any(|played| played.entrants == (entrant, None)))
+                .unwrap_or(unpaired.len() - 1);
+
+            new_matches.push(self.new_match(round, unpaired.remove(bye), None));
+        }
+
+        while !unpaired.is_empty() {
+            let first = unpaired.remove(0);
+            let opponent = unpaired
+                .iter()
+                .position(|&second| !self.have_met(first, second))
+                .unwrap_or(0);
+            let second = unpaired.remove(opponent);
+
+            new_matches.push(self.new_match(round, first, Some(second)));
+        }
+
+        new_matches
+    }
+
+    // draws up a knockout round, seeding the bracket in the first round so the
+    // top seeds can only meet late on, then pairing the winners of each pair of
+    // matches from the round before
+    fn knockout_round(&self, round: usize) -> Vec<Match> {
+        let pairs: Vec<(usize, Option<usize>)> = if round == 1 {
+            let num_of_entrants = self.entrants.len();
+            let mut bracket = vec![1];
+
+            while bracket.len() < num_of_entrants.next_power_of_two() {
+                let size = bracket.len() * 2;
+
+                bracket = bracket.iter().flat_map(|&seed| [seed, size + 1 - seed]).collect();
+            }
+
+            bracket
+                .chunks(2)
+                .map(|pair| (pair[0] - 1, (pair[1] <= num_of_entrants).then_some(pair[1] - 1)))
+                .collect()
+        } else {
+            let winners: Vec<usize> = self
+                .matches
+                .iter()
+                .filter(|played| played.round == round - 1)
+                .filter_map(Match::winner)
+                .collect();
+
+            winners
+                .chunks(2)
+                .map(|pair| match pair {
+                    [first, second] => ((*first).min(*second), Some((*first).max(*second))),
+                    _ => (pair[0], None),
+                })
+                .collect()
+        };
+
+        pairs
+            .into_iter()
+            .map(|(first, second)| self.new_match(round, first, second))
+            .collect()
+    }
+
+    // checks if two entrants have already been drawn against each other
+    fn have_met(&self, first: usize, second: usize) -> bool {
+        self.matches.iter().any(|played| {
+            played.entrants == (first, Some(second)) || played.entrants == (second, Some(first))
+        })
+    }
+
+    // makes an empty match with the tournament's settings
+    fn new_match(&self, round: usize, first: usize, second: Option<usize>) -> Match {
+        Match {
+            round,
+            entrants: (first, second),
+            games: Vec::new(),
+            best_of: self.best_of,
+            must_win: self.format == Format::SingleElimination,
+        }
+    }
+
+    // turns the tournament into the JSON it is saved as
+    fn to_json(&self) -> Value {
+        let entrants: Vec<Value> = self.entrants.iter().map(player_to_json).collect();
+        let matches: Vec<Value> = self
+            .matches
+            .iter()
+            .map(|played| {
+                json!({
+                    "round": played.round,
+                    "entrants": [played.entrants.0, played.entrants.1],
+                    "games": played.games,
+                    "best_of": played.best_of,
+                    "must_win": played.must_win,
+                })
+            })
+            .collect();
+
+        json!({
+            "name": self.name,
+            "format": self.format.to_string(),
+            "game": {
+                "mode": mode_name(self.game.current_mode),
+                "width": self.game.width,
+                "height": self.game.height,
+                "win_length": self.game.win_length,
+                "rules": self.game.rules.to_string(),
+            },
+            "best_of": self.best_of,
+            "entrants": entrants,
+            "matches": matches,
+        })
+    }
+
+    // reads a tournament back from the JSON it was saved as
+    fn from_json(value: &Value) -> Option<Tournament> {
+        let number = |value: &Value| value.as_u64().and_then(|number| usize::try_from(number).ok());
+        let format = Format::ALL
+            .into_iter()
+            .find(|format| value["format"].as_str() == Some(format.to_string().as_str()))?;
+        let mode = Mode::PLAYABLE
+            .into_iter()
+            .find(|mode| value["game"]["mode"].as_str() == Some(mode_name(*mode)))?;
+        let mut game = Game::new(
+            mode.to_string(),
+            mode,
+            number(&value["game"]["width"])?,
+            number(&value["game"]["height"])?,
+            number(&value["game"]["win_length"])?,
+        );
+
+        // tournaments saved before there were rules are played by the standard ones
+        if let Some(rules) = value["game"]["rules"].as_str() {
+            game.rules = *Rules::for_mode(mode)
+                .iter()
+                .find(|known| known.to_string() == rules)?;
+        }
+        let entrants = value["entrants"]
+            .as_array()?
+            .iter()
+            .map(player_from_json)
+            .collect::<Option<Vec<Player>>>()?;
+        let matches = value["matches"]
+            .as_array()?
+            .iter()
+            .map(|played| {
+                Some(Match {
+                    round: number(&played["round"])?,
+                    entrants: (number(&played["entrants"][0])?, number(&played["entrants"][1])),
+                    games: played["games"].as_array()?.iter().map(number).collect(),
+                    best_of: number(&played["best_of"])?,
+                    must_win: played["must_win"].as_bool()?,
+                })
+            })
+            .collect::<Option<Vec<Match>>>()?;
+        let mut tournament = Tournament::new(
+            value["name"].as_str()?.to_string(),
+            format,
+            game,
+            entrants,
+            number(&value["best_of"])?,
+        );
+
+        tournament.matches = matches;
+
+        Some(tournament)
+    }
+}
+
+// shows the standings as a table
+impl fmt::Display for Tournament {
+    fn fmt(&self, format_buffer: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(format_buffer, "{name}: {format}, best of {best_of}, round {round} of {rounds}",
+            name = self.name, format = self.format, best_of = self.best_of,
+            round = self.current_round(), rounds = self.num_of_rounds())?;
+        writeln!(format_buffer, "{:>3}  {:<width$} {:>3} {:>3} {:>3} {:>3} {:>4} {:>4} {:>4}",
+            "#", "Name", "P", "W", "D", "L", "Pts", "Bch", "+/-", width = SHORT_NAME_LENGTH)?;
+
+        for (place, standing) in self.standings().iter().enumerate() {
+            writeln!(format_buffer, "{place:>3}  {name:<width$} {played:>3} {won:>3} {drawn:>3} {lost:>3} {points:>4} {buchholz:>4} {difference:>4}",
+                place = place + 1, name = self.entrants[standing.entrant].short_name(),
+                played = standing.played, won = standing.won, drawn = standing.drawn,
+                lost = standing.lost, points = standing.points, buchholz = standing.buchholz,
+                difference = standing.game_difference(), width = SHORT_NAME_LENGTH)?;
+        }
+
+        Ok(())
+    }
+}
+
+// the JSON a player is saved as
+fn player_to_json(player: &Player) -> Value {
+    let (control, setting) = match &player.control {
+        ControlMode::Human => ("human", Value::Null),
+        ControlMode::Ai(difficulty) => ("ai", json!(difficulty.to_string())),
+        ControlMode::Bot(command) => ("bot", json!(command)),
+    };
+
+    json!({
+        "name": player.name,
+        "sprite": player.sprite.0,
+        "colour": player.colour.to_string(),
+        "control": control,
+        "setting": setting,
+        "wins": player.wins,
+        "records": player.records.iter().map(|record| json!({
+            "mode": mode_name(record.mode),
+            "wins": record.wins,
+            "losses": record.losses,
+            "ties": record.ties,
+        })).collect::<Vec<Value>>(),
+        "ratings": player.ratings.iter().map(|rating| json!({
+            "mode": mode_name(rating.mode),
+            "history": rating.history,
+        })).collect::<Vec<Value>>(),
+    })
+}
+
+// reads a player back from the JSON they were saved as
+fn player_from_json(value: &Value) -> Option<Player> {
+    let colour = Colour::ALL
+        .into_iter()
+        .find(|colour| value["colour"].as_str() == Some(colour.to_string().as_str()))?;
+    let control = match value["control"].as_str()? {
+        "human" => ControlMode::Human,
+        "ai" => ControlMode::Ai(Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| value["setting"].as_str() == Some(difficulty.to_string().as_str()))?),
+        "bot" => ControlMode::Bot(value["setting"].as_str()?.to_string()),
+        _ => return None,
+    };
+    let mut player = Player::new(
+        control,
+        value["name"].as_str()?.to_string(),
+        Sprite(value["sprite"].as_str()?.to_string()),
+        colour,
+    );
+
+    player.wins = value["wins"].as_u64().and_then(|wins| usize::try_from(wins).ok())?;
+
+    let count = |value: &Value| value.as_u64().and_then(|count| usize::try_from(count).ok());
+
+    // saves from before records and ratings were kept have none
+    for record in value["records"].as_array().into_iter().flatten() {
+        let mode = Mode::PLAYABLE
+            .into_iter()
+            .find(|mode| record["mode"].as_str() == Some(mode_name(*mode)))?;
+
+        player.records.push(Record {
+            mode,
+            wins: count(&record["wins"])?,
+            losses: count(&record["losses"])?,
+            ties: count(&record["ties"])?,
+        });
+    }
+
+    for rating in value["ratings"].as_array().into_iter().flatten() {
+        let mode = Mode::PLAYABLE
+            .into_iter()
+            .find(|mode| rating["mode"].as_str() == Some(mode_name(*mode)))?;
+        let history = rating["history"].as_array()?.iter().map(Value::as_f64).collect::<Option<Vec<f64>>>()?;
+
+        player.ratings.push(Rating { mode, history });
+    }
+
+    Some(player)
+}
@@ -0,0 +1,416 @@
+use super::game::{Cell, Game, Mode, Move, MoveStatus, PieceKind, Rules, State};
+use super::game_engine::{board_has_line, player_to_move, repetitions, ttt_cnct_four_board_check};
+use super::game_engine::{ttt_cnct_four_board_move_chck, window_score, REPEATS_TO_DRAW};
+
+/// How many boards Notakto is played on when none are picked
+pub const NOTAKTO_BOARDS: usize = 3;
+/// The most boards Notakto can be played on, side by side
+pub const MAX_NOTAKTO_BOARDS: usize = 9;
+
+/// This function reads what a selection stands for under the Tic-Tac-Toe
+/// variants. In Wild the cells are numbered from 1 for an X and on from the
+/// last cell for an O, in Notakto every cell is an X, and in Sliding a slide is
+/// numbered on from there (see `Move::selection`).
+///
+/// # Arguments
+///
+/// * `rules` - \
+///    The rules being played by
+/// * `cells` - \
+///    How many cells are on the board
+/// * `selection` - \
+///    The move's number
+///
+/// # Returns
+///
+/// * `Move` - \
+///    What the move does
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Move, PieceKind, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::ttt_variants::read_selection;
+///
+/// assert_eq!(read_selection(Rules::Wild, 9, 14), Move::Mark(5, PieceKind::Nought));
+/// assert_eq!(read_selection(Rules::Notakto, 9, 5), Move::Mark(5, PieceKind::Cross));
+/// assert_eq!(read_selection(Rules::Sliding, 9, 20), Move::Slide(1, 2));
+/// assert_eq!(read_selection(Rules::Misere, 9, 5), Move::Place(5));
+/// ```
+#[must_use]
+pub fn read_selection(rules: Rules, cells: usize, selection: usize) -> Move {
+    match rules {
+        Rules::Wild if selection > cells => Move::Mark(selection - cells, PieceKind::Nought),
+        Rules::Wild | Rules::Notakto => Move::Mark(selection, PieceKind::Cross),
+        Rules::Sliding if selection > cells * 2 => Move::Slide((selection - 1) / cells - 1, (selection - 1) % cells + 1),
+        _ => Move::Place(selection),
+    }
+}
+
+/// This function reads a move typed under the Tic-Tac-Toe variants. Wild takes
+/// `x` or `o` and a cell, Notakto a board and a cell on it, and Sliding a cell
+/// to slide a mark from and one to slide it to. A single number is read as the
+/// move's selection, the way bots send them.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `input` - \
+///    What the player typed
+///
+/// # Returns
+///
+/// * `Some(usize)` - \
+///    The move's selection (see `read_selection`)
+/// * `None` - \
+///    The input isn't a move
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::ttt_variants::parse_move;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// game.rules = Rules::Wild;
+/// assert_eq!(parse_move(&game, "o 5"), Some(14));
+/// assert_eq!(parse_move(&game, "x5"), Some(5));
+///
+/// game.rules = Rules::Sliding;
+/// assert_eq!(parse_move(&game, "1 2"), Some(20));
+///
+/// // the second of three boards side by side
+/// game.rules = Rules::Notakto;
+/// game.width = 9;
+/// game.board = vec![None; 27];
+/// assert_eq!(parse_move(&game, "2 5"), Some(14));
+/// ```
+#[must_use]
+pub fn parse_move(game: &Game, input: &str) -> Option<usize> {
+    let input = input.trim().to_lowercase();
+    let cells = game.board.len();
+    let is_cell = |cell: &usize| (1..=cells).contains(cell);
+    let numbers: Option<Vec<usize>> = input.split_whitespace().map(|word| word.parse::<usize>().ok()).collect();
+
+    match (game.rules, numbers.as_deref()) {
+        (Rules::Wild, _) => {
+            let kind = match input.chars().next() {
+                Some('x') => PieceKind::Cross,
+                Some('o') => PieceKind::Nought,
+                _ => return input.parse::<usize>().ok().filter(|selection| (1..=cells * 2).contains(selection)),
+            };
+
+            input[1..]
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .filter(is_cell)
+                .map(|cell| Move::Mark(cell, kind).selection(game.width, cells))
+        }
+        (Rules::Sliding, Some([from, to])) if is_cell(from) && is_cell(to) => Some(Move::Slide(*from, *to).selection(game.width, cells)),
+        (Rules::Notakto, Some([board, cell])) => {
+            let size = game.height;
+
+            if *board == 0 || *board > game.width / size || *cell == 0 || *cell > size * size {
+                return None;
+            }
+
+            Some((cell - 1) / size * game.width + (board - 1) * size + (cell - 1) % size + 1)
+        }
+        (Rules::Sliding, Some([selection])) => Some(*selection).filter(|selection| (1..=cells * (cells + 2)).contains(selection)),
+        (_, Some([selection])) => Some(*selection).filter(is_cell),
+        _ => None,
+    }
+}
+
+/// This function is used to validate a move under the Tic-Tac-Toe variants.
+/// On top of the cell being free, Notakto's boards can't be played on once
+/// they have a line, and in Sliding a mark can only slide once all of the
+/// player's marks are placed, and only to a free cell next to it.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The move's selection (see `read_selection`)
+///
+/// # Returns
+///
+/// * `MoveStatus::Valid` - \
+///    The move can be played
+/// * `MoveStatus::Invalid(error_str)` - \
+///    The move can't be played and the error explains why
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::ttt_variants::check_move;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// game.rules = Rules::Sliding;
+///
+/// assert_eq!(check_move(&game, 20), MoveStatus::Invalid("Place all your marks before sliding them"));
+///
+/// for (current_player, selection) in [(1, 1), (2, 5), (1, 9), (2, 3), (1, 7), (2, 8)] {
+///     play_move(&mut game, current_player, selection);
+/// }
+///
+/// // player 1 slides from the top left to the top middle
+/// assert_eq!(check_move(&game, 20), MoveStatus::Valid);
+/// assert_eq!(check_move(&game, 24), MoveStatus::Invalid("A mark can only slide to a free cell next to it"));
+/// ```
+#[must_use]
+pub fn check_move(game: &Game, selection: usize) -> MoveStatus<'static> {
+    let cells = game.board.len();
+    let cell_check = |cell: usize| ttt_cnct_four_board_move_chck(&game.board, game.width, Mode::TicTacToe, cell);
+
+    match (game.rules, read_selection(game.rules, cells, selection)) {
+        (Rules::Notakto, Move::Mark(cell, _)) if cell_check(cell) == MoveStatus::Valid && !is_live(game, board_of(game, cell - 1)) => {
+            MoveStatus::Invalid("That board already has a line on it")
+        }
+        (Rules::Sliding, played) => check_slide(game, played),
+        (_, Move::Mark(cell, _) | Move::Place(cell)) => cell_check(cell),
+        _ => MoveStatus::Invalid("Selected cell was out of range"),
+    }
+}
+
+/// This function gives every move the next player is allowed to make under
+/// the Tic-Tac-Toe variants.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The moves' selections (see `read_selection`)
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::ttt_variants::valid_moves;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// // an X or an O in every cell
+/// game.rules = Rules::Wild;
+///
+/// assert_eq!(valid_moves(&game).len(), 18);
+/// ```
+#[must_use]
+pub fn valid_moves(game: &Game) -> Vec<usize> {
+    let cells = game.board.len();
+
+    match game.rules {
+        Rules::Wild => (1..=cells * 2).filter(|selection| game.board[(selection - 1) % cells].is_none()).collect(),
+        Rules::Sliding if marks_placed(game) >= game.win_length => {
+            let player = player_to_move(game);
+            let mut moves: Vec<usize> = (1..=cells)
+                .filter(|from| game.board[from - 1].is_some_and(|piece| piece.owner == player))
+                .flat_map(|from| {
+                    (1..=cells)
+                        .filter(move |to| game.board[to - 1].is_none() && is_next_to(game.width, from, *to))
+                        .map(move |to| Move::Slide(from, to).selection(game.width, cells))
+                })
+                .collect();
+
+            moves.sort_unstable();
+            moves
+        }
+        _ => (1..=cells).filter(|selection| check_move(game, *selection) == MoveStatus::Valid).collect(),
+    }
+}
+
+/// This function is used to check how a game stands after a player's move
+/// under the Tic-Tac-Toe variants. In Misere completing a line loses and in
+/// Wild a line of Xs or Os wins whoever made it. In Notakto the game ends once
+/// every board has a line, and whoever made the last one loses. In Sliding a
+/// line wins, the same position coming up `REPEATS_TO_DRAW` times is a draw,
+/// and a player who can't slide any of their marks loses.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player who just moved, counted from 1
+///
+/// # Returns
+///
+/// * `State::Won` - \
+///    The player has won the game
+/// * `State::Lost` - \
+///    The player has lost the game, so their opponent won
+/// * `State::NotOver` - \
+///    The game goes on
+/// * `State::Tie` - \
+///    Nobody can win any more
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Rules, State};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::ttt_variants::status;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// game.rules = Rules::Misere;
+///
+/// for (current_player, selection) in [(1, 1), (2, 4), (1, 2), (2, 5), (1, 3)] {
+///     play_move(&mut game, current_player, selection);
+/// }
+///
+/// // player 1 completed the top row
+/// assert_eq!(status(&game, 1), State::Lost);
+/// ```
+#[must_use]
+pub fn status(game: &Game, player: usize) -> State {
+    let is_full = game.board.iter().all(Option::is_some);
+
+    match game.rules {
+        Rules::Wild if [PieceKind::Cross, PieceKind::Nought].iter().any(|kind| {
+            board_has_line(&game.board, game.width, game.win_length, |piece| piece.kind == *kind)
+        }) => State::Won,
+        Rules::Notakto if (0..boards(game)).any(|board| is_live(game, board)) => State::NotOver,
+        // the last board filled up without a line on it
+        Rules::Notakto if game.moves.last().is_some_and(|selection| !has_line(game, board_of(game, selection - 1))) => State::Tie,
+        Rules::Notakto => State::Lost,
+        Rules::Sliding if ttt_cnct_four_board_check(&game.board, game.width, game.win_length, player) => State::Won,
+        Rules::Sliding if repetitions(game) >= REPEATS_TO_DRAW => State::Tie,
+        Rules::Sliding if valid_moves(game).is_empty() => State::Won,
+        Rules::Sliding => State::NotOver,
+        Rules::Misere if ttt_cnct_four_board_check(&game.board, game.width, game.win_length, player) => State::Lost,
+        _ if is_full => State::Tie,
+        _ => State::NotOver,
+    }
+}
+
+/// This function is used to give a game a score for how well a player is
+/// doing under the Tic-Tac-Toe variants. Misere scores lines the other way
+/// around from `window_score`, since making them loses. In Wild and Notakto
+/// the marks don't belong to anyone, so only a finished game can be scored.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is doing better, negative when the
+///    opponent is
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece, Rules};
+/// use tic_tac_toe_and_connect_4::game_lib::ttt_variants::score;
+///
+/// let mut game = Game::tic_tac_toe();
+///
+/// game.rules = Rules::Misere;
+/// game.board[4] = Some(Piece::stone(1));
+///
+/// assert!(score(&game, 1) < 0);
+/// ```
+#[must_use]
+pub fn score(game: &Game, player: usize) -> i32 {
+    match game.rules {
+        Rules::Misere => -window_score(&game.board, game.width, game.win_length, player),
+        Rules::Sliding => window_score(&game.board, game.width, game.win_length, player),
+        _ => 0,
+    }
+}
+
+// checks a Sliding move: marks are placed until the player has as many as it
+// takes to win, then slid to a free cell next to them
+fn check_slide(game: &Game, played: Move) -> MoveStatus<'static> {
+    let cells = game.board.len();
+    let mover = player_to_move(game);
+    let all_placed = marks_placed(game) >= game.win_length;
+
+    match played {
+        Move::Place(cell) if !all_placed || cell == 0 || cell > cells => {
+            ttt_cnct_four_board_move_chck(&game.board, game.width, Mode::TicTacToe, cell)
+        }
+        Move::Place(_) => MoveStatus::Invalid("Your marks are all placed, slide one with `from to`"),
+        Move::Slide(..) if !all_placed => MoveStatus::Invalid("Place all your marks before sliding them"),
+        Move::Slide(from, to) if from == 0 || from > cells || to > cells => MoveStatus::Invalid("Selected cell was out of range"),
+        Move::Slide(from, _) if game.board[from - 1].is_none_or(|piece| piece.owner != mover) => {
+            MoveStatus::Invalid("You can only slide your own marks")
+        }
+        Move::Slide(from, to) if game.board[to - 1].is_some() || !is_next_to(game.width, from, to) => {
+            MoveStatus::Invalid("A mark can only slide to a free cell next to it")
+        }
+        _ => MoveStatus::Valid,
+    }
+}
+
+// counts the marks the player to move has on the board
+fn marks_placed(game: &Game) -> usize {
+    let player = player_to_move(game);
+
+    game.board.iter().flatten().filter(|piece| piece.owner == player).count()
+}
+
+// checks two cells, counted from 1, touch along a row, column or diagonal
+fn is_next_to(width: usize, from: usize, to: usize) -> bool {
+    let (from_row, from_column) = ((from - 1) / width, (from - 1) % width);
+    let (to_row, to_column) = ((to - 1) / width, (to - 1) % width);
+
+    from != to && from_row.abs_diff(to_row) <= 1 && from_column.abs_diff(to_column) <= 1
+}
+
+// Notakto's boards are `height` by `height` squares side by side
+fn boards(game: &Game) -> usize {
+    game.width / game.height
+}
+
+// gives which of Notakto's boards a cell is on, counted from 0
+fn board_of(game: &Game, index: usize) -> usize {
+    index % game.width / game.height
+}
+
+// copies one of Notakto's boards out of the row of them
+fn small_board(game: &Game, board: usize) -> Vec<Cell> {
+    let size = game.height;
+
+    (0..size * size)
+        .map(|cell| game.board[cell / size * game.width + board * size + cell % size])
+        .collect()
+}
+
+// checks one of Notakto's boards has a line of Xs on it
+fn has_line(game: &Game, board: usize) -> bool {
+    board_has_line(&small_board(game, board), game.height, game.win_length, |_| true)
+}
+
+// checks one of Notakto's boards can still be played on
+fn is_live(game: &Game, board: usize) -> bool {
+    small_board(game, board).iter().any(Option::is_none) && !has_line(game, board)
+}
@@ -0,0 +1,383 @@
+use super::game::{Cell, Game, MoveStatus, Piece, State};
+use super::game_engine::{ttt_cnct_four_board_check, ttt_cnct_four_board_move_chck, window_score};
+
+/// How many cells are on each side of a small board, and how many small boards
+/// are on each side of the big one
+pub const BLOCK: usize = 3;
+/// How much more a won small board is worth than a piece when the `Ai` scores
+/// the game
+const SMALL_BOARD_WEIGHT: i32 = 20;
+
+/// This function gives the small board a cell is in. The cells of the 9 by 9
+/// board are counted from 1 row by row from the top left, the same as every
+/// other grid, and so are the small boards on the 3 by 3 big board.
+///
+/// # Arguments
+///
+/// * `selection` - \
+///    The cell, counted from 1
+///
+/// # Returns
+///
+/// * `usize` - \
+///    The small board the cell is in, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::ultimate::board_of;
+///
+/// assert_eq!(board_of(1), 1);
+/// assert_eq!(board_of(13), 2);
+/// assert_eq!(board_of(81), 9);
+/// ```
+#[must_use]
+pub fn board_of(selection: usize) -> usize {
+    let index = selection - 1;
+    let side = BLOCK * BLOCK;
+
+    index / side / BLOCK * BLOCK + index % side / BLOCK + 1
+}
+
+/// This function gives the cell a move sends the next player to, which is the
+/// small board they have to play in. It is where the cell sits inside its own
+/// small board, so the top right cell of any small board sends them to the top
+/// right small board.
+///
+/// # Arguments
+///
+/// * `selection` - \
+///    The cell that was played, counted from 1
+///
+/// # Returns
+///
+/// * `usize` - \
+///    The small board the next player is sent to, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::game_lib::ultimate::sends_to;
+///
+/// assert_eq!(sends_to(1), 1);
+/// assert_eq!(sends_to(3), 3);
+/// assert_eq!(sends_to(41), 5);
+/// ```
+#[must_use]
+pub fn sends_to(selection: usize) -> usize {
+    let index = selection - 1;
+    let side = BLOCK * BLOCK;
+
+    index / side % BLOCK * BLOCK + index % BLOCK + 1
+}
+
+/// This function splits the board into its small boards, so each one can be
+/// checked like a game of Tic-Tac-Toe.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The 9 by 9 board
+///
+/// # Returns
+///
+/// * `Vec<Vec<Cell>>` - \
+///    The small boards row by row from the top left, with their cells row by
+///    row from the top left
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece};
+/// use tic_tac_toe_and_connect_4::game_lib::ultimate::small_boards;
+///
+/// let mut game = Game::ultimate_tic_tac_toe();
+///
+/// // the middle cell of the middle small board
+/// game.board[40] = Some(Piece::stone(1));
+///
+/// assert_eq!(small_boards(&game.board)[4][4], Some(Piece::stone(1)));
+/// ```
+#[must_use]
+pub fn small_boards(board: &[Cell]) -> Vec<Vec<Cell>> {
+    let side = BLOCK * BLOCK;
+    let mut small_boards = vec![Vec::new(); side];
+
+    for (index, cell) in board.iter().enumerate() {
+        small_boards[board_of(index + 1) - 1].push(*cell);
+    }
+
+    small_boards
+}
+
+/// This function gives the big board, where each cell holds a piece for the
+/// player who won that small board.
+///
+/// # Arguments
+///
+/// * `board` - \
+///    The 9 by 9 board
+///
+/// # Returns
+///
+/// * `Vec<Cell>` - \
+///    The 3 by 3 big board, row by row from the top left
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, Piece};
+/// use tic_tac_toe_and_connect_4::game_lib::ultimate::meta_board;
+///
+/// let mut game = Game::ultimate_tic_tac_toe();
+///
+/// // player 2 takes the top row of the top left small board
+/// for index in [0, 1, 2] {
+///     game.board[index] = Some(Piece::stone(2));
+/// }
+///
+/// assert_eq!(meta_board(&game.board)[0], Some(Piece::stone(2)));
+/// assert_eq!(meta_board(&game.board)[1], None);
+/// ```
+#[must_use]
+pub fn meta_board(board: &[Cell]) -> Vec<Cell> {
+    small_boards(board)
+        .iter()
+        .map(|small_board| small_board_winner(small_board).map(Piece::stone))
+        .collect()
+}
+
+/// This function gives the small boards the next player is allowed to play in.
+/// That is the one the last move sent them to, unless it has been won or is
+/// full, in which case they can play in any small board that is still open.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The small boards that can be played in, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::ultimate::active_boards;
+///
+/// let mut game = Game::ultimate_tic_tac_toe();
+///
+/// assert_eq!(active_boards(&game).len(), 9);
+///
+/// // the top right cell of the top left small board
+/// play_move(&mut game, 1, 3);
+///
+/// assert_eq!(active_boards(&game), vec![3]);
+/// ```
+#[must_use]
+pub fn active_boards(game: &Game) -> Vec<usize> {
+    let small_boards = small_boards(&game.board);
+    let sent_to = game
+        .moves
+        .last()
+        .map(|last_move| sends_to(*last_move))
+        .filter(|sent_to| is_open(&small_boards[sent_to - 1]));
+
+    match sent_to {
+        Some(sent_to) => vec![sent_to],
+        None => (1..=small_boards.len()).filter(|number| is_open(&small_boards[number - 1])).collect(),
+    }
+}
+
+/// This function is used to validate a move in Ultimate Tic-Tac-Toe. On top
+/// of the cell being free, it has to be in one of the small boards the last
+/// move sent the player to.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `selection` - \
+///    The cell the player picked, counted from 1
+///
+/// # Returns
+///
+/// * `MoveStatus::Valid` - \
+///    The move can be played
+/// * `MoveStatus::Invalid(error_str)` - \
+///    The move can't be played and the error explains why
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::{Game, MoveStatus};
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::ultimate::check_move;
+///
+/// let mut game = Game::ultimate_tic_tac_toe();
+///
+/// play_move(&mut game, 1, 3);
+///
+/// // player 2 was sent to the top right small board
+/// assert_eq!(check_move(&game, 7), MoveStatus::Valid);
+/// assert_eq!(check_move(&game, 4), MoveStatus::Invalid("You have to play in the small board you were sent to"));
+/// ```
+#[must_use]
+pub fn check_move(game: &Game, selection: usize) -> MoveStatus<'static> {
+    let status = ttt_cnct_four_board_move_chck(&game.board, game.width, game.current_mode, selection);
+
+    if status != MoveStatus::Valid {
+        return status;
+    }
+
+    if !active_boards(game).contains(&board_of(selection)) {
+        return MoveStatus::Invalid("You have to play in the small board you were sent to");
+    }
+
+    MoveStatus::Valid
+}
+
+/// This function gives every move the next player is allowed to make, which is
+/// every free cell in the small boards they can play in.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+///
+/// # Returns
+///
+/// * `Vec<usize>` - \
+///    The cells that can be picked, counted from 1
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::ultimate::valid_moves;
+///
+/// let mut game = Game::ultimate_tic_tac_toe();
+///
+/// assert_eq!(valid_moves(&game).len(), 81);
+///
+/// play_move(&mut game, 1, 41);
+///
+/// // sent back to the middle small board, where one cell is taken
+/// assert_eq!(valid_moves(&game), vec![31, 32, 33, 40, 42, 49, 50, 51]);
+/// ```
+#[must_use]
+pub fn valid_moves(game: &Game) -> Vec<usize> {
+    let active = active_boards(game);
+
+    (1..=game.board.len())
+        .filter(|selection| game.board[selection - 1].is_none() && active.contains(&board_of(*selection)))
+        .collect()
+}
+
+/// This function is used to check how an Ultimate Tic-Tac-Toe game stands
+/// after a player's move. They win by taking three small boards in a line,
+/// and it is a tie once nobody can move without anyone having done that.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player who just moved, counted from 1
+///
+/// # Returns
+///
+/// * `State::Won` - \
+///    The player has won the game
+/// * `State::NotOver` - \
+///    The game goes on
+/// * `State::Tie` - \
+///    Every small board is won or full and nobody won the big one
+#[must_use]
+pub fn status(game: &Game, player: usize) -> State {
+    if ttt_cnct_four_board_check(&meta_board(&game.board), BLOCK, BLOCK, player) {
+        State::Won
+    } else if valid_moves(game).is_empty() {
+        State::Tie
+    } else {
+        State::NotOver
+    }
+}
+
+/// This function is used to give an Ultimate Tic-Tac-Toe game a score for how
+/// close each player is to winning, the same way `window_score` scores a grid.
+/// The big board counts the most, and every small board that is still open
+/// adds how close each player is to winning it.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    The game being played
+/// * `player` - \
+///    The seat of the player the score is for, counted from 1
+///
+/// # Returns
+///
+/// * `i32` - \
+///    A positive score when `player` is closer to winning, negative when the
+///    opponents are
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::game_lib::game_engine::play_move;
+/// use tic_tac_toe_and_connect_4::game_lib::ultimate::score;
+///
+/// let mut game = Game::ultimate_tic_tac_toe();
+///
+/// play_move(&mut game, 1, 41);
+///
+/// assert!(score(&game, 1) > 0);
+/// assert!(score(&game, 2) < 0);
+/// ```
+#[must_use]
+pub fn score(game: &Game, player: usize) -> i32 {
+    let big_board = window_score(&meta_board(&game.board), BLOCK, BLOCK, player) * SMALL_BOARD_WEIGHT;
+
+    small_boards(&game.board)
+        .iter()
+        .filter(|small_board| is_open(small_board))
+        .map(|small_board| window_score(small_board, BLOCK, BLOCK, player))
+        .sum::<i32>()
+        + big_board
+}
+
+// gives the seat of the player who has three in a line on a small board
+fn small_board_winner(small_board: &[Cell]) -> Option<usize> {
+    small_board
+        .iter()
+        .flatten()
+        .map(|piece| piece.owner)
+        .find(|owner| ttt_cnct_four_board_check(small_board, BLOCK, BLOCK, *owner))
+}
+
+// checks that nobody has won a small board and it still has a free cell
+fn is_open(small_board: &[Cell]) -> bool {
+    small_board.iter().any(Option::is_none) && small_board_winner(small_board).is_none()
+}
@@ -0,0 +1,44 @@
+//! # Tic-Tac-Toe and Connect-4
+//!
+//! A simple game engine to make grid based games like Tic-Tac-Toe or Connect-4. 
+//! The engine lives in `game_lib` and the terminal interface that the 
+//! `tic-tac-toe-and-connect-4` program uses lives in `ui_lib`. The types most
+//! programs need are re-exported here.
+//!
+//! # Examples
+//!
+//! Basic Usage:
+//!
+//! ```
+//! use tic_tac_toe_and_connect_4::{Game, MoveStatus, State};
+//! use tic_tac_toe_and_connect_4::game_lib::game_engine::{change_status, edit_board, ttt_cnct_four_board_move_chck};
+//!
+//! let mut game = Game::tic_tac_toe();
+//!
+//! // the players are numbered by their seats, so the board doesn't depend on
+//! // how their pieces are drawn
+//! for (current_player, selection) in [(1, 1), (2, 4), (1, 2), (2, 5), (1, 3)] {
+//!     let status = ttt_cnct_four_board_move_chck(&game.board, game.width, game.current_mode, selection);
+//!
+//!     assert_eq!(status, MoveStatus::Valid);
+//!
+//!     game.board = edit_board(game.board, game.width, game.current_mode, current_player, selection);
+//!     game.update_turns();
+//! }
+//!
+//! assert_eq!(change_status(&game, 1), State::Won);
+//! ```
+
+#![warn(clippy::all, clippy::pedantic)]
+#![allow(clippy::doc_overindented_list_items)]
+
+/// The game engine: the Game, Player, the rules and the `Ai`
+pub mod game_lib;
+/// The terminal interface that is built on top of the game engine
+pub mod ui_lib;
+
+pub use game_lib::game::{Cell, Game, Mode, Move, MoveStatus, Piece, PieceKind, Rules, State};
+pub use game_lib::player::{ControlMode, List, Outcome, Player, Record, Sprite};
+pub use game_lib::ai_engine::{think, Difficulty};
+pub use game_lib::theme::{Colour, Theme};
+pub use game_lib::session::Session;
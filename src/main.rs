@@ -1,141 +1,87 @@
 #![warn(clippy::all, clippy::pedantic)]
-
-mod game_lib;
-mod ui_lib;
-
-use std::mem;
-use ui_lib::cmdln_interface::{MAIN_MENU, WHICH_PLAYER, GAME_EDITOR, PLAY_AGAIN};
-use ui_lib::cmdln_interface::{get_int_input, get_str_input};
-use game_lib::player::{List, Player, ControlMode, Sprite};
-use game_lib::ai_engine::simple_think;
-use game_lib::player_editor::player_editor;
-use game_lib::game::{Game, State, MoveStatus};
-use game_lib::game_editor::game_editor;
-use game_lib::game_engine::{change_status, edit_board, ttt_cnct_four_board_move_chck};
+#![allow(clippy::doc_overindented_list_items)]
+
+use std::{env, process};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use tic_tac_toe_and_connect_4::game_lib::tournament::Tournament;
+use tic_tac_toe_and_connect_4::ui_lib::cmdln_app::{main_menu, play_match};
+use tic_tac_toe_and_connect_4::ui_lib::cmdln_args::{parse_args, Command, USAGE};
+use tic_tac_toe_and_connect_4::ui_lib::cmdln_engine::run_engine;
+use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+use tic_tac_toe_and_connect_4::ui_lib::cmdln_tournament::run_tournament;
+use tic_tac_toe_and_connect_4::ui_lib::http_api::{serve_http, GameStore};
+use tic_tac_toe_and_connect_4::ui_lib::tcp_client::connect;
+use tic_tac_toe_and_connect_4::ui_lib::tcp_server::serve;
 
 const SOFTWARE_NAME: &str = env!["CARGO_PKG_NAME"];
 const SOFTWARE_VER: &str = env!["CARGO_PKG_VERSION"];
 
 fn main() {
-    println!("Software Name:    {SOFTWARE_NAME}");
-    println!("Software Version: v{SOFTWARE_VER}");
-    println!();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut console = Console::stdio();
+    let command = parse_args(&args);
+
+    // an engine's output is read by another program, so it only gets the protocol
+    if command != Ok(Command::Engine) {
+        console.println(format!("Software Name:    {SOFTWARE_NAME}"));
+        console.println(format!("Software Version: v{SOFTWARE_VER}"));
+        console.println("");
+    }
 
-    let mut user_input = get_int_input(MAIN_MENU);
-    let mut player_list = List {
-        player_1: Player::human(String::from("P1"), Sprite::new("X")),
-        player_2: Player::ai(Sprite::new("H")),
-    };
-    let mut game = Game::tic_tac_toe();
- 
-    while user_input.is_some() {
-        match user_input {
-            Some(1) => {
-                player_list = player_editor(WHICH_PLAYER, player_list.clone());              
+    match command {
+        Ok(Command::Menu) => main_menu(&mut console),
+        Ok(Command::Help) => console.println(USAGE),
+        Ok(Command::Engine) => run_engine(&mut console),
+        Ok(Command::Play(settings)) => play_match(&mut console, *settings),
+        Ok(Command::Serve(address, settings)) => {
+            let hosted = TcpListener::bind(&address)
+                .and_then(|listener| serve(&mut console, &listener, *settings));
 
-                user_input = get_int_input(MAIN_MENU);
-            }
-            Some(2) => {
-                game = game_editor(GAME_EDITOR, game.clone());
+            if let Err(error) = hosted {
+                eprintln!("Error: Could not host on {address}: {error}");
 
-                user_input = get_int_input(MAIN_MENU);
+                process::exit(1);
             }
-            Some(3) => {
-                let mut play_again = String::from("Y");
-
-                while matches!(play_again.as_str(), "Y") {
-                    let mut current_player = &mut player_list.player_1;
-                    let mut other_player = &mut player_list.player_2;
-
-                    eprintln!("Welcome to {name}", name = game.name);
-
-                    while matches!(game.current_state, State::NotOver) {
-                        eprintln!("{game}");
-                        eprintln!("Current Player: {current_player}", 
-                            current_player = current_player.name);
-
-                        let player_move = match &current_player.control {
-                            ControlMode::Human => {
-                                    if let Some(val) = get_int_input("Make a move (`q` to end roud): ") {
-                                        val
-                                    } else {
-                                        eprintln!("Exiting game...");
-
-                                        break;
-                                    }
-                                }
-                            ControlMode::Ai => simple_think(&game.board, game.size, game.current_mode),
-                        };
-                    
-                        match ttt_cnct_four_board_move_chck(
-                            &game.board, 
-                            game.size, 
-                            game.current_mode, 
-                            &current_player.sprite, 
-                            &other_player.sprite, 
-                            player_move,
-                        ) {
-                            MoveStatus::Valid => {
-                                game.board = edit_board(
-                                    game.board, 
-                                    game.size, 
-                                    game.current_mode, 
-                                    &current_player.sprite, 
-                                    &other_player.sprite, 
-                                    player_move);
-
-                                game.update_turns();
-                            }
-                            MoveStatus::Invalid(error) => {
-                                eprintln!("{error}");
-
-                                continue;
-                            }
-                        }
-
-                        game.current_state = match change_status(&game, &current_player.sprite) {
-                            State::Won => {
-                                println!("Congrats {current_player} won!", 
-                                    current_player = current_player.name);
-
-                                current_player.update_wins(1);
-
-                                State::Won
-                            }
-                            State::Tie => {
-                                println!("It was a tie");
-
-                                State::Tie
-                            }
-                            State::NotOver => {
-                                mem::swap(&mut current_player, &mut other_player);
-                                
-                                State::NotOver
-                            }
-                        };
-                    } 
+        }
+        Ok(Command::Connect(address)) => {
+            let joined = TcpStream::connect(&address)
+                .and_then(|stream| connect(&mut console, stream));
 
-                    println!("{game}");
-                    println!("{player_list}");
+            if let Err(error) = joined {
+                eprintln!("Error: Could not play on {address}: {error}");
 
-                    play_again = get_str_input(PLAY_AGAIN, 1);
+                process::exit(1);
+            }
+        }
+        Ok(Command::Http(address)) => {
+            let served = TcpListener::bind(&address)
+                .and_then(|listener| serve_http(&mut console, &listener, GameStore::new(None)));
 
-                    while !(matches!(play_again.as_str(), "Y" | "N")) {
-                        eprintln!("Please enter a `Y` or `N`: Try again.");
+            if let Err(error) = served {
+                eprintln!("Error: Could not serve on {address}: {error}");
 
-                        play_again = get_str_input(PLAY_AGAIN, 1);
-                    }
+                process::exit(1);
+            }
+        }
+        Ok(Command::Tournament(file, tournament)) => {
+            let path = Path::new(&file);
+            let played = if path.exists() { Tournament::load(path) } else { Ok(*tournament) }
+                .and_then(|tournament| run_tournament(&mut console, path, tournament, &mut StdRng::from_entropy()));
 
-                    game.reset();
-                }
+            if let Err(error) = played {
+                eprintln!("Error: Could not play the tournament in {file}: {error}");
 
-                user_input = get_int_input(MAIN_MENU);
+                process::exit(1);
             }
-            _ => {
-                eprintln!("Invalid selection. Try again.");
+        }
+        Err(error) => {
+            eprintln!("Error: {error}");
+            eprintln!("{USAGE}");
 
-                user_input = get_int_input(MAIN_MENU);
-            }
+            process::exit(2);
         }
     }
-}
\ No newline at end of file
+}
@@ -5,4 +5,24 @@
 //! 
 
 /// A module that holds all the input and output for this program
-pub mod cmdln_interface;
\ No newline at end of file
+pub mod cmdln_interface;
+/// A module that turns the command line arguments into a game that is ready
+/// to play
+pub mod cmdln_args;
+/// A module that holds the main menu and game loop of the program
+pub mod cmdln_app;
+/// A module that hosts a match over TCP so players can join from other machines
+pub mod tcp_server;
+/// A module that joins a match hosted by the TCP server and plays it from the
+/// terminal
+pub mod tcp_client;
+/// A module that serves a JSON API over HTTP for creating and playing many games
+/// at once
+pub mod http_api;
+/// A module that lets the program be a bot for another program, answering the
+/// Grid Engine Protocol over stdin and stdout
+pub mod cmdln_engine;
+/// A module that plays a tournament from the terminal, saving it after every game
+pub mod cmdln_tournament;
+
+use super::game_lib::{game, player, ai_engine, theme, game_editor, player_editor, session, game_engine, tournament, rating, series, qubic, gomoku, reversi, order_chaos, hex};
@@ -0,0 +1,527 @@
+use std::io::{BufRead, Write};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use super::cmdln_interface::{MAIN_MENU, WHICH_PLAYER, GAME_EDITOR, PROMPT_FAIR_AI, ESCAPE_CHAR};
+use super::cmdln_interface::{PROMPT_SERIES_LENGTH, FIRST_MOVE_SEL, PROMPT_FIRST_PLAYER};
+use super::cmdln_interface::{PROMPT_MOVE, PROMPT_GRID_MOVE, PROMPT_POPOUT_MOVE, PROMPT_QUBIC_MOVE};
+use super::cmdln_interface::{PROMPT_WILD_MOVE, PROMPT_NOTAKTO_MOVE, PROMPT_SLIDING_MOVE};
+use super::cmdln_interface::{PROMPT_ORDER_CHAOS_MOVE, ORDER_TO_MOVE, CHAOS_TO_MOVE};
+use super::cmdln_interface::{PROMPT_HEX_MOVE, PROMPT_HEX_SWAP_MOVE, HEX_TOP_TO_BOTTOM, HEX_LEFT_TO_RIGHT};
+use super::cmdln_interface::{get_int_input, get_line_input, get_str_input, Console};
+use super::cmdln_args::Settings;
+use super::player::{List, Player, ControlMode, Sprite};
+use super::player_editor::player_editor;
+use super::game::{Game, Mode, Rules, State, MoveStatus};
+use super::game_engine::{player_to_move, read_move};
+use super::{hex, order_chaos};
+use super::game_editor::game_editor;
+use super::session::Session;
+use super::cmdln_tournament::tournament_menu;
+use super::rating::fair_difficulty;
+use super::series::{FirstMove, Series};
+
+/// This function runs the interactive menus that are used when the program is 
+/// started without arguments. It returns once the user exits from the main menu
+/// or the input runs out.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::Cursor;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_app::main_menu;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+///
+/// // open the game editor, leave it and exit
+/// let mut console = Console::new(Cursor::new("2\nq\nq\n"), Vec::new());
+///
+/// main_menu(&mut console);
+///
+/// assert!(String::from_utf8_lossy(console.writer()).contains("Exiting to main menu"));
+/// ```
+pub fn main_menu<R: BufRead, W: Write>(console: &mut Console<R, W>) {
+    let mut rng = StdRng::from_entropy();
+    let mut user_input = get_int_input(console, MAIN_MENU);
+    let mut session = Session::new(Game::tic_tac_toe(), List::new(vec![
+        Player::human(String::from("P1"), Sprite::new("X")),
+        Player::ai(Sprite::new("H")),
+    ]));
+
+    while user_input.is_some() {
+        match user_input {
+            Some(1) => {
+                session.player_list = player_editor(console, WHICH_PLAYER, session.player_list.clone());              
+
+                user_input = get_int_input(console, MAIN_MENU);
+            }
+            Some(2) => {
+                session.game = game_editor(console, GAME_EDITOR, session.game.clone());
+
+                user_input = get_int_input(console, MAIN_MENU);
+            }
+            Some(3) => {
+                if let Some(series) = new_series(console, session.player_list.players.len()) {
+                    play_series(console, &mut session, series, &mut rng);
+                    offer_fair_ai(console, &mut session);
+                }
+
+                user_input = get_int_input(console, MAIN_MENU);
+            }
+            Some(4) => {
+                if let Err(error) = tournament_menu(console, &session.game, &session.player_list, &mut rng) {
+                    console.println(format!("The tournament could not be saved or loaded: {error}"));
+                }
+
+                user_input = get_int_input(console, MAIN_MENU);
+            }
+            _ => {
+                console.println("Invalid selection. Try again.");
+
+                user_input = get_int_input(console, MAIN_MENU);
+            }
+        }
+    }
+}
+
+/// This function plays the rounds or the series that were set up from the
+/// command line, printing the board and the scores after each round.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `settings` - \
+///    The game, players, seed and number of rounds or series to play
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::Cursor;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_app::play_match;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_args::{parse_args, Command};
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+///
+/// let args: Vec<String> = ["--p1-control", "ai", "--ai-difficulty", "hard", "--seed", "1"]
+///     .iter()
+///     .map(|arg| arg.to_string())
+///     .collect();
+/// let mut console = Console::new(Cursor::new(""), Vec::new());
+///
+/// if let Ok(Command::Play(settings)) = parse_args(&args) {
+///     play_match(&mut console, *settings);
+/// }
+///
+/// // two perfect players always tie
+/// assert!(String::from_utf8_lossy(console.writer()).contains("It was a tie"));
+/// ```
+pub fn play_match<R: BufRead, W: Write>(console: &mut Console<R, W>, settings: Settings) {
+    let Settings { game, player_list, seed, rounds, series } = settings;
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let mut session = Session::new(game, player_list);
+
+    if !fits_players(console, &session) {
+        return;
+    }
+
+    if let Some(series) = series {
+        play_series(console, &mut session, series, &mut rng);
+
+        return;
+    }
+
+    for round in 1..=rounds {
+        console.println(format!("Welcome to {name}: Round {round} of {rounds}", 
+            name = session.game.name));
+
+        play_round(console, &mut session, &mut rng);
+
+        console.println(session.game.coloured(&session.player_list, console.colour));
+        console.println(&session.player_list);
+        print_round_summary(console, &session);
+
+        session.next_round();
+    }
+}
+
+// checks the game can be played by this many players, saying why not when it can't
+fn fits_players<R: BufRead, W: Write>(console: &mut Console<R, W>, session: &Session) -> bool {
+    let num_of_players = session.player_list.players.len();
+
+    if session.game.is_for_two() && num_of_players != 2 {
+        console.println(format!("{name} is played by two players, not {num_of_players}. Change the players or the game first.",
+            name = session.game.name));
+
+        return false;
+    }
+
+    true
+}
+
+// keeps asking until the user answers `y`, `n` or `q`
+fn get_yes_or_no<R: BufRead, W: Write>(console: &mut Console<R, W>, message: &str) -> String {
+    let mut answer = get_str_input(console, message, 1);
+
+    while !(matches!(answer.as_str(), "Y" | "N" | ESCAPE_CHAR)) {
+        console.println("Please enter a `Y` or `N`: Try again.");
+
+        answer = get_str_input(console, message, 1);
+    }
+
+    answer
+}
+
+// prints everyone's record and rating in the mode after a round, with how much
+// the rating moved
+fn print_round_summary<R: BufRead, W: Write>(console: &mut Console<R, W>, session: &Session) {
+    if session.result() == State::NotOver {
+        return;
+    }
+
+    let mode = session.game.current_mode;
+
+    let players = &session.player_list.players;
+    let width = players.iter().map(|player| player.short_name().chars().count() + 1).max().unwrap_or(0);
+
+    console.println(format!("{mode} results:"));
+
+    for player in players {
+        let name = format!("{name}:", name = player.short_name());
+        let rating = match player.rating_history(mode) {
+            [.., before, after] => format!("{after:.0} ({change:+.0})", change = after - before),
+            _ => format!("{rating:.0}", rating = player.rating(mode)),
+        };
+
+        console.println(format!("  {name:<width$} {record}, rated {rating}", record = player.record(mode)));
+    }
+}
+
+// offers to move the `Ai` players to the difficulty closest to the humans' rating
+fn offer_fair_ai<R: BufRead, W: Write>(console: &mut Console<R, W>, session: &mut Session) {
+    let mode = session.game.current_mode;
+    let human_ratings: Vec<f64> = session.player_list.players
+        .iter()
+        .filter(|player| player.control == ControlMode::Human)
+        .map(|player| player.rating(mode))
+        .collect();
+    let Ok(num_of_humans @ 1..) = u32::try_from(human_ratings.len()) else {
+        return;
+    };
+    let rating = human_ratings.iter().sum::<f64>() / f64::from(num_of_humans);
+    let fair = fair_difficulty(rating, mode);
+    let is_unfair = |player: &Player| matches!(player.control, ControlMode::Ai(difficulty) if difficulty != fair);
+
+    if !session.player_list.players.iter().any(is_unfair) {
+        return;
+    }
+
+    console.println(format!("A fair Ai for a rating of {rating:.0} is {fair} ({fair_rating:.0})",
+        fair_rating = fair.rating(mode)));
+
+    if get_yes_or_no(console, PROMPT_FAIR_AI) == "Y" {
+        for player in session.player_list.players.iter_mut().filter(|player| is_unfair(player)) {
+            player.control = ControlMode::Ai(fair);
+        }
+    }
+}
+
+/// This function plays a best-of-N series, picking who moves first in each game
+/// by the series' rule, until someone clinches it or every game is played. The
+/// board and the scores are printed after each game and a summary of the series
+/// at the end. If a human leaves a game part of the way through, the series is
+/// left unfinished.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `session` - \
+///    A mutable reference to the session holding the game and the players
+/// * `series` - \
+///    The series to play, usually with no games played yet
+/// * `rng` - \
+///    The random number generator used by the `Ai` players and the coin flip
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::Cursor;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use tic_tac_toe_and_connect_4::{ControlMode, Difficulty, Game, List, Player, Session, Sprite};
+/// use tic_tac_toe_and_connect_4::game_lib::series::{FirstMove, Series};
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_app::play_series;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+///
+/// let mut console = Console::new(Cursor::new(""), Vec::new());
+/// let mut players = vec![Player::ai(Sprite::new("X")), Player::ai(Sprite::new("O"))];
+///
+/// for player in &mut players {
+///     player.control = ControlMode::Ai(Difficulty::Hard);
+/// }
+///
+/// let mut session = Session::new(Game::tic_tac_toe(), List::new(players));
+///
+/// play_series(&mut console, &mut session, Series::new(3, FirstMove::Alternate, 2), &mut StdRng::seed_from_u64(1));
+///
+/// // two perfect players tie every game
+/// assert!(String::from_utf8_lossy(console.writer()).contains("The series is drawn"));
+///
+/// // Reversi can't be started with a third player
+/// let mut console = Console::new(Cursor::new(""), Vec::new());
+/// let mut session = Session::new(Game::reversi(8), List::new(vec![
+///     Player::ai(Sprite::new("X")),
+///     Player::ai(Sprite::new("O")),
+///     Player::ai(Sprite::new("H")),
+/// ]));
+///
+/// play_series(&mut console, &mut session, Series::new(3, FirstMove::Alternate, 3), &mut StdRng::seed_from_u64(1));
+///
+/// assert!(String::from_utf8_lossy(console.writer()).contains("Reversi is played by two players"));
+/// assert_eq!(session.game.num_of_turns, 0);
+/// ```
+pub fn play_series<R: BufRead, W: Write>(
+    console: &mut Console<R, W>,
+    session: &mut Session,
+    mut series: Series,
+    rng: &mut StdRng,
+) {
+    if !fits_players(console, session) {
+        return;
+    }
+
+    while !series.is_over() {
+        let first_player = pick_first_player(console, session, &series, rng);
+
+        session.next_round_from(first_player);
+        console.println(format!("Welcome to {name}: Game {game} of a best of {best_of}, {first} moves first",
+            name = session.game.name, game = series.games.len() + 1, best_of = series.best_of,
+            first = session.whose_turn().name));
+
+        play_round(console, session, rng);
+
+        if session.result() == State::NotOver {
+            console.println(format!("The series was left at {score}", score = series_score(&series, session)));
+
+            return;
+        }
+
+        console.println(session.game.coloured(&session.player_list, console.colour));
+        console.println(&session.player_list);
+        print_round_summary(console, session);
+
+        series.record(first_player, session.winner().map(|_| session.current_player_number()));
+        console.println(format!("Series: {score}", score = series_score(&series, session)));
+    }
+
+    let score = series_score(&series, session);
+
+    match series.winner() {
+        Some(winner) => console.println(format!("{name} wins the series {score}",
+            name = session.player_list.players[winner - 1].name)),
+        None => console.println(format!("The series is drawn {score}")),
+    }
+
+    session.next_round();
+}
+
+// asks how long a series should be and how the first player is picked
+fn new_series<R: BufRead, W: Write>(console: &mut Console<R, W>, num_of_players: usize) -> Option<Series> {
+    let best_of = loop {
+        match get_int_input(console, PROMPT_SERIES_LENGTH)? {
+            0 => console.println("A series needs at least one game"),
+            val => break val,
+        }
+    };
+    let first_move = loop {
+        match get_int_input(console, FIRST_MOVE_SEL)? {
+            val if (1..=FirstMove::ALL.len()).contains(&val) => break FirstMove::ALL[val - 1],
+            _ => console.println("Invalid selection"),
+        }
+    };
+
+    Some(Series::new(best_of, first_move, num_of_players))
+}
+
+// picks who moves first in the next game, asking a human loser if they get to choose
+fn pick_first_player<R: BufRead, W: Write>(
+    console: &mut Console<R, W>,
+    session: &Session,
+    series: &Series,
+    rng: &mut StdRng,
+) -> usize {
+    let first_player = series.next_first_player(rng);
+    let players = &session.player_list.players;
+
+    match series.last_loser() {
+        Some(loser) if series.first_move == FirstMove::LoserChooses && players[loser - 1].control == ControlMode::Human => {
+            console.println(format!("{name} lost the last game and picks who moves first", name = players[loser - 1].name));
+
+            get_int_input(console, PROMPT_FIRST_PLAYER)
+                .filter(|number| (1..=players.len()).contains(number))
+                .unwrap_or(first_player)
+        }
+        _ => first_player,
+    }
+}
+
+// the series score, like `P1 2 - HAL 1 (1 tie)`
+fn series_score(series: &Series, session: &Session) -> String {
+    let wins: Vec<String> = session.player_list.players
+        .iter()
+        .enumerate()
+        .map(|(index, player)| format!("{name} {wins}", name = player.short_name(), wins = series.wins(index + 1)))
+        .collect();
+
+    match series.ties() {
+        0 => wins.join(" - "),
+        1 => format!("{wins} (1 tie)", wins = wins.join(" - ")),
+        ties => format!("{wins} ({ties} ties)", wins = wins.join(" - ")),
+    }
+}
+
+/// This function plays turns until the round is over or a human decides to leave
+/// it. The winner's score is updated in the session's player list.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `session` - \
+///    A mutable reference to the session holding the game and the players
+/// * `rng` - \
+///    The random number generator used by the `Ai` players
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::Cursor;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use tic_tac_toe_and_connect_4::{Game, List, Player, Session, Sprite, State};
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_app::play_round;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+///
+/// let mut console = Console::new(Cursor::new("1\n4\n2\n5\n3\n"), Vec::new());
+/// let mut session = Session::new(Game::tic_tac_toe(), List::new(vec![
+///     Player::human(String::from("P1"), Sprite::new("X")),
+///     Player::human(String::from("P2"), Sprite::new("O")),
+/// ]));
+///
+/// play_round(&mut console, &mut session, &mut StdRng::seed_from_u64(0));
+///
+/// assert_eq!(session.result(), State::Won);
+/// assert_eq!(session.player_list.players[0].wins, 1);
+/// ```
+pub fn play_round<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    session: &mut Session, 
+    rng: &mut StdRng,
+) {
+    while matches!(session.result(), State::NotOver) {
+        console.println(session.game.coloured(&session.player_list, console.colour));
+        console.println(format!("Current Player: {current_player}", 
+            current_player = session.whose_turn().name));
+
+        let player_move = if let Some(val) = session.ai_move(rng) {
+            val
+        } else if let Some(val) = get_move_input(console, &session.game) {
+            val
+        } else {
+            console.println("Exiting game...");
+
+            break;
+        };
+
+        if let MoveStatus::Invalid(error) = session.submit_move(player_move) {
+            console.println(error);
+
+            continue;
+        }
+
+        match session.result() {
+            State::Won | State::Lost => {
+                if let Some(winner) = session.winner() {
+                    console.println(format!("Congrats {winner} won!", winner = winner.name));
+                }
+            }
+            State::Tie => console.println("It was a tie"),
+            State::NotOver => (),
+        }
+    }
+}
+
+/// This function gives the prompt that asks for a move the way the current
+/// mode reads it (see `game_engine::read_move`), so a player knows how to
+/// type it.
+///
+/// # Arguments
+///
+/// * `game` - \
+///    A reference to the game being played
+///
+/// # Returns
+///
+/// * `&str` - \
+///    The prompt for the next move
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::Game;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_app::move_prompt;
+///
+/// assert!(move_prompt(&Game::qubic(4)).contains("`layer row column`"));
+/// ```
+#[must_use]
+pub fn move_prompt(game: &Game) -> &'static str {
+    match game.current_mode {
+        Mode::Qubic => PROMPT_QUBIC_MOVE,
+        Mode::Gomoku | Mode::Reversi => PROMPT_GRID_MOVE,
+        Mode::ConnectFour if game.rules == Rules::PopOut => PROMPT_POPOUT_MOVE,
+        Mode::TicTacToe if game.rules == Rules::Wild => PROMPT_WILD_MOVE,
+        Mode::TicTacToe if game.rules == Rules::Notakto => PROMPT_NOTAKTO_MOVE,
+        Mode::TicTacToe if game.rules == Rules::Sliding => PROMPT_SLIDING_MOVE,
+        Mode::OrderAndChaos => PROMPT_ORDER_CHAOS_MOVE,
+        Mode::Hex if game.rules == Rules::Swap && game.num_of_turns == 1 => PROMPT_HEX_SWAP_MOVE,
+        Mode::Hex => PROMPT_HEX_MOVE,
+        _ => PROMPT_MOVE,
+    }
+}
+
+// keeps asking until the user types a move the way the mode reads it, or `q`
+fn get_move_input<R: BufRead, W: Write>(console: &mut Console<R, W>, game: &Game) -> Option<usize> {
+    let message = move_prompt(game);
+
+    // remind the players which side they're on where it isn't plain from the
+    // board, since both place the same marks in Order and Chaos and Hex's
+    // sides aren't drawn
+    match game.current_mode {
+        Mode::OrderAndChaos if player_to_move(game) == order_chaos::order(game) => console.println(ORDER_TO_MOVE),
+        Mode::OrderAndChaos => console.println(CHAOS_TO_MOVE),
+        Mode::Hex if player_to_move(game) == hex::TOP_TO_BOTTOM => console.println(HEX_TOP_TO_BOTTOM),
+        Mode::Hex => console.println(HEX_LEFT_TO_RIGHT),
+        _ => (),
+    }
+
+    loop {
+        if let Some(val) = read_move(game, &get_line_input(console, message)?) {
+            return Some(val);
+        }
+
+        console.println("There was an error getting your input. Try again");
+    }
+}
@@ -0,0 +1,658 @@
+use super::game::{Game, Mode, Rules};
+use super::{gomoku, hex, qubic, reversi};
+use super::player::{List, Player, ControlMode, Sprite, MAX_PLAYERS};
+use super::ai_engine::Difficulty;
+use super::tournament::{Format, Tournament};
+use super::series::{FirstMove, Series};
+
+/// The help text printed for `--help` or when the arguments could not be used
+pub const USAGE: &str = "Usage: tic-tac-toe-and-connect-4 [OPTIONS] \
+    \n\nWith no options the menus are shown. Any option starts straight into a game. \
+    \n\nOptions: \
+    \n  --mode <MODE>             `tic-tac-toe`, `connect-4`, `ultimate`, `qubic`, `gomoku`, \
+    \n                            `reversi`, `order-and-chaos` or `hex` (default: tic-tac-toe) \
+    \n  --width <N>               Number of columns, at least 3 \
+    \n  --height <N>              Number of rows, at least 3 \
+    \n                            (in `qubic` either one sets the size of the cube, default: 4, \
+    \n                            in `reversi` the size of the board, even, default: 8, and in \
+    \n                            `hex` the size of the board, up to 26, default: 11) \
+    \n  --win-length <N>          How many in a line wins (default: the shorter side) \
+    \n  --rules <RULES>           `standard`, or in `gomoku` `freestyle` (five or more wins) or \
+    \n                            `renju` (black can't make fouls), or in `connect-4` `popout` \
+    \n                            (discs can be popped out of the bottom row), or in `tic-tac-toe` \
+    \n                            `misere` (a line loses), `wild` (either player places X or O), \
+    \n                            `notakto` (both place Xs on boards `--height` wide side by side \
+    \n                            and the last line loses) or `sliding` (once placed, marks slide \
+    \n                            to a free cell next to them), or in `hex` `swap` (the second \
+    \n                            player can take the first stone as theirs) (default: standard) \
+    \n  --players <N>             Number of players, taking turns in order (default: 2) \
+    \n  --p1-name <NAME>          Player 1's name, up to 32 characters (default: P1) \
+    \n  --p2-name <NAME>          Player 2's name, up to 32 characters (default: HAL) \
+    \n  --p1-sprite <SPRITE>      Player 1's sprite, up to 3 characters or emoji (default: X) \
+    \n  --p2-sprite <SPRITE>      Player 2's sprite, up to 3 characters or emoji (default: H) \
+    \n  --p1-control <CONTROL>    `human` or `ai` (default: human) \
+    \n  --p2-control <CONTROL>    `human` or `ai` (default: ai) \
+    \n  --p1-bot <COMMAND>        Player 1 is a bot started with the command (see `--engine`) \
+    \n  --p2-bot <COMMAND>        Player 2 is a bot started with the command \
+    \n  --p3-name, --p3-sprite... The same options for player 3 and on, up to 35, who default \
+    \n                            to Ai players named after their seat, e.g. `P3` \
+    \n  --ai-difficulty <LEVEL>   `easy`, `medium` or `hard` for every Ai player (default: easy) \
+    \n  --seed <N>                Seed for the Ai so a match can be replayed \
+    \n  --rounds <N>              Number of rounds to play before exiting (default: 1) \
+    \n  --tournament <FILE>       Play a tournament between the players, saved in the file and \
+    \n                            picked up from it if it is already there \
+    \n  --format <FORMAT>         `round-robin`, `swiss` or `knockout` (default: round-robin) \
+    \n  --best-of <N>             Games in each tournament match, or without `--tournament` play \
+    \n                            a best-of-N series that ends once someone clinches it \
+    \n  --first-move <RULE>       Who moves first in each game of a series: `alternate`, `loser` \
+    \n                            (the loser of the last game picks) or `coin` (default: alternate) \
+    \n  --serve <ADDRESS>         Host the game over TCP, e.g. `0.0.0.0:7878` (players after \
+    \n                            player 1 default to human, and each joins with `--connect`) \
+    \n  --connect <ADDRESS>       Join a game hosted with `--serve`, used on its own \
+    \n  --http <ADDRESS>          Serve the HTTP JSON API, e.g. `127.0.0.1:8080`, used on its own \
+    \n  --engine                  Be a bot for another program over stdin and stdout, used on its own \
+    \n  --help                    Print this message";
+
+/// An enumerator used to tell the program what it was asked to do from the
+/// command line
+#[derive(Debug, PartialEq, Clone)]
+pub enum Command {
+    /// `Menu` - \
+    ///  No arguments were given so the menus should be shown
+    Menu,
+    /// `Help` - \
+    ///  The user asked for the usage message
+    Help,
+    /// `Play(settings)` - \
+    ///  Start straight into a game with the given settings
+    Play(Box<Settings>),
+    /// `Serve(address, settings)` - \
+    ///  Host a game with the given settings for clients to join over TCP
+    Serve(String, Box<Settings>),
+    /// `Connect(address)` - \
+    ///  Join a game hosted by a server at the address
+    Connect(String),
+    /// `Http(address)` - \
+    ///  Serve the HTTP JSON API at the address
+    Http(String),
+    /// `Engine` - \
+    ///  Play as a bot for another program over stdin and stdout
+    Engine,
+    /// `Tournament(file, tournament)` - \
+    ///  Play the tournament saved in the file, or start this one if there isn't one
+    Tournament(String, Box<Tournament>),
+}
+
+/// A struct used to hold everything needed to start a match from the command line
+#[derive(Debug, PartialEq, Clone)]
+pub struct Settings {
+    /// `game` - \
+    ///  The game to play, already sized and set to the right mode
+    pub game: Game,
+    /// `player_list` - \
+    ///  The players in the match
+    pub player_list: List,
+    /// `seed` - \
+    ///  A seed for the random number generator, or `None` to get a random one
+    pub seed: Option<u64>,
+    /// `rounds` - \
+    ///  How many rounds to play before the program exits
+    pub rounds: usize,
+    /// `series` - \
+    ///  A best-of-N series to play instead of a set number of rounds
+    pub series: Option<Series>,
+}
+
+// the options that belong to one player
+#[derive(Default)]
+struct PlayerArgs {
+    name: Option<String>,
+    sprite: Option<String>,
+    ai: Option<bool>,
+    bot: Option<String>,
+}
+
+/// This function is used to turn the program's arguments into a `Command`.
+///
+/// # Arguments
+///
+/// * `args` - \
+///    A slice of the arguments, without the program's name
+///
+/// # Returns
+///
+/// * `Ok(Command)` - \
+///    What the program was asked to do
+/// * `Err(String)` - \
+///    A message explaining which argument could not be used
+///
+/// # Errors
+///
+/// Will return an error for unknown options, missing or badly formatted values,
+/// and settings that can't make a playable game.
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_args::{Command, parse_args};
+///
+/// let args = vec![String::from("--mode"), String::from("connect-4"), String::from("--rounds"), String::from("3")];
+///
+/// match parse_args(&args) {
+///     Ok(Command::Play(settings)) => assert_eq!(settings.rounds, 3),
+///     _ => panic!("expected a game to be set up"),
+/// }
+///
+/// // a third player can be added just by giving them options
+/// let args = vec![String::from("--width"), String::from("5"), String::from("--p3-sprite"), String::from("Z")];
+///
+/// match parse_args(&args) {
+///     Ok(Command::Play(settings)) => assert_eq!(settings.player_list.players.len(), 3),
+///     _ => panic!("expected a game to be set up"),
+/// }
+///
+/// // but not one in a seat that can't be filled
+/// assert!(parse_args(&[String::from("--p100000000000-name"), String::from("x")]).is_err());
+/// assert!(parse_args(&[String::from("--p0-name"), String::from("x")]).is_err());
+/// ```
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    if let Some(command) = standalone_command(args) {
+        return Ok(command);
+    }
+
+    let mut mode = Mode::TicTacToe;
+    let mut width = None;
+    let mut height = None;
+    let mut win_length = None;
+    let mut rules = None;
+    let mut num_of_players = None;
+    let mut player_args: Vec<PlayerArgs> = Vec::new();
+    let mut difficulty = Difficulty::default();
+    let mut seed = None;
+    let mut rounds = None;
+    let mut serve = None;
+    let mut tournament = None;
+    let mut format = None;
+    let mut best_of = None;
+    let mut first_move = None;
+    let mut args = args.iter();
+
+    while let Some(option) = args.next() {
+        if option == "--help" {
+            return Ok(Command::Help);
+        }
+
+        if option == "--engine" {
+            return Err(String::from("`--engine` can't be used with other options"));
+        }
+
+        let value = args
+            .next()
+            .ok_or_else(| | format!("`{option}` needs a value"))?;
+
+        match option.as_str() {
+            "--connect" | "--http" => return Err(format!("`{option}` can't be used with other options")),
+            "--serve" => serve = Some(value.clone()),
+            "--tournament" => tournament = Some(value.clone()),
+            "--format" => format = Some(parse_format(value)?),
+            "--best-of" => best_of = Some(parse_number(option, value)?),
+            "--mode" => mode = parse_mode(value)?,
+            "--width" => width = Some(parse_number(option, value)?),
+            "--height" => height = Some(parse_number(option, value)?),
+            "--win-length" => win_length = Some(parse_number(option, value)?),
+            "--rules" => rules = Some(parse_rules(value)?),
+            "--players" => num_of_players = Some(parse_number(option, value)?),
+            "--ai-difficulty" => difficulty = parse_difficulty(value)?,
+            "--seed" => {
+                seed = Some(value
+                    .parse::<u64>()
+                    .map_err(|_| format!("`--seed` needs a whole number, not `{value}`"))?);
+            }
+            "--rounds" => rounds = Some(parse_number(option, value)?),
+            "--first-move" => first_move = Some(parse_first_move(value)?),
+            _ => {
+                let (number, attribute) = parse_player_option(option)?;
+
+                if player_args.len() < number {
+                    player_args.resize_with(number, PlayerArgs::default);
+                }
+
+                let player = &mut player_args[number - 1];
+
+                match attribute {
+                    "name" => player.name = Some(value.clone()),
+                    "sprite" => player.sprite = Some(value.clone()),
+                    "control" => player.ai = Some(parse_control(value)?),
+                    _ => player.bot = Some(value.clone()),
+                }
+            }
+        }
+    }
+
+    let game = build_game(mode, width, height, win_length)?;
+
+    if rounds == Some(0) {
+        return Err(String::from("`--rounds` must be at least 1"));
+    }
+
+    let player_list = build_player_list(player_args, num_of_players, serve.is_some(), difficulty)?;
+    let game = set_rules(game, rules, player_list.players.len())?;
+
+    if let Some(file) = tournament {
+        if serve.is_some() || first_move.is_some() {
+            return Err(String::from("`--tournament` can't be used with `--serve` or `--first-move`"));
+        }
+
+        return build_tournament(file, format, best_of, game, player_list);
+    }
+
+    if format.is_some() {
+        return Err(String::from("`--format` is only used with `--tournament`"));
+    }
+
+    let series = build_series(best_of, first_move, rounds.is_some() || serve.is_some(), &player_list)?;
+    let settings = Box::new(Settings {
+        game,
+        player_list,
+        seed,
+        rounds: rounds.unwrap_or(1),
+        series,
+    });
+
+    match serve {
+        Some(address) => Ok(Command::Serve(address, settings)),
+        None => Ok(Command::Play(settings)),
+    }
+}
+
+// finds the commands that are used on their own: the menus, the engine and
+// the network clients and servers that don't play a game themselves
+fn standalone_command(args: &[String]) -> Option<Command> {
+    match args {
+        [] => Some(Command::Menu),
+        [option] if option == "--engine" => Some(Command::Engine),
+        [option, address] if option == "--connect" => Some(Command::Connect(address.clone())),
+        [option, address] if option == "--http" => Some(Command::Http(address.clone())),
+        _ => None,
+    }
+}
+
+// makes the game from its options, as long as it can be played
+fn build_game(
+    mode: Mode,
+    width: Option<usize>,
+    height: Option<usize>,
+    win_length: Option<usize>,
+) -> Result<Game, String> {
+    let mut game = match mode {
+        Mode::ConnectFour => Game::connect_four(),
+        Mode::UltimateTicTacToe if width.is_some() || height.is_some() || win_length.is_some() => {
+            return Err(String::from("The board can't be resized in Ultimate Tic-Tac-Toe"));
+        }
+        Mode::UltimateTicTacToe => return Ok(Game::ultimate_tic_tac_toe()),
+        Mode::Qubic => return build_qubic(width, height, win_length),
+        Mode::Gomoku if win_length.is_some_and(|win_length| win_length != gomoku::WIN_LENGTH) => {
+            return Err(String::from("Five in a row always wins in Gomoku"));
+        }
+        Mode::Gomoku => Game::gomoku(Rules::default()),
+        Mode::Reversi => return build_reversi(width, height, win_length),
+        Mode::OrderAndChaos if width.is_some() || height.is_some() || win_length.is_some() => {
+            return Err(String::from("The board can't be resized in Order and Chaos"));
+        }
+        Mode::OrderAndChaos => return Ok(Game::order_and_chaos()),
+        Mode::Hex => return build_hex(width, height, win_length),
+        _ => Game::tic_tac_toe(),
+    };
+
+    game.width = width.unwrap_or(game.width);
+    game.height = height.unwrap_or(game.height);
+    game.win_length = win_length.unwrap_or(match mode {
+        Mode::Gomoku => gomoku::WIN_LENGTH,
+        _ => game.width.min(game.height),
+    });
+    game.board = vec![None; game.width * game.height];
+
+    if game.width < 3 || game.height < 3 {
+        return Err(String::from("The board must be at least 3 by 3"));
+    }
+
+    if game.win_length < 3 || game.win_length > game.width.max(game.height) {
+        return Err(String::from("`--win-length` must be at least 3 and fit on the board"));
+    }
+
+    Ok(game)
+}
+
+// plays the game by the rules picked, as long as the mode and the players fit them
+fn set_rules(mut game: Game, rules: Option<Rules>, num_of_players: usize) -> Result<Game, String> {
+    if let Some(rules) = rules {
+        if !Rules::for_mode(game.current_mode).contains(&rules) {
+            return Err(format!("{mode} can't be played by {rules} rules", mode = game.current_mode));
+        }
+
+        game.rules = rules;
+    }
+
+    if game.rules.is_for_two() && num_of_players != 2 {
+        return Err(format!("{rules} is played by two players", rules = game.rules));
+    }
+
+    // Notakto's boards are squares as tall as the board, side by side
+    if game.rules == Rules::Notakto && !game.width.is_multiple_of(game.height) {
+        return Err(String::from("In Notakto `--width` must be a multiple of `--height`, one board for each"));
+    }
+
+    if game.rules == Rules::Notakto && game.win_length > game.height {
+        return Err(String::from("`--win-length` must fit on one of Notakto's boards"));
+    }
+
+    if game.is_for_two() && num_of_players != 2 {
+        return Err(format!("{mode} is played by two players", mode = game.current_mode));
+    }
+
+    Ok(game)
+}
+
+// makes a Qubic cube, where the width, height and win length are all the size
+// of its sides
+fn build_qubic(width: Option<usize>, height: Option<usize>, win_length: Option<usize>) -> Result<Game, String> {
+    let size = match (width, height) {
+        (Some(width), Some(height)) if width != height => {
+            return Err(String::from("`--width` and `--height` must match in Qubic"));
+        }
+        (Some(size), _) | (None, Some(size)) => size,
+        (None, None) => qubic::DEFAULT_SIZE,
+    };
+
+    if size < 3 {
+        return Err(String::from("The cube must be at least 3 by 3 by 3"));
+    }
+
+    if win_length.is_some_and(|win_length| win_length != size) {
+        return Err(String::from("`--win-length` must be the size of the cube in Qubic"));
+    }
+
+    Ok(Game::qubic(size))
+}
+
+// makes a square Reversi board, which needs an even size so the discs can start
+// crossed in the middle
+fn build_reversi(width: Option<usize>, height: Option<usize>, win_length: Option<usize>) -> Result<Game, String> {
+    let size = match (width, height) {
+        (Some(width), Some(height)) if width != height => {
+            return Err(String::from("`--width` and `--height` must match in Reversi"));
+        }
+        (Some(size), _) | (None, Some(size)) => size,
+        (None, None) => reversi::DEFAULT_SIZE,
+    };
+
+    if size < 4 || !size.is_multiple_of(2) {
+        return Err(String::from("The Reversi board must be an even size, at least 4 by 4"));
+    }
+
+    if win_length.is_some() {
+        return Err(String::from("`--win-length` isn't used in Reversi, the most discs wins"));
+    }
+
+    Ok(Game::reversi(size))
+}
+
+// makes a Hex rhombus, which has as many cells along every side and a letter
+// for each column
+fn build_hex(width: Option<usize>, height: Option<usize>, win_length: Option<usize>) -> Result<Game, String> {
+    let size = match (width, height) {
+        (Some(width), Some(height)) if width != height => {
+            return Err(String::from("`--width` and `--height` must match in Hex"));
+        }
+        (Some(size), _) | (None, Some(size)) => size,
+        (None, None) => hex::DEFAULT_SIZE,
+    };
+
+    if !(3..=hex::MAX_SIZE).contains(&size) {
+        return Err(format!("The Hex board must be from 3 by 3 up to {max} by {max}", max = hex::MAX_SIZE));
+    }
+
+    if win_length.is_some() {
+        return Err(String::from("`--win-length` isn't used in Hex, connecting your sides wins"));
+    }
+
+    Ok(Game::hex(size))
+}
+
+// sets up a best-of-N series between the players, if one was asked for
+fn build_series(
+    best_of: Option<usize>,
+    first_move: Option<FirstMove>,
+    is_rounds_or_served: bool,
+    player_list: &List,
+) -> Result<Option<Series>, String> {
+    match (best_of, first_move) {
+        (None, None) => Ok(None),
+        (None, Some(_)) => Err(String::from("`--first-move` is only used with `--best-of`")),
+        (Some(_), _) if is_rounds_or_served => Err(String::from("`--best-of` can't be used with `--rounds` or `--serve`")),
+        (Some(0), _) => Err(String::from("`--best-of` must be at least 1")),
+        (Some(best_of), first_move) => Ok(Some(Series::new(best_of, first_move.unwrap_or_default(),
+            player_list.players.len()))),
+    }
+}
+
+// sets up a tournament between the players that is saved in the file
+fn build_tournament(
+    file: String,
+    format: Option<Format>,
+    best_of: Option<usize>,
+    game: Game,
+    player_list: List,
+) -> Result<Command, String> {
+    if best_of == Some(0) {
+        return Err(String::from("`--best-of` must be at least 1"));
+    }
+
+    let name = file.rsplit(['/', '\\']).next().unwrap_or(&file).trim_end_matches(".json").to_string();
+    let mut tournament = Tournament::new(name, format.unwrap_or(Format::RoundRobin), game, 
+        player_list.players, best_of.unwrap_or(1));
+
+    for entrant in &mut tournament.entrants {
+        entrant.reset();
+    }
+
+    Ok(Command::Tournament(file, Box::new(tournament)))
+}
+
+// makes every player from their options, filling in the players without any
+fn build_player_list(
+    mut player_args: Vec<PlayerArgs>,
+    num_of_players: Option<usize>,
+    is_served: bool,
+    difficulty: Difficulty,
+) -> Result<List, String> {
+    let num_of_players = num_of_players.unwrap_or(player_args.len().max(2));
+
+    if !(2..=MAX_PLAYERS).contains(&num_of_players) {
+        return Err(format!("`--players` must be from 2 to {MAX_PLAYERS}"));
+    }
+
+    if player_args.len() > num_of_players {
+        return Err(format!("There are options for player {number} but only {num_of_players} players",
+            number = player_args.len()));
+    }
+
+    player_args.resize_with(num_of_players, PlayerArgs::default);
+
+    let mut player_list = List::new(Vec::new());
+
+    for (index, args) in player_args.into_iter().enumerate() {
+        let default_player = match (index, is_served) {
+            (0, _) => Player::human(String::from("P1"), Sprite::new("X")),
+            (1, false) => Player::ai(Sprite::new("H")),
+            (1, true) => Player::human(String::from("P2"), Sprite::new("O")),
+            (_, is_served) => {
+                let mut player = player_list
+                    .new_player()
+                    .ok_or_else(|| String::from("Every sprite new players are given is already taken"))?;
+
+                if is_served {
+                    player.control = ControlMode::Human;
+                }
+
+                player
+            }
+        };
+
+        let is_named = args.name.is_some();
+        let mut player = build_player(args, default_player, difficulty)?;
+
+        // default names like `HAL` are numbered when they are used twice
+        if !is_named {
+            player.name = player_list.free_name(&player.name);
+        }
+
+        player.name = player_list
+            .check_name(&player.name, index + 1)
+            .map_err(|error| format!("{error}, `{name}` can't be used", name = player.name))?;
+        player.sprite = player_list
+            .check_sprite(&player.sprite.0, index + 1)
+            .map_err(|error| format!("{error}, `{sprite}` can't be used", sprite = player.sprite))?;
+        player_list.players.push(player);
+    }
+
+    Ok(player_list)
+}
+
+// applies one player's options on top of their default player
+fn build_player(args: PlayerArgs, mut player: Player, difficulty: Difficulty) -> Result<Player, String> {
+    match args.ai {
+        Some(true) => {
+            if player.control == ControlMode::Human {
+                player.name = String::from("HAL");
+            }
+
+            player.control = ControlMode::Ai(difficulty);
+        }
+        Some(false) => player.control = ControlMode::Human,
+        None => {
+            if let ControlMode::Ai(_) = player.control {
+                player.control = ControlMode::Ai(difficulty);
+            }
+        }
+    }
+
+    if let Some(command) = args.bot {
+        if command.trim().is_empty() {
+            return Err(String::from("A bot needs a command to start it"));
+        }
+
+        player.name = String::from("BOT");
+        player.control = ControlMode::Bot(command.trim().to_string());
+    }
+
+    if let Some(name) = args.name {
+        player.name = name;
+    }
+
+    if let Some(sprite) = args.sprite {
+        player.sprite = Sprite(sprite);
+    }
+
+    Ok(player)
+}
+
+// splits a player's option like `--p3-name` into the player's number and the
+// name of the option
+fn parse_player_option(option: &str) -> Result<(usize, &str), String> {
+    let (number, attribute) = option
+        .strip_prefix("--p")
+        .and_then(|rest| rest.split_once('-'))
+        .ok_or_else(| | format!("Unknown option `{option}`"))?;
+
+    match (number.parse::<usize>(), attribute) {
+        (Ok(number @ 1..=MAX_PLAYERS), "name" | "sprite" | "control" | "bot") => Ok((number, attribute)),
+        (Ok(_), "name" | "sprite" | "control" | "bot") => {
+            Err(format!("`{option}` is for player {number}, but players go from 1 to {MAX_PLAYERS}"))
+        }
+        _ => Err(format!("Unknown option `{option}`")),
+    }
+}
+
+// turns a mode name into a Mode
+fn parse_mode(value: &str) -> Result<Mode, String> {
+    match value.to_lowercase().as_str() {
+        "tic-tac-toe" | "tictactoe" | "ttt" => Ok(Mode::TicTacToe),
+        "connect-4" | "connect-four" | "connectfour" | "c4" => Ok(Mode::ConnectFour),
+        "ultimate" | "ultimate-tic-tac-toe" | "uttt" => Ok(Mode::UltimateTicTacToe),
+        "qubic" | "3d" | "3d-tic-tac-toe" => Ok(Mode::Qubic),
+        "gomoku" | "five-in-a-row" => Ok(Mode::Gomoku),
+        "reversi" | "othello" => Ok(Mode::Reversi),
+        "order-and-chaos" | "order-chaos" => Ok(Mode::OrderAndChaos),
+        "hex" => Ok(Mode::Hex),
+        _ => Err(format!(
+            "Unknown mode `{value}`, use `tic-tac-toe`, `connect-4`, `ultimate`, `qubic`, `gomoku`, `reversi`, \
+            `order-and-chaos` or `hex`"
+        )),
+    }
+}
+
+// turns a rules name into Rules
+fn parse_rules(value: &str) -> Result<Rules, String> {
+    match value.to_lowercase().as_str() {
+        "standard" => Ok(Rules::Standard),
+        "freestyle" => Ok(Rules::Freestyle),
+        "renju" => Ok(Rules::Renju),
+        "popout" | "pop-out" => Ok(Rules::PopOut),
+        "misere" | "misère" => Ok(Rules::Misere),
+        "wild" => Ok(Rules::Wild),
+        "notakto" => Ok(Rules::Notakto),
+        "sliding" | "three-pieces" => Ok(Rules::Sliding),
+        "swap" | "pie" => Ok(Rules::Swap),
+        _ => Err(format!("Unknown rules `{value}`, use `standard`, `freestyle`, `renju`, `popout`, `misere`, `wild`, \
+            `notakto`, `sliding` or `swap`")),
+    }
+}
+
+// turns a format name into a tournament Format
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value.to_lowercase().as_str() {
+        "round-robin" | "roundrobin" => Ok(Format::RoundRobin),
+        "swiss" => Ok(Format::Swiss),
+        "knockout" | "single-elimination" => Ok(Format::SingleElimination),
+        _ => Err(format!("Unknown format `{value}`, use `round-robin`, `swiss` or `knockout`")),
+    }
+}
+
+// turns a rule name into the way the first player of each game is picked
+fn parse_first_move(value: &str) -> Result<FirstMove, String> {
+    match value.to_lowercase().as_str() {
+        "alternate" => Ok(FirstMove::Alternate),
+        "loser" => Ok(FirstMove::LoserChooses),
+        "coin" => Ok(FirstMove::CoinFlip),
+        _ => Err(format!("Unknown first move rule `{value}`, use `alternate`, `loser` or `coin`")),
+    }
+}
+
+// turns a control name into whether the player is an Ai
+fn parse_control(value: &str) -> Result<bool, String> {
+    match value.to_lowercase().as_str() {
+        "human" => Ok(false),
+        "ai" => Ok(true),
+        _ => Err(format!("Unknown control `{value}`, use `human` or `ai`")),
+    }
+}
+
+// turns a difficulty name into a Difficulty
+fn parse_difficulty(value: &str) -> Result<Difficulty, String> {
+    match value.to_lowercase().as_str() {
+        "easy" => Ok(Difficulty::Easy),
+        "medium" => Ok(Difficulty::Medium),
+        "hard" => Ok(Difficulty::Hard),
+        _ => Err(format!("Unknown difficulty `{value}`, use `easy`, `medium` or `hard`")),
+    }
+}
+
+// turns the value of a numeric option into a number
+fn parse_number(option: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .map_err(|_| format!("`{option}` needs a whole number, not `{value}`"))
+}
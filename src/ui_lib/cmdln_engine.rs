@@ -0,0 +1,206 @@
+use std::io::{BufRead, Write};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use super::cmdln_interface::{get_line_input, Console};
+use super::ai_engine::{think, Difficulty};
+use super::game::{Game, Mode, Piece, PieceKind, Rules, State};
+use super::game_engine::valid_moves;
+use super::hex;
+use super::player::MAX_PLAYERS;
+
+const ENGINE_NAME: &str = env!["CARGO_PKG_NAME"];
+const ENGINE_VER: &str = env!["CARGO_PKG_VERSION"];
+
+/// This function makes the program act as a bot for another program, reading
+/// Grid Engine Protocol commands (see `Bot`) from the console and answering them
+/// with this engine's `Ai`. It returns once it is sent `quit` or the input runs
+/// out. The `difficulty` option picks how well it plays, and it always answers
+/// well within any `movetime` it is given.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct the commands are read from and the answers written to
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::Cursor;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_engine::run_engine;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+///
+/// // player 1 has two in a row along the top and should finish it
+/// let commands = "gep\nsetoption name difficulty value medium\nnewgame tic-tac-toe 3 3 3\n\
+///     isready\nposition 11.22.... 1\ngo movetime 100\nquit\n";
+/// let mut console = Console::new(Cursor::new(commands), Vec::new());
+///
+/// run_engine(&mut console);
+///
+/// let output = String::from_utf8_lossy(console.writer()).to_string();
+///
+/// assert!(output.contains("gepok"));
+/// assert!(output.contains("readyok"));
+/// assert!(output.contains("bestmove 3"));
+/// ```
+pub fn run_engine<R: BufRead, W: Write>(console: &mut Console<R, W>) {
+    let mut rng = StdRng::from_entropy();
+    let mut difficulty = Difficulty::Hard;
+    let mut game = Game::tic_tac_toe();
+    let mut num_of_players = 2;
+    let mut player_number = 1;
+
+    while let Some(line) = get_line_input(console, "") {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["gep"] => {
+                console.println(format!("id name {ENGINE_NAME} v{ENGINE_VER}"));
+                console.println("option name difficulty type combo default hard var easy var medium var hard");
+                console.println("gepok");
+            }
+            ["isready"] => console.println("readyok"),
+            ["setoption", "name", name, "value", value] if name.eq_ignore_ascii_case("difficulty") => {
+                match value.to_lowercase().as_str() {
+                    "easy" => difficulty = Difficulty::Easy,
+                    "medium" => difficulty = Difficulty::Medium,
+                    "hard" => difficulty = Difficulty::Hard,
+                    _ => console.println(format!("info string unknown difficulty `{value}`")),
+                }
+            }
+            ["newgame", mode, width, height, win_length, players @ ..] if players.len() <= 1 => {
+                let players = players.first().map_or(Some(2), |players| players.parse::<usize>().ok());
+
+                match (new_game(mode, width, height, win_length), players) {
+                    // black and white are the only sides in Reversi, as are
+                    // Order and Chaos and Hex's two pairs of sides
+                    (Some(new_game), Some(3..)) if matches!(new_game.current_mode, Mode::Reversi | Mode::OrderAndChaos | Mode::Hex) => {
+                        console.println(format!("info string can't play `{line}`"));
+                    }
+                    (Some(new_game), Some(players @ 2..=MAX_PLAYERS)) => {
+                        game = new_game;
+                        num_of_players = players;
+                    }
+                    _ => console.println(format!("info string can't play `{line}`")),
+                }
+            }
+            ["rules", name] => {
+                let rules = Rules::for_mode(game.current_mode)
+                    .iter()
+                    .find(|rules| rules.to_string().eq_ignore_ascii_case(name));
+
+                match rules {
+                    // Notakto's boards are as wide as the board is tall
+                    Some(Rules::Notakto) if !game.width.is_multiple_of(game.height) => {
+                        console.println(format!("info string can't play `{line}`"));
+                    }
+                    Some(rules) => game.rules = *rules,
+                    None => console.println(format!("info string can't play `{line}`")),
+                }
+            }
+            ["position", cells, number, last_move @ ..] if last_move.len() <= 1 => {
+                let number = number.parse::<usize>().ok().filter(|number| (1..=num_of_players).contains(number));
+                // the last move is only sent in modes where it matters
+                let moves: Option<Vec<usize>> = last_move
+                    .iter()
+                    .map(|last_move| last_move.parse::<usize>().ok().filter(|last_move| (1..=game.board.len()).contains(last_move)))
+                    .collect();
+
+                match (number, moves) {
+                    (Some(number), Some(moves)) if cells.chars().count() == game.board.len() => {
+                        set_position(&mut game, cells);
+                        game.moves = moves;
+                        // whose turn it is in Reversi is kept by who moved
+                        // first, so work back to them from the player to move
+                        game.first_player = (number - 1 + num_of_players - game.num_of_turns % num_of_players) % num_of_players + 1;
+                        player_number = number;
+                    }
+                    _ => console.println(format!("info string the position doesn't fit the game `{line}`")),
+                }
+            }
+            ["go", ..] => {
+                // the players are numbered in the order they move, starting
+                // with the one to move
+                let opponents: Vec<usize> = (1..num_of_players)
+                    .map(|offset| (player_number - 1 + offset) % num_of_players + 1)
+                    .collect();
+
+                if valid_moves(&game).is_empty() {
+                    console.println("bestmove none");
+                } else {
+                    let selection = think(&game, player_number, &opponents, difficulty, &mut rng);
+
+                    console.println(format!("bestmove {selection}"));
+                }
+            }
+            ["quit"] => break,
+            [] => (),
+            _ => console.println(format!("info string unknown command `{line}`")),
+        }
+    }
+}
+
+// makes the game a `newgame` command asked for, if it can be played
+fn new_game(mode: &str, width: &str, height: &str, win_length: &str) -> Option<Game> {
+    let mode = match mode {
+        "tic-tac-toe" => Mode::TicTacToe,
+        "connect-4" => Mode::ConnectFour,
+        "ultimate" => Mode::UltimateTicTacToe,
+        "qubic" => Mode::Qubic,
+        "gomoku" => Mode::Gomoku,
+        "reversi" => Mode::Reversi,
+        "order-and-chaos" => Mode::OrderAndChaos,
+        "hex" => Mode::Hex,
+        _ => return None,
+    };
+    let width = width.parse::<usize>().ok()?;
+    let height = height.parse::<usize>().ok()?;
+    let win_length = win_length.parse::<usize>().ok()?;
+
+    if width == 0 || height == 0 || win_length == 0 {
+        return None;
+    }
+
+    // the small boards only fit together one way
+    if mode == Mode::UltimateTicTacToe && (width, height, win_length) != (9, 9, 3) {
+        return None;
+    }
+
+    // a cube has the same number of cells along every side, and a line goes
+    // all the way through it
+    if mode == Mode::Qubic && (width != height || win_length != width) {
+        return None;
+    }
+
+    // a rhombus has as many cells along every side, and each column has a
+    // letter
+    if mode == Mode::Hex && (width != height || width > hex::MAX_SIZE) {
+        return None;
+    }
+
+    // the discs start crossed in the middle of the board
+    if mode == Mode::Reversi && (!width.is_multiple_of(2) || !height.is_multiple_of(2)) {
+        return None;
+    }
+
+    Some(Game::new(mode.to_string(), mode, width, height, win_length))
+}
+
+// puts the pieces from a `position` command on the board, where the marks
+// either player can place are `x` and `o`
+fn set_position(game: &mut Game, cells: &str) {
+    let plays_marks = game.plays_marks();
+
+    game.board = cells
+        .chars()
+        .map(|cell| match (cell, cell.to_digit(36)) {
+            ('x', _) if plays_marks => Some(Piece { owner: 1, kind: PieceKind::Cross }),
+            ('o', _) if plays_marks => Some(Piece { owner: 1, kind: PieceKind::Nought }),
+            (_, Some(number @ 1..)) => usize::try_from(number).ok().map(Piece::stone),
+            _ => None,
+        })
+        .collect();
+    game.num_of_turns = game.board.iter().filter(|cell| cell.is_some()).count();
+    game.current_state = State::NotOver;
+}
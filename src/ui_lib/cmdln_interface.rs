@@ -1,33 +1,205 @@
-use std::io;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use super::theme::colour_enabled;
 
 ///
 /// # All the strings for the program's output and prompts for the user.
 ///
 pub const ESCAPE_CHAR: &str = "Q";
 pub const MAIN_MENU: &str = "WHat would you like to do? \
-    \n`1` for player editor \n`2` for game editor \n`3` to play game \n`q` to exit \nSelection: ";
+    \n`1` for player editor \n`2` for game editor \n`3` to play game \n`4` for a tournament \n`q` to exit \nSelection: ";
 pub const TO_MAIN: &str = "Exiting to main menu. . .";
-pub const GAME_EDITOR: &str = "Would you like to edit the gamemode or board size? \
-    \n`1` for Gamemode \n`2` for Boardsize \n`q` Exit \nSelection: ";
-pub const GAME_MODE_SEL: &str = "Which game mode would you like to play? \
-   \n`1` Tic-Tac-Toe \n`2` Connect-4 \n`3` Chess \n`4` Checkers \n`q` To keep current mode \
-    \nSelection:";
+pub const GAME_EDITOR: &str = "Would you like to edit the gamemode, board size, theme or rules? \
+    \n`1` for Gamemode \n`2` for Boardsize \n`3` for Theme \n`4` for Rules \n`q` Exit \nSelection: ";
+pub const THEME_SEL: &str = "Which theme would you like the board drawn with? \
+    \n`1` Classic \n`2` High Contrast \n`3` Colour-Blind Safe \n`q` To keep current theme \
+    \nSelection: ";
 pub const BOARD_SIZE_SEL: &str = "Which size board would you like to play on? \
     `q` to keep current size: ";
+pub const NOTAKTO_BOARDS_SEL: &str = "How many boards would you like to play on? \
+    `q` to keep current boards: ";
 pub const WHICH_PLAYER: &str = "Which player do you want to edit? \
-    \n`1` Player 1  \n`2` Player 2 \n`q` to exit \nSelection: ";
+    \n`1`, `2`... That player \n`+` Add a player \n`-` Remove a player \n`q` to exit \nSelection: ";
+pub const PROMPT_REMOVE_PLAYER: &str = "Which player do you want to remove? (Type `q` to exit): ";
 pub const PLAYER_ATTRIBUTE_MENU: &str = "Which player attribute do you want to edit or reset to default? \
-    \n`1` Name \n`2` Type \n`3` Sprite \n`4` Reset Player \n`q` Go back to player selection \nSelection: ";
+    \n`1` Name \n`2` Type \n`3` Sprite \n`4` Colour \n`5` Reset Player \n`6` Results and Ratings \n`q` Go back to player selection \nSelection: ";
 pub const PROMPT_PLAYER_NAME: &str = "Enter the players name (Type `q` to exit): ";
 pub const PROMPT_PLAYER_TYPE: &str = "Select a player type: \
-    \n`1` for Huamn \n`2` for Ai \n`q` to exit \nSelection: ";
-pub const PROMPT_PLAYER_SPRITE: &str = "Type in the new sprite (Type `q` to exunt)`: ";
-pub const PLAY_AGAIN: &str = "Would you like to play again? `y` or `n`: ";
+    \n`1` for Human \n`2` for Easy Ai \n`3` for Medium Ai \n`4` for Hard Ai \n`5` for Bot \
+    \n`q` to exit \nSelection: ";
+pub const PROMPT_BOT_COMMAND: &str = "Enter the command that starts the bot (Type `q` to exit): ";
+pub const PROMPT_MOVE: &str = "Make a move (`q` to end round): ";
+pub const PROMPT_GRID_MOVE: &str = "Make a move as `row column` (`q` to end round): ";
+pub const PROMPT_QUBIC_MOVE: &str = "Make a move as `layer row column` (`q` to end round): ";
+pub const PROMPT_POPOUT_MOVE: &str = "Drop a disc with `column` or pop one out with `p column` (`q` to end round): ";
+pub const PROMPT_WILD_MOVE: &str = "Make a move as `x cell` or `o cell` (`q` to end round): ";
+pub const PROMPT_NOTAKTO_MOVE: &str = "Make a move as `board cell` (`q` to end round): ";
+pub const PROMPT_SLIDING_MOVE: &str = "Place a mark with `cell` or slide one with `from to` (`q` to end round): ";
+pub const PROMPT_ORDER_CHAOS_MOVE: &str = "Make a move as `x row column` or `o row column` (`q` to end round): ";
+pub const ORDER_TO_MOVE: &str = "You are Order, make five Xs or five Os in a row";
+pub const CHAOS_TO_MOVE: &str = "You are Chaos, fill the board without five in a row";
+pub const PROMPT_HEX_MOVE: &str = "Make a move as `column row`, like `c3` (`q` to end round): ";
+pub const PROMPT_HEX_SWAP_MOVE: &str = "Make a move as `column row`, like `c3`, or take the first stone with `swap` (`q` to end round): ";
+pub const HEX_TOP_TO_BOTTOM: &str = "You are connecting the top and bottom rows";
+pub const HEX_LEFT_TO_RIGHT: &str = "You are connecting the left and right columns";
+pub const PROMPT_PLAYER_SPRITE: &str = "Type in the new sprite, up to 3 characters or emoji (Type `q` to exunt)`: ";
+pub const PROMPT_PLAYER_COLOUR: &str = "Select a colour: \
+    \n`1` Red \n`2` Yellow \n`3` Blue \n`4` Green \n`5` Magenta \n`6` Cyan \n`q` to exit \
+    \nSelection: ";
+pub const PROMPT_TOURNAMENT_FILE: &str = "Enter the file the tournament is saved in, an old one is picked up \
+    where it was left (Type `q` to exit): ";
+pub const TOURNAMENT_FORMAT_SEL: &str = "Which format should the tournament be played in? \
+    \n`1` Round-Robin \n`2` Swiss \n`3` Single-Elimination \n`q` to exit \nSelection: ";
+pub const PROMPT_BEST_OF: &str = "How many games should each match be played over? (Type `q` to exit): ";
+pub const PROMPT_SERIES_LENGTH: &str = "How many games should the series be played over? (Type `q` to exit): ";
+pub const FIRST_MOVE_SEL: &str = "Who should move first in each game? \
+    \n`1` Take turns \n`2` The loser of the last game picks \n`3` Flip a coin \n`q` to exit \nSelection: ";
+pub const PROMPT_FIRST_PLAYER: &str = "Which player should move first? (Type `q` to move first yourself): ";
+pub const PROMPT_FAIR_AI: &str = "Would you like the Ai players to play at that difficulty? `y` or `n`: ";
 
-/// Returns a string that is used for game input
+/// A struct used to hold where the program reads the user's input from and
+/// where it writes its output to. Anything that reads lines and anything that
+/// can be written to can be used, so a game can be played from the terminal or
+/// driven by a script.
+#[derive(Debug)]
+pub struct Console<R, W> {
+    reader: R,
+    writer: W,
+    /// `colour` - \
+    ///  Whether the board should be drawn with escape codes for colour
+    pub colour: bool,
+}
+
+impl Console<io::StdinLock<'static>, io::Stdout> {
+    /// This constructor is used to make a console that reads from stdin and writes
+    /// to stdout. Colour is only turned on if stdout is a terminal and `NO_COLOR` 
+    /// is not set.
+    ///
+    /// # Returns
+    ///
+    /// * `Console` - \
+    ///    A Console struct for the terminal
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+    ///
+    /// let mut console = Console::stdio();
+    ///
+    /// console.println("Hello!");
+    /// ```
+    #[must_use]
+    pub fn stdio() -> Self {
+        Console {
+            reader: io::stdin().lock(),
+            writer: io::stdout(),
+            colour: colour_enabled(),
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> Console<R, W> {
+    /// This constructor is used to make a console out of any reader and writer.
+    /// Colour is turned off, so the output is plain text.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - \
+    ///    Where the user's input is read from one line at a time
+    /// * `writer` - \
+    ///    Where prompts and the board are written to
+    ///
+    /// # Returns
+    ///
+    /// * `Console` - \
+    ///    A Console struct wrapping the reader and writer
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::{Console, get_int_input};
+    ///
+    /// let mut console = Console::new(Cursor::new("5\n"), Vec::new());
+    ///
+    /// assert_eq!(get_int_input(&mut console, "Pick a number: "), Some(5));
+    /// assert_eq!(console.writer(), b"Pick a number: ");
+    /// ```
+    pub fn new(reader: R, writer: W) -> Self {
+        Console {
+            reader,
+            writer,
+            colour: false,
+        }
+    }
+
+    /// This function gives access to everything that has been written so far, 
+    /// which is mostly useful when the writer is a `Vec<u8>`.
+    ///
+    /// # Returns
+    ///
+    /// * `&W` - \
+    ///    A reference to the writer
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// This function writes a message without a newline, so it can be used 
+    /// for prompts.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - \
+    ///    Anything that can be printed with `{}`
+    ///
+    /// # Panics
+    ///
+    /// Will panic if there was a error writing the output
+    pub fn print(&mut self, message: impl fmt::Display) {
+        write!(self.writer, "{message}").expect("There was an issue writing output.");
+        self.writer.flush().expect("There was an issue writing output.");
+    }
+
+    /// This function writes a message followed by a newline.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - \
+    ///    Anything that can be printed with `{}`
+    ///
+    /// # Panics
+    ///
+    /// Will panic if there was a error writing the output
+    pub fn println(&mut self, message: impl fmt::Display) {
+        writeln!(self.writer, "{message}").expect("There was an issue writing output.");
+        self.writer.flush().expect("There was an issue writing output.");
+    }
+
+    // reads a single line, or `None` when there is nothing left to read
+    fn read_line(&mut self) -> Option<String> {
+        let mut user_input = String::new();
+
+        match self.reader.read_line(&mut user_input) {
+            Ok(0) => None,
+            Ok(_) => Some(user_input),
+            Err(error) => panic!("There was an issue getting user input: {error}"),
+        }
+    }
+}
+
+/// Returns a string that is used for game input. When there is no input left 
+/// (i.e. the end of a file or a closed terminal) the `ESCAPE_CHAR` is returned so 
+/// the user is sent back out of whatever they were doing.
 ///
 /// # Arguments
 ///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
 /// * `message` - \
 ///    A string slice that can be used to tell the user what to provide
 /// * `max_character_input` - \
@@ -36,7 +208,7 @@ pub const PLAY_AGAIN: &str = "Would you like to play again? `y` or `n`: ";
 /// # Returns
 ///                           
 /// * `String` - \
-///     A String made from user input that get extra character's (carriage return) trimmeed  
+///     A String made from user input that get extra character's (carriage return) trimmeed \
 ///     and made to be uppercase.
 ///
 /// # Panics
@@ -48,89 +220,131 @@ pub const PLAY_AGAIN: &str = "Would you like to play again? `y` or `n`: ";
 /// Basic Usage:
 ///
 /// ```
-/// let user_input = get_str_input("Please type `Hi`: ", 2);
+/// use std::io::Cursor;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::{Console, get_str_input};
+///
+/// let mut console = Console::new(Cursor::new("hi\n"), Vec::new());
+/// let user_input = get_str_input(&mut console, "Please type `Hi`: ", 2);
 ///
-/// println!("{user_input}");
+/// assert_eq!(user_input, "HI");
 /// ```
-pub fn get_str_input(message: &str, max_character_input: usize) -> String {
-    let mut user_input = String::new();
-
-    while user_input.trim().chars().count() != max_character_input {
-        eprint!("{message}");
+pub fn get_str_input<R: BufRead, W: Write>(
+    console: &mut Console<R, W>, 
+    message: &str, 
+    max_character_input: usize,
+) -> String {
+    loop {
+        console.print(message);
 
-        io::stdin()
-            .read_line(&mut user_input)
-            .expect("There was an issue getting your name");
+        let Some(user_input) = console.read_line() else {
+            return String::from(ESCAPE_CHAR);
+        };
 
         if user_input.trim().to_uppercase().as_str() == ESCAPE_CHAR {
             return user_input.trim().to_uppercase()
         }
 
-        if user_input.trim().chars().count() < max_character_input || 
-            user_input.trim().chars().count() > max_character_input 
-        {
-            eprintln!("Error: You need to enter {max_character_input} character(s)");
-
-            user_input.clear(); // try again
+        if user_input.trim().chars().count() == max_character_input {
+            return user_input.trim().to_uppercase();
         }
+
+        console.println(format!("Error: You need to enter {max_character_input} character(s)"));
     }
+}
 
-    user_input.trim().to_uppercase()
+/// Returns a whole line of the user's input with the spaces around it trimmed
+/// but its case kept, for things like commands and file paths. If the user gives
+/// the `ESCAPE_CHAR`, or there is no input left to read, `None` is returned.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `message` -  \
+///    A string slice that can be used to tell the user what to provide
+///
+/// # Returns
+///                           
+/// * `Option<String>` - \
+///     An Option enum that will return the line or `None` if the user
+///     wanted to leave
+///
+/// # Panics
+///
+/// Will panic if there was a error getting keyboard input
+///
+/// # Examples 
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::Cursor;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::{Console, get_line_input};
+///
+/// let mut console = Console::new(Cursor::new("./my_bot --fast\nq\n"), Vec::new());
+///
+/// assert_eq!(get_line_input(&mut console, "Command: "), Some(String::from("./my_bot --fast")));
+/// assert_eq!(get_line_input(&mut console, "Command: "), None);
+/// ```
+pub fn get_line_input<R: BufRead, W: Write>(console: &mut Console<R, W>, message: &str) -> Option<String> {
+    console.print(message);
+
+    let user_input = console.read_line()?;
+
+    if user_input.trim().to_uppercase() == ESCAPE_CHAR {
+        return None;
+    }
+
+    Some(user_input.trim().to_string())
 }
 
-/// Returns an usigned integer, but will panic if, for whatever reason, 
-/// there is an error in unwrapping the user's input to an unsigned integer
+/// Returns an usigned integer from the user's input. If the user gives the
+/// `ESCAPE_CHAR`, or there is no input left to read, `None` is returned.
 ///
 /// # Arguments
 ///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
 /// * `message` -  \
 ///    A string slice that can be used to tell the user what to provide
 ///
 /// # Returns
 ///                           
 /// * `Option<usize>` - \
-///     An Option enum that will return 
+///     An Option enum that will return the integer or `None` if the user
+///     wanted to leave
 ///
 /// # Panics
 ///
-/// Will panic if there was a error getting keyboard input or unwrapping the integer
+/// Will panic if there was a error getting keyboard input
 ///
 /// # Examples 
 ///
 /// Basic Usage:
 ///
 /// ```
-/// let user_int = get_int_input("Please type an integer: ");
+/// use std::io::Cursor;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::{Console, get_int_input};
 ///
-/// println!("{user_int}");
+/// let mut console = Console::new(Cursor::new("abc\n12\n"), Vec::new());
+///
+/// assert_eq!(get_int_input(&mut console, "Please type an integer: "), Some(12));
+/// assert_eq!(get_int_input(&mut console, "Please type an integer: "), None);
 /// ```
-pub fn get_int_input(message: &str) -> Option<usize> {
-    let mut user_input = String::new();
-    let mut user_int: Option<usize> = None;
-
+pub fn get_int_input<R: BufRead, W: Write>(console: &mut Console<R, W>, message: &str) -> Option<usize> {
     loop {
-        eprint!("{message}");
+        console.print(message);
 
-        io::stdin()
-            .read_line(&mut user_input)
-            .expect("There was an issue getting user input.");
+        let user_input = console.read_line()?;
 
         if user_input.trim().to_uppercase() == ESCAPE_CHAR {
-            break;
+            return None;
         }
 
-        let filter = user_input.trim().parse::<usize>();
-
-        if let Ok(val) = filter {
-            user_int = Some(val);
-
-           break;
-        } else {
-            eprintln!("There was an error getting your input. Try again");    
-             
-            user_input.clear();
+        if let Ok(val) = user_input.trim().parse::<usize>() {
+            return Some(val);
         }
-    }
 
-    user_int
-}
\ No newline at end of file
+        console.println("There was an error getting your input. Try again");    
+    }
+}
@@ -0,0 +1,176 @@
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use rand::rngs::StdRng;
+use super::cmdln_interface::{get_int_input, get_line_input, Console};
+use super::cmdln_interface::{PROMPT_TOURNAMENT_FILE, TOURNAMENT_FORMAT_SEL, PROMPT_BEST_OF};
+use super::cmdln_app::play_round;
+use super::game::{Game, State};
+use super::player::List;
+use super::tournament::{Format, Tournament};
+
+/// This function asks the user where a tournament is saved, and either picks it
+/// up from there or sets up a new one between the players in the list on the
+/// game that is set up, before playing it.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `game` - \
+///    The game a new tournament is played on
+/// * `player_list` - \
+///    The players entered into a new tournament, seeded in the order they are
+///    listed
+/// * `rng` - \
+///    The random number generator used by the `Ai` players
+///
+/// # Errors
+///
+/// Will return an error if the tournament could not be read or saved.
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::Cursor;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use tic_tac_toe_and_connect_4::{Game, List, Player, Sprite};
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_tournament::tournament_menu;
+///
+/// let path = std::env::temp_dir().join("menu_tournament.json");
+/// let _ = std::fs::remove_file(&path);
+/// let input = format!("{path}\n3\n1\n", path = path.display());
+/// let mut console = Console::new(Cursor::new(input), Vec::new());
+/// let player_list = List::new(vec![Player::ai(Sprite::new("X")), Player::ai(Sprite::new("O"))]);
+///
+/// tournament_menu(&mut console, &Game::tic_tac_toe(), &player_list, &mut StdRng::seed_from_u64(1)).unwrap();
+///
+/// assert!(String::from_utf8_lossy(console.writer()).contains("wins the tournament"));
+/// ```
+pub fn tournament_menu<R: BufRead, W: Write>(
+    console: &mut Console<R, W>,
+    game: &Game,
+    player_list: &List,
+    rng: &mut StdRng,
+) -> io::Result<()> {
+    let Some(file) = get_line_input(console, PROMPT_TOURNAMENT_FILE).filter(|file| !file.is_empty()) else {
+        return Ok(());
+    };
+    let path = Path::new(&file);
+
+    let tournament = if path.exists() {
+        Tournament::load(path)?
+    } else {
+        let format = loop {
+            match get_int_input(console, TOURNAMENT_FORMAT_SEL) {
+                None => return Ok(()),
+                Some(val) if (1..=Format::ALL.len()).contains(&val) => break Format::ALL[val - 1],
+                Some(_) => console.println("Invalid selection"),
+            }
+        };
+        let best_of = loop {
+            match get_int_input(console, PROMPT_BEST_OF) {
+                None => return Ok(()),
+                Some(val @ 1..) => break val,
+                Some(_) => console.println("A match needs at least one game"),
+            }
+        };
+        let name = path.file_stem().map_or_else(|| file.clone(), |stem| stem.to_string_lossy().to_string());
+        let mut entrants = player_list.players.clone();
+
+        for entrant in &mut entrants {
+            entrant.reset();
+        }
+
+        Tournament::new(name, format, game.clone(), entrants, best_of)
+    };
+
+    run_tournament(console, path, tournament, rng)
+}
+
+/// This function plays a tournament's matches in order, one game at a time,
+/// saving it after every game. If a human leaves a game part of the way through,
+/// it stops, and the game is played again from the start when the tournament is
+/// picked up. The standings are shown before every round and at the end.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `path` - \
+///    The file the tournament is saved in
+/// * `tournament` - \
+///    The tournament to play
+/// * `rng` - \
+///    The random number generator used by the `Ai` players
+///
+/// # Errors
+///
+/// Will return an error if the tournament could not be saved.
+pub fn run_tournament<R: BufRead, W: Write>(
+    console: &mut Console<R, W>,
+    path: &Path,
+    mut tournament: Tournament,
+    rng: &mut StdRng,
+) -> io::Result<()> {
+    let mut round = 0;
+
+    tournament.save(path)?;
+
+    while let Some(index) = tournament.next_match() {
+        if tournament.matches[index].round != round {
+            round = tournament.matches[index].round;
+
+            console.println(&tournament);
+            console.println(format!("Round {round} of {rounds}", rounds = tournament.num_of_rounds()));
+
+            for bye in tournament.matches.iter().filter(|played| played.round == round && played.entrants.1.is_none()) {
+                console.println(format!("{name} has a bye", name = tournament.entrants[bye.entrants.0].name));
+            }
+        }
+
+        let Some(mut session) = tournament.session_for(index) else {
+            break;
+        };
+
+        console.println(format!("{first} vs {second}: Game {game} of {best_of}",
+            first = session.player_list.players[0].name, second = session.player_list.players[1].name,
+            game = tournament.matches[index].games.len() + 1, best_of = tournament.matches[index].best_of));
+
+        play_round(console, &mut session, rng);
+
+        if session.result() == State::NotOver {
+            console.println(format!("The tournament is saved in {path}", path = path.display()));
+
+            return Ok(());
+        }
+
+        console.println(session.game.coloured(&session.player_list, console.colour));
+
+        tournament.record_game(index, session.winner().map(|_| session.current_player_number()));
+        tournament.save(path)?;
+
+        let played = &tournament.matches[index];
+
+        if let (true, (first, Some(second))) = (played.is_over(), played.entrants) {
+            let score = format!("{first_wins}-{second_wins}", first_wins = played.wins(first), second_wins = played.wins(second));
+
+            match played.winner() {
+                Some(winner) => console.println(format!("{name} wins the match {score}",
+                    name = tournament.entrants[winner].name)),
+                None => console.println(format!("The match is drawn {score}")),
+            }
+        }
+    }
+
+    console.println(&tournament);
+
+    if let Some(champion) = tournament.standings().first() {
+        console.println(format!("{name} wins the tournament!", name = tournament.entrants[champion.entrant].name));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,733 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::thread;
+use std::time::Duration;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde_json::{json, Value};
+use tungstenite::{Message, WebSocket};
+use tungstenite::handshake::derive_accept_key;
+use tungstenite::protocol::Role;
+use super::cmdln_interface::Console;
+use super::cmdln_args::{parse_args, Command};
+use super::player::{ControlMode, Player};
+use super::ai_engine::{think, Difficulty};
+use super::game::{Mode, State, MoveStatus};
+use super::session::Session;
+
+// the options a new game can be created with, as they are named in the JSON
+// body and on the command line, besides each player's options
+const GAME_OPTIONS: [&str; 7] = [
+    "mode",
+    "width",
+    "height",
+    "win_length",
+    "rules",
+    "players",
+    "ai_difficulty",
+];
+// the options every player has, as in `p1_name`
+const PLAYER_OPTIONS: [&str; 3] = ["name", "sprite", "control"];
+
+// how long a live connection waits for a message before checking for new events
+const LIVE_POLL: Duration = Duration::from_millis(50);
+// how long a client has to send each part of its request before it is dropped
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+// the largest body a request can have, far more than any game's options
+const MAX_BODY_LENGTH: usize = 64 * 1024;
+
+/// A struct used to keep every game being played over HTTP in memory, each one
+/// under its own ID. It answers the API's requests without knowing anything about
+/// sockets, so it can be used (and tested) on its own. Anyone subscribed to a game
+/// is sent an event every time it changes.
+#[derive(Debug)]
+pub struct GameStore {
+    sessions: HashMap<usize, Session>,
+    subscribers: HashMap<usize, Vec<Sender<Value>>>,
+    next_id: usize,
+    rng: StdRng,
+}
+
+impl GameStore {
+    /// This constructor is used to make an empty store.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - \
+    ///    A seed for the `Ai` players' random number generator, or `None` to get
+    ///    a random one
+    ///
+    /// # Returns
+    ///
+    /// * `GameStore` - \
+    ///    A store with no games in it
+    #[must_use]
+    pub fn new(seed: Option<u64>) -> GameStore {
+        GameStore {
+            sessions: HashMap::new(),
+            subscribers: HashMap::new(),
+            next_id: 1,
+            rng: seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64),
+        }
+    }
+
+    /// This function answers one request to the API. Every reply is a JSON object,
+    /// and anything that went wrong is given as `{"error": "..."}`.
+    ///
+    /// | Request                          | What it does                                   |
+    /// |----------------------------------|------------------------------------------------|
+    /// | `GET /games`                     | Lists the IDs of every game                    |
+    /// | `POST /games`                    | Creates a game and gives its state             |
+    /// | `GET /games/{id}`                | Gives the game's state                         |
+    /// | `DELETE /games/{id}`             | Removes the game                               |
+    /// | `GET /games/{id}/moves`          | Lists the legal moves for the current player   |
+    /// | `POST /games/{id}/moves`         | Plays `{"move": N}` for the current human      |
+    /// | `POST /games/{id}/ai-move`       | Has the `Ai` pick and play the current move    |
+    /// | `POST /games/{id}/next-round`    | Clears the board for a new round               |
+    ///
+    /// A new game takes the same options as the command line, with `_` in place
+    /// of `-`: `mode`, `width`, `height`, `win_length`, `rules`, `players`, `ai_difficulty`
+    /// and, for each player, options like `p1_name`, `p3_sprite` and `p2_control`.
+    /// An `ai-move` for a human player can be given a `difficulty`.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - \
+    ///    The HTTP method, like `GET` or `POST`
+    /// * `path` - \
+    ///    The path that was requested
+    /// * `body` - \
+    ///    The body of the request, which should be JSON or empty
+    ///
+    /// # Returns
+    ///
+    /// * `(u16, Value)` - \
+    ///    The HTTP status code and the JSON to reply with
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::ui_lib::http_api::GameStore;
+    ///
+    /// let mut store = GameStore::new(Some(1));
+    ///
+    /// let (status, game) = store.respond("POST", "/games", r#"{"mode": "connect-4", "width": 7}"#);
+    /// assert_eq!(status, 201);
+    /// assert_eq!(game["width"], 7);
+    ///
+    /// let (status, game) = store.respond("POST", "/games/1/moves", r#"{"move": 4}"#);
+    /// assert_eq!(status, 200);
+    /// assert_eq!(game["current_player"], "HAL");
+    ///
+    /// let (status, _) = store.respond("POST", "/games/1/moves", r#"{"move": 4}"#);
+    /// assert_eq!(status, 409);
+    ///
+    /// let (status, game) = store.respond("POST", "/games/1/ai-move", "");
+    /// assert_eq!(status, 200);
+    /// assert_eq!(game["current_player"], "P1");
+    /// ```
+    pub fn respond(&mut self, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let body = if body.trim().is_empty() {
+            Ok(json!({}))
+        } else {
+            serde_json::from_str::<Value>(body)
+        };
+        let Ok(body) = body else {
+            return error(400, "The body must be JSON");
+        };
+        let path: Vec<&str> = path
+            .split('?')
+            .next()
+            .unwrap_or("")
+            .split('/')
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        match (method, path.as_slice()) {
+            ("GET", ["games"]) => {
+                let mut ids: Vec<usize> = self.sessions.keys().copied().collect();
+                ids.sort_unstable();
+
+                (200, json!({ "games": ids }))
+            }
+            ("POST", ["games"]) => self.create_game(&body),
+            (_, ["games", id, rest @ ..]) => {
+                let Ok(id) = id.parse::<usize>() else {
+                    return error(404, "Game IDs are whole numbers");
+                };
+
+                if !self.sessions.contains_key(&id) {
+                    return error(404, "There is no game with that ID");
+                }
+
+                match (method, rest) {
+                    ("GET", []) => (200, game_json(id, &self.sessions[&id])),
+                    ("DELETE", []) => {
+                        self.publish(id, &json!({ "event": "deleted", "id": id }));
+                        self.sessions.remove(&id);
+                        self.subscribers.remove(&id);
+
+                        (200, json!({ "deleted": id }))
+                    }
+                    ("GET", ["moves"]) => (200, json!({ "moves": self.sessions[&id].legal_moves() })),
+                    ("POST", ["moves"]) => self.submit_move(id, &body),
+                    ("POST", ["ai-move"]) => self.ai_move(id, &body),
+                    ("POST", ["next-round"]) => self.next_round(id),
+                    _ => error(404, "Unknown request"),
+                }
+            }
+            _ => error(404, "Unknown request"),
+        }
+    }
+
+    // makes a new game from the options in the body
+    fn create_game(&mut self, body: &Value) -> (u16, Value) {
+        let Some(options) = body.as_object() else {
+            return error(400, "The body must be a JSON object");
+        };
+        let mut args = vec![String::from("--mode"), String::from("tic-tac-toe")];
+
+        for (key, value) in options {
+            if !is_game_option(key) {
+                return error(400, &format!("Unknown option `{key}`"));
+            }
+
+            let value = match value {
+                Value::String(text) => text.clone(),
+                Value::Number(number) => number.to_string(),
+                _ => return error(400, &format!("`{key}` must be a string or a number")),
+            };
+
+            args.push(format!("--{option}", option = key.replace('_', "-")));
+            args.push(value);
+        }
+
+        match parse_args(&args) {
+            Ok(Command::Play(settings)) => {
+                let id = self.next_id;
+
+                self.next_id += 1;
+                self.sessions.insert(id, Session::new(settings.game, settings.player_list));
+
+                (201, game_json(id, &self.sessions[&id]))
+            }
+            Ok(_) => error(400, "Those options can't make a game"),
+            Err(message) => error(400, &message),
+        }
+    }
+
+    /// This function is used to follow a game as it is played. Every time the game
+    /// changes, an event is sent down the channel:
+    ///
+    /// * `{"event": "move", "player": NAME, "move": N, "game": STATE}` - \
+    ///    A player made a move
+    /// * `{"event": "round_over", "winner": NAME or null, "game": STATE}` - \
+    ///    The round was won or tied
+    /// * `{"event": "new_round", "game": STATE}` - \
+    ///    The board was cleared for a new round
+    /// * `{"event": "deleted", "id": ID}` - \
+    ///    The game was removed, and no more events will be sent
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - \
+    ///    The ID of the game to follow
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Receiver<Value>)` - \
+    ///    The end of the channel the game's events are sent to
+    /// * `None` - \
+    ///    There is no game with that ID
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::ui_lib::http_api::GameStore;
+    ///
+    /// let mut store = GameStore::new(Some(1));
+    /// let _ = store.respond("POST", "/games", "");
+    /// let events = store.subscribe(1).unwrap();
+    ///
+    /// let _ = store.respond("POST", "/games/1/moves", r#"{"move": 5}"#);
+    ///
+    /// let event = events.try_recv().unwrap();
+    /// assert_eq!(event["event"], "move");
+    /// assert_eq!(event["move"], 5);
+    /// ```
+    pub fn subscribe(&mut self, id: usize) -> Option<Receiver<Value>> {
+        if !self.sessions.contains_key(&id) {
+            return None;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.entry(id).or_default().push(sender);
+
+        Some(receiver)
+    }
+
+    /// This function plays a move for one of the players, but only when it is
+    /// their turn. It is used by players connected to a game live, so they can't
+    /// move for their opponent.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - \
+    ///    The ID of the game
+    /// * `player_number` - \
+    ///    `1` for player 1 or `2` for player 2
+    /// * `selection` - \
+    ///    The cell (or column for Connect-4) the player picked
+    ///
+    /// # Returns
+    ///
+    /// * `(u16, Value)` - \
+    ///    The HTTP status code and the JSON to reply with, the same as `respond`
+    ///
+    /// # Examples
+    ///
+    /// Basic Usage:
+    ///
+    /// ```
+    /// use tic_tac_toe_and_connect_4::ui_lib::http_api::GameStore;
+    ///
+    /// let mut store = GameStore::new(Some(1));
+    /// let _ = store.respond("POST", "/games", r#"{"p2_control": "human"}"#);
+    ///
+    /// assert_eq!(store.play_as(1, 2, 5).0, 409);
+    /// assert_eq!(store.play_as(1, 1, 5).0, 200);
+    /// assert_eq!(store.play_as(1, 2, 1).0, 200);
+    /// ```
+    pub fn play_as(&mut self, id: usize, player_number: usize, selection: usize) -> (u16, Value) {
+        let Some(session) = self.sessions.get(&id) else {
+            return error(404, "There is no game with that ID");
+        };
+
+        if session.current_player_number() != player_number {
+            return error(409, "It is not your turn");
+        }
+
+        self.submit_move(id, &json!({ "move": selection }))
+    }
+
+    // plays a human player's move
+    fn submit_move(&mut self, id: usize, body: &Value) -> (u16, Value) {
+        let Some(session) = self.sessions.get(&id) else {
+            return error(404, "There is no game with that ID");
+        };
+        let Some(selection) = body["move"].as_u64().and_then(|val| usize::try_from(val).ok()) else {
+            return error(400, "The body needs a `move` that is a whole number");
+        };
+
+        if session.whose_turn().control != ControlMode::Human {
+            return error(409, "It is an Ai player's turn, ask for an `ai-move`");
+        }
+
+        self.play(id, selection)
+    }
+
+    // has the Ai pick the current player's move and plays it
+    fn ai_move(&mut self, id: usize, body: &Value) -> (u16, Value) {
+        let Some(session) = self.sessions.get(&id) else {
+            return error(404, "There is no game with that ID");
+        };
+
+        if session.result() != State::NotOver {
+            return error(409, "The round is already over");
+        }
+
+        let difficulty = match body["difficulty"].as_str().map(str::to_lowercase).as_deref() {
+            Some("easy") => Some(Difficulty::Easy),
+            Some("medium") => Some(Difficulty::Medium),
+            Some("hard") => Some(Difficulty::Hard),
+            Some(_) => return error(400, "`difficulty` must be `easy`, `medium` or `hard`"),
+            None => None,
+        };
+        let selection = match (difficulty, &session.whose_turn().control) {
+            (None, ControlMode::Ai(_) | ControlMode::Bot(_)) => self
+                .sessions
+                .get_mut(&id)
+                .and_then(|session| session.ai_move(&mut self.rng)),
+            (difficulty, _) => Some(think(
+                &session.game,
+                session.current_player_number(),
+                &session.opponent_numbers(),
+                difficulty.unwrap_or_default(),
+                &mut self.rng,
+            )),
+        };
+        let Some(selection) = selection else {
+            return error(409, "The round is already over");
+        };
+        let (status, mut state) = self.play(id, selection);
+
+        if status == 200 {
+            state["move"] = json!(selection);
+        }
+
+        (status, state)
+    }
+
+    // plays a move for the current player and tells the subscribers about it
+    fn play(&mut self, id: usize, selection: usize) -> (u16, Value) {
+        let Some(session) = self.sessions.get_mut(&id) else {
+            return error(404, "There is no game with that ID");
+        };
+        let name = session.whose_turn().name.clone();
+
+        if let MoveStatus::Invalid(message) = session.submit_move(selection) {
+            return error(409, message);
+        }
+
+        let state = game_json(id, session);
+        let winner = session.winner().map(|winner| winner.name.clone());
+        let over = session.result() != State::NotOver;
+
+        self.publish(id, &json!({ "event": "move", "player": name, "move": selection, "game": state }));
+
+        if over {
+            self.publish(id, &json!({ "event": "round_over", "winner": winner, "game": state }));
+        }
+
+        (200, state)
+    }
+
+    // clears the board for a new round
+    fn next_round(&mut self, id: usize) -> (u16, Value) {
+        let Some(session) = self.sessions.get_mut(&id) else {
+            return error(404, "There is no game with that ID");
+        };
+
+        session.next_round();
+
+        let state = game_json(id, session);
+        self.publish(id, &json!({ "event": "new_round", "game": state }));
+
+        (200, state)
+    }
+
+    // sends an event to everyone following a game, forgetting anyone who left
+    fn publish(&mut self, id: usize, event: &Value) {
+        if let Some(subscribers) = self.subscribers.get_mut(&id) {
+            subscribers.retain(|subscriber| subscriber.send(event.clone()).is_ok());
+        }
+    }
+}
+
+/// This function serves the HTTP API until the program is closed. Each connection
+/// is answered on its own thread, and the games are shared between all of them.
+///
+/// A game can also be followed live over a WebSocket, which is sent every event
+/// from `GameStore::subscribe` as JSON text. `/games/{id}/watch` is for spectators,
+/// who can only watch. `/games/{id}/play/{n}` is for player `n`, who can also send
+/// `{"move": N}` to make their moves. Anything that can't be played is answered
+/// with `{"error": "..."}`.
+///
+/// A request has ten seconds to send each of its lines and its body, and a body
+/// of more than 64 KiB is turned away with `413` without being read.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct the server logs what is happening to
+/// * `listener` - \
+///    A `TcpListener` that is already bound to the address to serve on
+/// * `store` - \
+///    The `GameStore` holding the games, usually an empty one
+///
+/// # Errors
+///
+/// Will return an error if the listener's address could not be read.
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::{Cursor, Read, Write};
+/// use std::net::{TcpListener, TcpStream};
+/// use std::thread;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+/// use tic_tac_toe_and_connect_4::ui_lib::http_api::{serve_http, GameStore};
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+/// let address = listener.local_addr().unwrap();
+///
+/// thread::spawn(move || {
+///     serve_http(&mut Console::new(Cursor::new(""), Vec::new()), &listener, GameStore::new(None))
+/// });
+///
+/// let mut stream = TcpStream::connect(address).unwrap();
+/// write!(stream, "POST /games HTTP/1.1\r\nContent-Length: 2\r\n\r\n{{}}").unwrap();
+///
+/// let mut reply = String::new();
+/// stream.read_to_string(&mut reply).unwrap();
+///
+/// assert!(reply.starts_with("HTTP/1.1 201 Created"));
+/// assert!(reply.contains(r#""id":1"#));
+///
+/// // a body too big to hold is never read
+/// let mut stream = TcpStream::connect(address).unwrap();
+/// write!(stream, "POST /games HTTP/1.1\r\nContent-Length: 99999999999\r\n\r\n").unwrap();
+///
+/// let mut reply = String::new();
+/// stream.read_to_string(&mut reply).unwrap();
+///
+/// assert!(reply.starts_with("HTTP/1.1 413 Payload Too Large"));
+///
+/// // spectators can watch but can't make moves
+/// let (mut spectator, _) = tungstenite::connect(format!("ws://{address}/games/1/watch")).unwrap();
+/// spectator.send(tungstenite::Message::text(r#"{"move": 5}"#)).unwrap();
+///
+/// assert!(spectator.read().unwrap().to_text().unwrap().contains("joined"));
+/// assert!(spectator.read().unwrap().to_text().unwrap().contains("Spectators can't make moves"));
+/// ```
+pub fn serve_http<R: BufRead, W: Write>(
+    console: &mut Console<R, W>,
+    listener: &TcpListener,
+    store: GameStore,
+) -> io::Result<()> {
+    let store = Arc::new(Mutex::new(store));
+
+    console.println(format!("Serving the HTTP API on http://{address}", address = listener.local_addr()?));
+
+    for stream in listener.incoming().flatten() {
+        let store = Arc::clone(&store);
+
+        thread::spawn(move || {
+            // a client that hangs up early has nothing left to be told
+            let _ = answer(&store, stream);
+        });
+    }
+
+    Ok(())
+}
+
+// reads one HTTP request from the stream and writes the store's reply to it
+fn answer(store: &Mutex<GameStore>, mut stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    let mut content_length = 0;
+    let mut websocket_key = None;
+
+    reader.read_line(&mut request_line)?;
+
+    loop {
+        let mut header = String::new();
+
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            } else if name.trim().eq_ignore_ascii_case("sec-websocket-key") {
+                websocket_key = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("");
+
+    if let Some(key) = websocket_key {
+        let path: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+        let (id, player_number) = match path.as_slice() {
+            ["games", id, "watch"] => (id.parse().ok(), None),
+            ["games", id, "play", number] => match number.parse::<usize>() {
+                Ok(number) => (id.parse().ok(), Some(number)),
+                Err(_) => (None, None),
+            },
+            _ => (None, None),
+        };
+        let events = id.and_then(|id| store.lock().unwrap_or_else(PoisonError::into_inner).subscribe(id));
+
+        if let (Some(id), Some(events)) = (id, events) {
+            return go_live(store, stream, &key, id, player_number, &events);
+        }
+    }
+
+    let (status, reply) = if content_length > MAX_BODY_LENGTH {
+        error(413, &format!("The body can be at most {MAX_BODY_LENGTH} bytes"))
+    } else {
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+
+        store
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .respond(method, path, &String::from_utf8_lossy(&body))
+    };
+    let reply = reply.to_string();
+    let reason = match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        404 => "Not Found",
+        413 => "Payload Too Large",
+        _ => "Conflict",
+    };
+
+    write!(stream, "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\n\
+        Content-Length: {length}\r\nConnection: close\r\n\r\n{reply}", length = reply.len())?;
+    stream.flush()
+}
+
+// upgrades the connection to a WebSocket and passes it the game's events until
+// either side closes it
+fn go_live(
+    store: &Mutex<GameStore>,
+    mut stream: TcpStream,
+    key: &str,
+    id: usize,
+    player_number: Option<usize>,
+    events: &Receiver<Value>,
+) -> io::Result<()> {
+    write!(stream, "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+        Sec-WebSocket-Accept: {accept}\r\n\r\n", accept = derive_accept_key(key.as_bytes()))?;
+    stream.set_read_timeout(Some(LIVE_POLL))?;
+
+    let mut socket = WebSocket::from_raw_socket(stream, Role::Server, None);
+    let role = player_number.map_or_else(|| String::from("spectator"), |number| format!("player {number}"));
+    let (_, state) = store.lock().unwrap_or_else(PoisonError::into_inner).respond("GET", &format!("/games/{id}"), "");
+
+    send_live(&mut socket, &json!({ "event": "joined", "role": role, "game": state }))?;
+
+    loop {
+        match socket.read() {
+            Ok(Message::Text(text)) => {
+                let reply = match player_number {
+                    None => error(403, "Spectators can't make moves"),
+                    Some(number) => live_move(store, id, number, text.as_str()),
+                };
+
+                // a move that was played reaches everyone as an event
+                if reply.0 != 200 {
+                    send_live(&mut socket, &reply.1)?;
+                }
+            }
+            Ok(Message::Close(_)) | Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => {
+                return Ok(());
+            }
+            Ok(_) => (),
+            Err(tungstenite::Error::Io(error))
+                if matches!(error.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => (),
+            Err(error) => return Err(io::Error::other(error)),
+        }
+
+        loop {
+            match events.try_recv() {
+                Ok(event) => send_live(&mut socket, &event)?,
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    return socket.close(None).map_err(io::Error::other);
+                }
+            }
+        }
+    }
+}
+
+// plays a move sent by a player over a WebSocket
+fn live_move(store: &Mutex<GameStore>, id: usize, player_number: usize, text: &str) -> (u16, Value) {
+    let Ok(body) = serde_json::from_str::<Value>(text) else {
+        return error(400, "Moves must be JSON");
+    };
+    let Some(selection) = body["move"].as_u64().and_then(|val| usize::try_from(val).ok()) else {
+        return error(400, "Send a `move` that is a whole number");
+    };
+
+    store
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .play_as(id, player_number, selection)
+}
+
+// sends one event down a WebSocket
+fn send_live(socket: &mut WebSocket<TcpStream>, event: &Value) -> io::Result<()> {
+    socket.send(Message::text(event.to_string())).map_err(io::Error::other)
+}
+
+// the JSON for a game and how it stands
+fn game_json(id: usize, session: &Session) -> Value {
+    let game = &session.game;
+    let board: Vec<Value> = game
+        .board
+        .iter()
+        .map(|cell| cell.map_or(Value::Null, |piece| json!(session.player_list.glyph(&piece))))
+        .collect();
+    let state = match session.result() {
+        State::NotOver => "not_over",
+        // when the player who moved last lost, the next player won
+        State::Won | State::Lost => "won",
+        State::Tie => "tie",
+    };
+
+    json!({
+        "id": id,
+        "mode": game.current_mode.to_string(),
+        "width": game.width,
+        "height": game.height,
+        "depth": game.depth,
+        "win_length": game.win_length,
+        "rules": game.rules.to_string(),
+        "board": board,
+        "turns": game.num_of_turns,
+        "state": state,
+        "current_player": session.whose_turn().name,
+        "winner": session.winner().map(|winner| winner.name.clone()),
+        "players": session.player_list.players
+            .iter()
+            .map(|player| player_json(player, session.game.current_mode))
+            .collect::<Vec<Value>>(),
+    })
+}
+
+// checks a key in a new game's body is one of its options, bots can't be started
+// this way since their command would be run on the server
+fn is_game_option(key: &str) -> bool {
+    let player_option = key
+        .strip_prefix('p')
+        .and_then(|rest| rest.split_once('_'))
+        .is_some_and(|(number, option)| {
+            number.parse::<usize>().is_ok_and(|number| number > 0) && PLAYER_OPTIONS.contains(&option)
+        });
+
+    player_option || GAME_OPTIONS.contains(&key)
+}
+
+// the JSON for one player, with their record and rating in the game's mode
+fn player_json(player: &Player, mode: Mode) -> Value {
+    let control = match player.control {
+        ControlMode::Human => String::from("human"),
+        ControlMode::Ai(difficulty) => format!("ai ({difficulty})").to_lowercase(),
+        ControlMode::Bot(_) => String::from("bot"),
+    };
+
+    json!({
+        "name": player.name,
+        "sprite": player.sprite.0,
+        "colour": player.colour.to_string(),
+        "control": control,
+        "wins": player.record(mode).wins,
+        "losses": player.record(mode).losses,
+        "ties": player.record(mode).ties,
+        "games_played": player.record(mode).games_played(),
+        "rating": player.rating(mode).round(),
+    })
+}
+
+// an error reply
+fn error(status: u16, message: &str) -> (u16, Value) {
+    (status, json!({ "error": message }))
+}
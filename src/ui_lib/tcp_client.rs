@@ -0,0 +1,114 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use super::cmdln_interface::{get_line_input, Console};
+use super::cmdln_interface::PROMPT_PLAYER_NAME;
+
+/// This function joins a match hosted with `serve` and plays it from this
+/// terminal. The user is asked for their name, then the board is drawn every
+/// turn and they are asked for a move whenever it is theirs, typed the same
+/// way as in a local game. Typing `q` leaves the match.
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct used to prompt the user and read their input
+/// * `stream` - \
+///    A `TcpStream` that is connected to the server
+///
+/// # Errors
+///
+/// Will return an error if the server could not be read from or written to.
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::Cursor;
+/// use std::net::{TcpListener, TcpStream};
+/// use std::thread;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_args::{parse_args, Command};
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+/// use tic_tac_toe_and_connect_4::ui_lib::tcp_client::connect;
+/// use tic_tac_toe_and_connect_4::ui_lib::tcp_server::serve;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+/// let address = listener.local_addr().unwrap();
+/// let Ok(Command::Play(settings)) = parse_args(&[String::from("--seed"), String::from("1")]) else {
+///     panic!("expected a game to be set up");
+/// };
+///
+/// let server = thread::spawn(move || {
+///     serve(&mut Console::new(Cursor::new(""), Vec::new()), &listener, *settings)
+/// });
+///
+/// // join as `Bob` and leave on the first move
+/// let mut console = Console::new(Cursor::new("Bob\nq\n"), Vec::new());
+///
+/// connect(&mut console, TcpStream::connect(address).unwrap()).unwrap();
+/// server.join().unwrap().unwrap();
+///
+/// assert!(String::from_utf8_lossy(console.writer()).contains("You are player 1"));
+/// ```
+pub fn connect<R: BufRead, W: Write>(console: &mut Console<R, W>, stream: TcpStream) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    match get_line_input(console, PROMPT_PLAYER_NAME) {
+        Some(name) => writeln!(writer, "JOIN {name}")?,
+        None => writeln!(writer, "JOIN")?,
+    }
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 {
+            console.println("The server closed the connection");
+
+            return Ok(());
+        }
+
+        let line = line.trim_end();
+        let (command, value) = line.split_once(' ').unwrap_or((line, ""));
+
+        match command {
+            "WELCOME" => {
+                let (number, sprite) = value.split_once(' ').unwrap_or((value, ""));
+
+                console.println(format!("You are player {number}, playing as `{sprite}`"));
+            }
+            "WAITING" => console.println("Waiting for the other player to join..."),
+            "START" => {
+                let (round, rounds) = value.split_once(' ').unwrap_or((value, ""));
+
+                console.println(format!("Round {round} of {rounds}"));
+            }
+            "BOARD" => {
+                for _ in 0..value.parse::<usize>().unwrap_or(0) {
+                    let mut board_line = String::new();
+
+                    reader.read_line(&mut board_line)?;
+                    console.print(board_line);
+                }
+            }
+            "TURN" => console.println(format!("Current Player: {value}")),
+            "YOUR_MOVE" => {
+                // the move is sent as it is typed and read by the server
+                if let Some(val) = get_line_input(console, value) {
+                    writeln!(writer, "MOVE {val}")?;
+                } else {
+                    writeln!(writer, "QUIT")?;
+                    console.println("Leaving the game...");
+                }
+            }
+            "INVALID" | "ERROR" | "SCORE" => console.println(value),
+            "WON" => console.println(format!("Congrats {value} won!")),
+            "TIE" => console.println("It was a tie"),
+            "BYE" => {
+                console.println(value);
+
+                return Ok(());
+            }
+            _ => (),
+        }
+    }
+}
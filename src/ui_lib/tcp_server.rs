@@ -0,0 +1,311 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use super::cmdln_interface::Console;
+use super::cmdln_args::Settings;
+use super::player::ControlMode;
+use super::game::{State, MoveStatus};
+use super::game_engine::read_move;
+use super::session::Session;
+use super::cmdln_app::move_prompt;
+
+// how long a new connection has to send `JOIN` before it is dropped
+const JOIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+// a player that is connected to the server
+struct Client {
+    number: usize,
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Client {
+    // writes one message to the client
+    fn send(&mut self, message: &str) -> io::Result<()> {
+        writeln!(self.writer, "{message}")?;
+        self.writer.flush()
+    }
+
+    // reads the next message from the client, or `None` once it has disconnected
+    fn receive(&mut self) -> Option<String> {
+        let mut line = String::new();
+
+        match self.reader.read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim().to_string()),
+        }
+    }
+}
+
+/// This function hosts a match over TCP. It waits for a client to join for every
+/// human player, then plays the rounds in the settings, asking each client for
+/// their moves in turn. Every move is checked by the server's own Session (and so
+/// by `ttt_cnct_four_board_move_chck`) before it is played, so a client can't
+/// cheat. `Ai` players are played by the server.
+///
+/// # Protocol
+///
+/// The server and its clients talk with one line per message. Every message
+/// starts with a word saying what it is, followed by its values.
+///
+/// From a client:
+///
+/// * `JOIN [NAME]` - \
+///    The first line a client sends, with an optional name of up to 32
+///    characters. A client that doesn't send it within ten seconds is dropped
+/// * `MOVE <MOVE>` - \
+///    The move the client wants to play, typed the way the terminal takes it,
+///    like `5`, `layer row column` in Qubic, `p column` in `PopOut` or `c3` in
+///    Hex (see `game_engine::read_move`)
+/// * `QUIT` - \
+///    The client is leaving, which ends the match
+///
+/// From the server:
+///
+/// * `WELCOME <N> <SPRITE>` - \
+///    The client is player `N` and plays with `SPRITE`
+/// * `WAITING` - \
+///    Not every player has joined yet
+/// * `START <ROUND> <ROUNDS>` - \
+///    A new round is starting
+/// * `BOARD <N>` - \
+///    The next `N` lines are the board as it is drawn in the terminal
+/// * `TURN <NAME>` - \
+///    Whose turn it is
+/// * `YOUR_MOVE <PROMPT>` - \
+///    The client has to send a `MOVE`, and the prompt says how to type it
+/// * `INVALID <ERROR>` - \
+///    The last `MOVE` was not played, and why
+/// * `ERROR <ERROR>` - \
+///    A line could not be used, and why
+/// * `WON <NAME>` or `TIE` - \
+///    How the round ended
+/// * `SCORE <SCORES>` - \
+///    The scores after a round
+/// * `BYE <REASON>` - \
+///    The match is over and the connection will be closed
+///
+/// # Arguments
+///
+/// * `console` - \
+///    A Console struct the server logs what is happening to
+/// * `listener` - \
+///    A `TcpListener` that is already bound to the address clients connect to
+/// * `settings` - \
+///    The game, players, seed and number of rounds to play
+///
+/// # Errors
+///
+/// Will return an error if a client could not be accepted or written to.
+///
+/// # Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use std::io::{BufRead, BufReader, Cursor, Write};
+/// use std::net::{TcpListener, TcpStream};
+/// use std::thread;
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_args::{parse_args, Command};
+/// use tic_tac_toe_and_connect_4::ui_lib::cmdln_interface::Console;
+/// use tic_tac_toe_and_connect_4::ui_lib::tcp_server::serve;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+/// let address = listener.local_addr().unwrap();
+/// let args: Vec<String> = ["--mode", "qubic", "--seed", "1"].iter().map(|arg| arg.to_string()).collect();
+/// let Ok(Command::Play(settings)) = parse_args(&args) else {
+///     panic!("expected a game to be set up");
+/// };
+///
+/// let server = thread::spawn(move || {
+///     serve(&mut Console::new(Cursor::new(""), Vec::new()), &listener, *settings)
+/// });
+///
+/// // moves are typed the way the terminal takes them
+/// let mut stream = TcpStream::connect(address).unwrap();
+/// writeln!(stream, "JOIN BOB").unwrap();
+/// writeln!(stream, "MOVE 1 1 1").unwrap();
+/// writeln!(stream, "QUIT").unwrap();
+///
+/// let lines: Vec<String> = BufReader::new(stream).lines().map(Result::unwrap).collect();
+///
+/// assert_eq!(lines[0], "WELCOME 1 X");
+/// assert_eq!(lines.iter().filter(|line| line.starts_with("YOUR_MOVE Make a move as `layer row column`")).count(), 2);
+/// assert!(!lines.iter().any(|line| line.starts_with("INVALID") || line.starts_with("ERROR")));
+/// assert!(lines.last().unwrap().starts_with("BYE"));
+/// assert!(server.join().unwrap().is_ok());
+/// ```
+pub fn serve<R: BufRead, W: Write>(
+    console: &mut Console<R, W>,
+    listener: &TcpListener,
+    settings: Settings,
+) -> io::Result<()> {
+    let Settings { game, player_list, seed, rounds, .. } = settings;
+    let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    let mut session = Session::new(game, player_list);
+    let mut clients = accept_clients(console, listener, &mut session)?;
+
+    for round in 1..=rounds {
+        broadcast(&mut clients, &format!("START {round} {rounds}"))?;
+
+        while matches!(session.result(), State::NotOver) {
+            broadcast(&mut clients, &board_message(&session))?;
+            broadcast(&mut clients, &format!("TURN {name}", name = session.whose_turn().name))?;
+
+            let player_move = if let Some(val) = session.ai_move(&mut rng) {
+                val
+            } else if let Some(val) = ask_for_move(&mut clients, &session)? {
+                val
+            } else {
+                let name = &session.whose_turn().name;
+
+                console.println(format!("{name} left the game"));
+
+                return broadcast(&mut clients, &format!("BYE {name} left the game"));
+            };
+
+            if let MoveStatus::Invalid(error) = session.submit_move(player_move) {
+                send_to(&mut clients, session.current_player_number(), &format!("INVALID {error}"))?;
+            }
+        }
+
+        broadcast(&mut clients, &board_message(&session))?;
+
+        if let Some(winner) = session.winner() {
+            console.println(format!("Round {round}: {name} won", name = winner.name));
+            broadcast(&mut clients, &format!("WON {name}", name = winner.name))?;
+        } else {
+            console.println(format!("Round {round}: It was a tie"));
+            broadcast(&mut clients, "TIE")?;
+        }
+
+        broadcast(&mut clients, &format!("SCORE {list}", list = session.player_list))?;
+
+        session.next_round();
+    }
+
+    broadcast(&mut clients, "BYE Thanks for playing")
+}
+
+// waits for a client to join in every human player's seat
+fn accept_clients<R: BufRead, W: Write>(
+    console: &mut Console<R, W>,
+    listener: &TcpListener,
+    session: &mut Session,
+) -> io::Result<Vec<Client>> {
+    let mut clients = Vec::new();
+    let seats: Vec<usize> = session
+        .player_list
+        .players
+        .iter()
+        .enumerate()
+        .filter(|(_, player)| player.control == ControlMode::Human)
+        .map(|(index, _)| index + 1)
+        .collect();
+
+    console.println(format!("Waiting for {count} player(s) on {address}",
+        count = seats.len(), address = listener.local_addr()?));
+
+    for (index, number) in seats.iter().copied().enumerate() {
+        let mut client = loop {
+            let (stream, address) = listener.accept()?;
+
+            stream.set_read_timeout(Some(JOIN_TIMEOUT))?;
+
+            let mut client = Client {
+                number,
+                reader: BufReader::new(stream.try_clone()?),
+                writer: stream,
+            };
+
+            match client.receive() {
+                Some(line) if line.starts_with("JOIN") => {
+                    // a player can take as long as they like over their moves
+                    client.writer.set_read_timeout(None)?;
+
+                    let name = line.trim_start_matches("JOIN").trim();
+                    let player_list = &mut session.player_list;
+
+                    match player_list.check_name(name, number) {
+                        Ok(name) => player_list.players[number - 1].name = name,
+                        Err(_) if name.is_empty() => (),
+                        Err(error) => client.send(&format!("ERROR {error}, using {name}",
+                            name = player_list.players[number - 1].name))?,
+                    }
+
+                    console.println(format!("{name} joined from {address}", name = player_list.players[number - 1].name));
+
+                    break client;
+                }
+                _ => console.println(format!("{address} did not join, waiting for another player")),
+            }
+        };
+
+        client.send(&format!("WELCOME {number} {sprite}", sprite = session.player_list.players[number - 1].sprite))?;
+        clients.push(client);
+
+        if index + 1 < seats.len() {
+            broadcast(&mut clients, "WAITING")?;
+        }
+    }
+
+    Ok(clients)
+}
+
+// asks the current player's client for a move until it sends one, or `None` if
+// they left
+fn ask_for_move(clients: &mut [Client], session: &Session) -> io::Result<Option<usize>> {
+    let number = session.current_player_number();
+    let Some(client) = clients.iter_mut().find(|client| client.number == number) else {
+        return Ok(None);
+    };
+
+    client.send(&format!("YOUR_MOVE {prompt}", prompt = move_prompt(&session.game)))?;
+
+    loop {
+        let Some(line) = client.receive() else {
+            return Ok(None);
+        };
+        let (command, value) = line.split_once(' ').unwrap_or((line.as_str(), ""));
+
+        match command {
+            "MOVE" => {
+                if let Some(val) = read_move(&session.game, value) {
+                    return Ok(Some(val));
+                }
+
+                client.send(&format!("ERROR `{value}` is not a move"))?;
+            }
+            "QUIT" => return Ok(None),
+            _ => client.send(&format!("ERROR Unknown command `{command}`"))?,
+        }
+    }
+}
+
+// the board as it is drawn in the terminal, with a line saying how long it is
+fn board_message(session: &Session) -> String {
+    let board = session.game.coloured(&session.player_list, false).to_string();
+    let board = board.trim_end_matches('\n');
+
+    format!("BOARD {count}\n{board}", count = board.lines().count())
+}
+
+// sends a message to every client
+fn broadcast(clients: &mut [Client], message: &str) -> io::Result<()> {
+    for client in clients {
+        client.send(message)?;
+    }
+
+    Ok(())
+}
+
+// sends a message to the client playing as the given player
+fn send_to(clients: &mut [Client], number: usize, message: &str) -> io::Result<()> {
+    for client in clients.iter_mut().filter(|client| client.number == number) {
+        client.send(message)?;
+    }
+
+    Ok(())
+}